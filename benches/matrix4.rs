@@ -0,0 +1,47 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Measures the hot paths of the 4x4 transformations, being the product of
+//! matrices, the matrix-vector product and the inverse, compared with the ones
+//! of `glam` with the `glam` feature.
+
+use criterion::{ black_box, criterion_group, criterion_main, Criterion };
+use linbra::matrix::Matrix4;
+use linbra::vector::Vector4;
+
+fn matrix() -> Matrix4<f32> {
+    Matrix4::natural([
+        [0.8, -0.6, 0.0, 3.0],
+        [0.6, 0.8, 0.0, -2.0],
+        [0.0, 0.0, 1.5, 0.5],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn linbra(c: &mut Criterion) {
+    let (m, v) = (matrix(), Vector4::new([1.0, 2.0, 3.0, 1.0]));
+
+    let mut group = c.benchmark_group("linbra");
+    group.bench_function("mul", |bencher| bencher.iter(|| black_box(m) * black_box(m)));
+    group.bench_function("transform", |bencher| bencher.iter(|| black_box(m) * black_box(v)));
+    group.bench_function("inverse", |bencher| bencher.iter(|| black_box(m).inverse()));
+    group.finish();
+}
+
+#[cfg(feature = "glam")]
+fn glam(c: &mut Criterion) {
+    let (m, v) = (glam::Mat4::from(matrix()), glam::Vec4::new(1.0, 2.0, 3.0, 1.0));
+
+    let mut group = c.benchmark_group("glam");
+    group.bench_function("mul", |bencher| bencher.iter(|| black_box(m) * black_box(m)));
+    group.bench_function("transform", |bencher| bencher.iter(|| black_box(m) * black_box(v)));
+    group.bench_function("inverse", |bencher| bencher.iter(|| black_box(m).inverse()));
+    group.finish();
+}
+
+#[cfg(not(feature = "glam"))]
+criterion_group!(benches, linbra);
+#[cfg(feature = "glam")]
+criterion_group!(benches, linbra, glam);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Compares the SIMD methods of the `simd` feature with the generic operators,
+//! on the `f32` 4D vectors and 4x4 matrices.
+
+use criterion::{ black_box, criterion_group, criterion_main, Criterion };
+use linbra::matrix::Matrix4;
+use linbra::vector::Vector4;
+
+fn matrix() -> Matrix4<f32> {
+    Matrix4::natural([
+        [0.8, -0.6, 0.0, 3.0],
+        [0.6, 0.8, 0.0, -2.0],
+        [0.0, 0.0, 1.5, 0.5],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn vectors(c: &mut Criterion) {
+    let (a, b) = (Vector4::new([1.0, 2.0, 3.0, 4.0]), Vector4::new([0.5, -1.0, 2.0, 0.25]));
+
+    let mut group = c.benchmark_group("Vector4<f32>");
+    group.bench_function("add", |bencher| bencher.iter(|| black_box(a) + black_box(b)));
+    group.bench_function("add_simd", |bencher| bencher.iter(|| black_box(a).add_simd(black_box(b))));
+    group.bench_function("mul", |bencher| bencher.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("mul_simd", |bencher| bencher.iter(|| black_box(a).mul_simd(black_box(b))));
+    group.bench_function("dot", |bencher| bencher.iter(|| black_box(a).dot(black_box(b))));
+    group.bench_function("dot_simd", |bencher| bencher.iter(|| black_box(a).dot_simd(black_box(b))));
+    group.finish();
+}
+
+fn matrices(c: &mut Criterion) {
+    let (m, v) = (matrix(), Vector4::new([1.0, 2.0, 3.0, 1.0]));
+
+    let mut group = c.benchmark_group("Matrix4<f32>");
+    group.bench_function("mul", |bencher| bencher.iter(|| black_box(m) * black_box(m)));
+    group.bench_function("mul_simd", |bencher| bencher.iter(|| black_box(m).mul_simd(black_box(m))));
+    group.bench_function("transform", |bencher| bencher.iter(|| black_box(m) * black_box(v)));
+    group.bench_function("transform_simd", |bencher| bencher.iter(|| black_box(m).transform_simd(black_box(v))));
+    group.bench_function("inverse", |bencher| bencher.iter(|| black_box(m).inverse()));
+    group.bench_function("inverse_simd", |bencher| bencher.iter(|| black_box(m).inverse_simd()));
+    group.finish();
+}
+
+criterion_group!(benches, vectors, matrices);
+criterion_main!(benches);
@@ -0,0 +1,280 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Wrapping of angles in radians and shortest differences between them, on
+//! [`Float`] values and component-wise on vectors of them, such as the yaw and
+//! pitch of a camera or the heading of a turret.
+//!
+//! The [`Deg`] and [`Rad`] wrappers tell the unit of an angle, or of a vector
+//! of angles such as Euler angles, and convert it into the other one.
+//!
+//! ## Example
+//! ```
+//! use std::f32::consts::PI;
+//!
+//! use linbra::angles;
+//!
+//! let heading = 0.75 * PI;
+//! let target = -0.75 * PI;
+//!
+//! // The turret turns by a quarter turn through the back, not three quarters.
+//! let delta = angles::angle_difference(heading, target);
+//! assert!((delta - 0.5 * PI).abs() < 1e-6);
+//! ```
+
+use crate::{ vector::Vector, Float };
+
+/// Returns the angle in degrees converted into radians.
+fn to_radians<T: Float>(degrees: T) -> T {
+    degrees * (T::PI / T::from_f64(180.0))
+}
+
+/// Returns the angle in radians converted into degrees.
+fn to_degrees<T: Float>(radians: T) -> T {
+    radians * (T::from_f64(180.0) / T::PI)
+}
+
+/// Angle in degrees, or vector of angles in degrees.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     angles::{ Deg, Rad },
+///     vector::Vector3,
+/// };
+///
+/// let euler = Deg(Vector3::<f64>::new([180.0, 90.0, 0.0]));
+/// let Rad(radians) = euler.to_radians();
+///
+/// assert_eq!(radians, Vector3::new([
+///     std::f64::consts::PI,
+///     std::f64::consts::FRAC_PI_2,
+///     0.0,
+/// ]));
+/// assert_eq!(Deg::from(Rad(radians)), euler);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Deg<T>(pub T);
+
+/// Angle in radians, or vector of angles in radians.
+///
+/// ## Example
+/// ```
+/// use linbra::angles::{ Deg, Rad };
+///
+/// let angle: Rad<f32> = Deg(180.0).into();
+/// assert_eq!(angle, Rad(std::f32::consts::PI));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Rad<T>(pub T);
+
+impl<T: Float> Deg<T> {
+    /// Returns the angle converted into radians.
+    ///
+    /// ## Formula
+    /// $$ \theta_{rad} = \theta_{deg} \times \frac{\pi}{180} $$
+    pub fn to_radians(self) -> Rad<T> {
+        Rad(to_radians(self.0))
+    }
+}
+
+impl<T: Float, const N: usize> Deg<Vector<T, N>> {
+    /// Returns the vector of angles converted into radians.
+    pub fn to_radians(self) -> Rad<Vector<T, N>> {
+        Rad(self.0.to_radians())
+    }
+}
+
+impl<T: Float> Rad<T> {
+    /// Returns the angle converted into degrees.
+    ///
+    /// ## Formula
+    /// $$ \theta_{deg} = \theta_{rad} \times \frac{180}{\pi} $$
+    pub fn to_degrees(self) -> Deg<T> {
+        Deg(to_degrees(self.0))
+    }
+}
+
+impl<T: Float, const N: usize> Rad<Vector<T, N>> {
+    /// Returns the vector of angles converted into degrees.
+    pub fn to_degrees(self) -> Deg<Vector<T, N>> {
+        Deg(self.0.to_degrees())
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    fn from(value: Deg<T>) -> Self {
+        value.to_radians()
+    }
+}
+
+impl<T: Float, const N: usize> From<Deg<Vector<T, N>>> for Rad<Vector<T, N>> {
+    fn from(value: Deg<Vector<T, N>>) -> Self {
+        value.to_radians()
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    fn from(value: Rad<T>) -> Self {
+        value.to_degrees()
+    }
+}
+
+impl<T: Float, const N: usize> From<Rad<Vector<T, N>>> for Deg<Vector<T, N>> {
+    fn from(value: Rad<Vector<T, N>>) -> Self {
+        value.to_degrees()
+    }
+}
+
+/// Returns the angle in radians wrapped into the range $[-\pi, \pi)$.
+///
+/// ## Formula
+/// $$ \theta' = ((\theta + \pi) \bmod 2\pi) - \pi $$
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::PI;
+///
+/// use linbra::angles::wrap_angle;
+///
+/// assert_eq!(wrap_angle(0.5 * PI), 0.5 * PI);
+/// assert_eq!(wrap_angle(5.0 * PI), -PI);
+/// assert_eq!(wrap_angle(-2.5 * PI), -0.5 * PI);
+///
+/// // Rounded onto the upper bound, but kept out of the range.
+/// let angle = wrap_angle(-PI - 4e-16);
+/// assert!(angle >= -PI && angle < PI);
+/// ```
+pub fn wrap_angle<T: Float>(angle: T) -> T {
+    let wrapped = wrap_angle_positive(angle + T::PI) - T::PI;
+
+    if wrapped >= T::PI {
+        wrapped - (T::PI + T::PI)
+    } else {
+        wrapped
+    }
+}
+
+/// Returns the angle in radians wrapped into the range $[0, 2\pi)$.
+///
+/// ## Formula
+/// $$ \theta' = \theta \bmod 2\pi $$
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::PI;
+///
+/// use linbra::angles::wrap_angle_positive;
+///
+/// assert_eq!(wrap_angle_positive(-0.5 * PI), 1.5 * PI);
+/// assert_eq!(wrap_angle_positive(4.0 * PI), 0.0);
+///
+/// // Rounded onto the upper bound, but kept out of the range.
+/// assert_eq!(wrap_angle_positive(-1e-20), 0.0);
+/// ```
+pub fn wrap_angle_positive<T: Float>(angle: T) -> T {
+    let tau = T::PI + T::PI;
+    let mut wrapped = angle - tau * (angle / tau).floor();
+
+    if wrapped < T::zero() {
+        wrapped += tau;
+    }
+    if wrapped >= tau {
+        wrapped -= tau;
+    }
+
+    wrapped
+}
+
+/// Returns the shortest signed angle in radians to turn from `a` to `b`, in
+/// the range $[-\pi, \pi)$.
+///
+/// ## Formula
+/// $$ \Delta = ((b - a + \pi) \bmod 2\pi) - \pi $$
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::PI;
+///
+/// use linbra::angles::angle_difference;
+///
+/// assert_eq!(angle_difference(0.0, 0.5 * PI), 0.5 * PI);
+/// assert_eq!(angle_difference(0.5 * PI, 0.0), -0.5 * PI);
+/// ```
+pub fn angle_difference<T: Float>(a: T, b: T) -> T {
+    wrap_angle(b - a)
+}
+
+/// Implements the wrapping of angles and their shortest differences, component
+/// by component, for vectors of angles of [`Float`] values.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the vector of angles in radians, each wrapped into the range
+    /// $[-\pi, \pi)$.
+    ///
+    /// See [`wrap_angle`].
+    pub fn wrap_angle(self) -> Self {
+        Vector::new(std::array::from_fn(|n| wrap_angle(self[n])))
+    }
+
+    /// Returns the vector of angles in radians, each wrapped into the range
+    /// $[0, 2\pi)$.
+    ///
+    /// See [`wrap_angle_positive`].
+    pub fn wrap_angle_positive(self) -> Self {
+        Vector::new(std::array::from_fn(|n| wrap_angle_positive(self[n])))
+    }
+
+    /// Returns the shortest signed angles in radians to turn from this vector
+    /// of angles to `target`, each in the range $[-\pi, \pi)$.
+    ///
+    /// See [`angle_difference`].
+    ///
+    /// ## Example
+    /// ```
+    /// use std::f64::consts::PI;
+    ///
+    /// use linbra::vector::Vector2;
+    ///
+    /// let yaw_pitch = Vector2::new([0.0, 0.25 * PI]);
+    /// let target = Vector2::new([-0.5 * PI, 2.25 * PI]);
+    ///
+    /// assert_eq!(yaw_pitch.angle_difference(target), Vector2::new([-0.5 * PI, 0.0]));
+    /// ```
+    pub fn angle_difference(self, target: Self) -> Self {
+        Vector::new(std::array::from_fn(|n| angle_difference(self[n], target[n])))
+    }
+}
+
+/// Implements the conversions between degrees and radians, component by
+/// component, for vectors of angles of [`Float`] values.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the vector of angles in degrees converted into radians.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let angles = Vector2::<f32>::new([90.0, -180.0]);
+    /// assert_eq!(angles.to_radians(), Vector2::new([
+    ///     std::f32::consts::FRAC_PI_2,
+    ///     -std::f32::consts::PI,
+    /// ]));
+    /// ```
+    pub fn to_radians(self) -> Self {
+        Vector::new(std::array::from_fn(|n| to_radians(self[n])))
+    }
+
+    /// Returns the vector of angles in radians converted into degrees.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let angles = Vector2::new([std::f64::consts::PI, 0.0]);
+    /// assert_eq!(angles.to_degrees(), Vector2::new([180.0, 0.0]));
+    /// ```
+    pub fn to_degrees(self) -> Self {
+        Vector::new(std::array::from_fn(|n| to_degrees(self[n])))
+    }
+}
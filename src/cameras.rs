@@ -0,0 +1,398 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Cameras converting the coordinates of the world into the ones of the
+//! screen, and the other way round.
+//!
+//! The screen coordinates are in pixels, from the top-left corner of the
+//! viewport, as described in [`points`](crate::points).
+
+use crate::{
+    matrix::{ Matrix3, Matrix4 },
+    points::Point2,
+    vector::{ Vector2, Vector3 },
+    Float,
+};
+
+/// Camera looking at a 2D world, for tile maps and side-scrolling games.
+///
+/// The world is seen around `position`, which is displayed at the centre of
+/// the viewport, scaled by `zoom` and turned by `rotation` radians. The world
+/// and the screen have their y-axis pointing in the same direction.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     cameras::Camera2D,
+///     vector::Vector2,
+/// };
+///
+/// let camera = Camera2D::new(Vector2::at(10.0, 20.0), Vector2::size(800.0, 600.0));
+///
+/// assert_eq!(camera.world_to_screen(Vector2::at(10.0, 20.0)), Vector2::at(400.0, 300.0));
+/// assert_eq!(camera.screen_to_world(Vector2::at(0.0, 0.0)), Vector2::at(-390.0, -280.0));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Camera2D<T> {
+    /// Point of the world displayed at the centre of the viewport.
+    pub position: Vector2<T>,
+    /// Scale from the world units to pixels, a greater zoom showing a smaller
+    /// part of the world.
+    pub zoom: T,
+    /// Angle in radians of the camera, turning the world the other way round
+    /// on the screen.
+    pub rotation: T,
+    /// Width and height of the viewport, in pixels.
+    pub viewport: Vector2<T>,
+}
+
+impl<T: Float> Camera2D<T> {
+    /// Creates a new camera looking at `position` on a viewport of the given
+    /// size, without zoom nor rotation.
+    pub fn new(position: Vector2<T>, viewport: Vector2<T>) -> Self {
+        Self {
+            position,
+            zoom: T::one(),
+            rotation: T::zero(),
+            viewport,
+        }
+    }
+
+    /// Returns the linear part of the view transform, as its rows.
+    fn linear(&self) -> [[T; 2]; 2] {
+        let c = self.zoom * self.rotation.cos();
+        let s = self.zoom * self.rotation.sin();
+
+        [[c, s], [-s, c]]
+    }
+
+    /// Returns the centre of the viewport, in pixels.
+    fn centre(&self) -> Vector2<T> {
+        let two = T::one() + T::one();
+        Vector2::at(self.viewport.x() / two, self.viewport.y() / two)
+    }
+
+    /// Returns the view matrix, transforming the homogeneous coordinates of
+    /// the world into the ones of the screen.
+    ///
+    /// ## Formula
+    /// With $p$ the position, $z$ the zoom, $\theta$ the rotation and $v$ the
+    /// viewport:
+    ///
+    /// $$ V = T\left(\frac{v}{2}\right) S(z) R(-\theta) T(-p) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera2D,
+    ///     vector::{ Vector2, Vector3 },
+    /// };
+    ///
+    /// let mut camera = Camera2D::new(Vector2::at(10.0, 20.0), Vector2::size(800.0, 600.0));
+    /// camera.zoom = 2.0;
+    ///
+    /// assert_eq!(camera.view() * Vector3::new([15.0, 20.0, 1.0]), Vector3::new([410.0, 300.0, 1.0]));
+    /// ```
+    pub fn view(&self) -> Matrix3<T> {
+        let [[a, b], [c, d]] = self.linear();
+        let centre = self.centre();
+        let (x, y) = (self.position.x(), self.position.y());
+
+        Matrix3::natural([
+            [a, b, centre.x() - (a * x + b * y)],
+            [c, d, centre.y() - (c * x + d * y)],
+            [T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /// Returns the position on the screen of a point of the world.
+    ///
+    /// ## Formula
+    /// $$ s = z R(-\theta) (w - p) + \frac{v}{2} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera2D,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let mut camera = Camera2D::new(Vector2::at(0.0, 0.0), Vector2::size(800.0, 600.0));
+    /// camera.zoom = 2.0;
+    ///
+    /// assert_eq!(camera.world_to_screen(Vector2::at(-10.0, 5.0)), Vector2::at(380.0, 310.0));
+    /// ```
+    pub fn world_to_screen(&self, point: Vector2<T>) -> Vector2<T> {
+        let [[a, b], [c, d]] = self.linear();
+        let centre = self.centre();
+        let (x, y) = (point.x() - self.position.x(), point.y() - self.position.y());
+
+        Vector2::at(a * x + b * y + centre.x(), c * x + d * y + centre.y())
+    }
+
+    /// Returns the point of the world at a position on the screen.
+    ///
+    /// ## Formula
+    /// $$ w = \frac{1}{z} R(\theta) \left(s - \frac{v}{2}\right) + p $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera2D,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let mut camera = Camera2D::new(Vector2::at(0.0, 0.0), Vector2::size(800.0, 600.0));
+    /// camera.zoom = 2.0;
+    ///
+    /// assert_eq!(camera.screen_to_world(Vector2::at(380.0, 310.0)), Vector2::at(-10.0, 5.0));
+    /// ```
+    pub fn screen_to_world(&self, point: Vector2<T>) -> Vector2<T> {
+        let cos = self.rotation.cos() / self.zoom;
+        let sin = self.rotation.sin() / self.zoom;
+        let centre = self.centre();
+        let (x, y) = (point.x() - centre.x(), point.y() - centre.y());
+
+        Vector2::at(
+            cos * x - sin * y + self.position.x(),
+            sin * x + cos * y + self.position.y(),
+        )
+    }
+
+    /// Multiplies the zoom by `factor`, moving the camera so that the point
+    /// of the world under `screen_point` stays under it, as when zooming with
+    /// the mouse wheel.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera2D,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let mut camera = Camera2D::new(Vector2::at(0.0, 0.0), Vector2::size(800.0, 600.0));
+    /// let cursor = Vector2::at(600.0, 300.0);
+    ///
+    /// camera.zoom_at(cursor, 2.0);
+    ///
+    /// assert_eq!(camera.zoom, 2.0);
+    /// assert_eq!(camera.position, Vector2::at(100.0, 0.0));
+    /// assert_eq!(camera.screen_to_world(cursor), Vector2::at(200.0, 0.0));
+    /// ```
+    pub fn zoom_at(&mut self, screen_point: Vector2<T>, factor: T) {
+        let before = self.screen_to_world(screen_point);
+        self.zoom *= factor;
+        let after = self.screen_to_world(screen_point);
+
+        self.position = Vector2::at(
+            self.position.x() + before.x() - after.x(),
+            self.position.y() + before.y() - after.y(),
+        );
+    }
+}
+
+/// Camera looking at a 3D world with a perspective projection, following the
+/// OpenGL conventions: the view space is right-handed with the camera looking
+/// towards $-z$, and the depth is mapped into $[-1, 1]$.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     cameras::Camera3D,
+///     vector::{ Vector2, Vector3, Vector4 },
+/// };
+///
+/// let camera = Camera3D::new(
+///     Vector3::at(0.0, 0.0, 5.0),
+///     Vector3::at(0.0, 0.0, 0.0),
+///     Vector2::size(800.0, 600.0),
+/// );
+///
+/// assert_eq!(camera.view() * Vector4::new([0.0, 0.0, 0.0, 1.0]), Vector4::new([0.0, 0.0, -5.0, 1.0]));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Camera3D<T> {
+    /// Position of the camera in the world.
+    pub eye: Vector3<T>,
+    /// Point of the world the camera is looking at.
+    pub target: Vector3<T>,
+    /// Upward direction of the world, not parallel to the viewing direction.
+    pub up: Vector3<T>,
+    /// Vertical field of view, in radians.
+    pub fov_y: T,
+    /// Distance from the eye to the near clipping plane, greater than zero.
+    pub near: T,
+    /// Distance from the eye to the far clipping plane, greater than `near`.
+    pub far: T,
+    /// Width and height of the viewport, in pixels.
+    pub viewport: Vector2<T>,
+}
+
+impl<T: Float> Camera3D<T> {
+    /// Creates a new camera at `eye` looking at `target` on a viewport of the
+    /// given size, with the y-axis going up, a vertical field of view of
+    /// 90 degrees and clipping planes at `0.1` and `100` units from the eye.
+    pub fn new(eye: Vector3<T>, target: Vector3<T>, viewport: Vector2<T>) -> Self {
+        Self {
+            eye,
+            target,
+            up: Vector3::at(T::zero(), T::one(), T::zero()),
+            fov_y: T::PI / T::from_f64(2.0),
+            near: T::from_f64(0.1),
+            far: T::from_f64(100.0),
+            viewport,
+        }
+    }
+
+    /// Returns the width of the viewport divided by its height.
+    pub fn aspect_ratio(&self) -> T {
+        self.viewport.x() / self.viewport.y()
+    }
+
+    /// Returns the normalized forward, right and up directions of the
+    /// camera.
+    ///
+    /// Panics when the eye is at the target, or when the up direction is
+    /// parallel to the viewing direction.
+    fn basis(&self) -> (Vector3<T>, Vector3<T>, Vector3<T>) {
+        let forward = (self.target - self.eye)
+            .normalize()
+            .expect("eye at the target");
+        let right = forward
+            .cross(self.up)
+            .normalize()
+            .expect("up parallel to the viewing direction");
+
+        (forward, right, right.cross(forward))
+    }
+
+    /// Returns the view matrix, transforming the homogeneous coordinates of
+    /// the world into the ones of the view space.
+    ///
+    /// Panics when the eye is at the target, or when the up direction is
+    /// parallel to the viewing direction.
+    ///
+    /// ## Formula
+    /// With $f$, $s$ and $u$ the forward, right and up directions of the
+    /// camera, and $e$ the eye:
+    ///
+    /// $$ V = \begin{pmatrix} s_x & s_y & s_z & -s \cdot e \\\ u_x & u_y & u_z & -u \cdot e \\\ -f_x & -f_y & -f_z & f \cdot e \\\ 0 & 0 & 0 & 1 \end{pmatrix} $$
+    pub fn view(&self) -> Matrix4<T> {
+        let (f, s, u) = self.basis();
+        let zero = T::zero();
+
+        Matrix4::natural([
+            [s[0], s[1], s[2], -s.dot(self.eye)],
+            [u[0], u[1], u[2], -u.dot(self.eye)],
+            [-f[0], -f[1], -f[2], f.dot(self.eye)],
+            [zero, zero, zero, T::one()],
+        ])
+    }
+
+    /// Returns the perspective projection matrix, transforming the
+    /// homogeneous coordinates of the view space into the clip space.
+    ///
+    /// ## Formula
+    /// With $c = \cot\left(\frac{fov_y}{2}\right)$, $a$ the aspect ratio,
+    /// $n$ and $f$ the near and far distances:
+    ///
+    /// $$ P = \begin{pmatrix} \frac{c}{a} & 0 & 0 & 0 \\\ 0 & c & 0 & 0 \\\ 0 & 0 & \frac{f + n}{n - f} & \frac{2 f n}{n - f} \\\ 0 & 0 & -1 & 0 \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// The near and far planes are mapped to the depths `-1` and `1`:
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera3D,
+    ///     vector::{ Vector2, Vector3, Vector4 },
+    /// };
+    ///
+    /// let mut camera = Camera3D::new(
+    ///     Vector3::at(0.0, 0.0, 0.0),
+    ///     Vector3::at(0.0, 0.0, -1.0),
+    ///     Vector2::size(800.0, 800.0),
+    /// );
+    /// camera.near = 1.0;
+    /// camera.far = 3.0;
+    ///
+    /// let near = camera.projection() * Vector4::new([0.0, 0.0, -1.0, 1.0]);
+    /// let far = camera.projection() * Vector4::new([0.0, 0.0, -3.0, 1.0]);
+    ///
+    /// assert_eq!(near[2] / near[3], -1.0);
+    /// assert_eq!(far[2] / far[3], 1.0);
+    /// ```
+    pub fn projection(&self) -> Matrix4<T> {
+        let two = T::one() + T::one();
+        let half = self.fov_y / two;
+        let c = half.cos() / half.sin();
+        let depth = self.near - self.far;
+        let zero = T::zero();
+
+        Matrix4::natural([
+            [c / self.aspect_ratio(), zero, zero, zero],
+            [zero, c, zero, zero],
+            [zero, zero, (self.far + self.near) / depth, two * self.far * self.near / depth],
+            [zero, zero, -T::one(), zero],
+        ])
+    }
+
+    /// Returns the product of the projection and the view matrices,
+    /// transforming the homogeneous coordinates of the world into the clip
+    /// space.
+    ///
+    /// ## Formula
+    /// $$ M = P V $$
+    pub fn view_projection(&self) -> Matrix4<T> {
+        self.projection() * self.view()
+    }
+
+    /// Returns the ray going from the eye through a pixel of the viewport, as
+    /// its origin and its normalized direction, to pick the objects under the
+    /// cursor.
+    ///
+    /// Panics when the eye is at the target, or when the up direction is
+    /// parallel to the viewing direction.
+    ///
+    /// ## Formula
+    /// With $x$ and $y$ the normalized device coordinates of the pixel, and
+    /// $t = \tan\left(\frac{fov_y}{2}\right)$:
+    ///
+    /// $$ d = \frac{f + x t a s + y t u}{\left\lVert f + x t a s + y t u \right\rVert} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     cameras::Camera3D,
+    ///     vector::{ Vector2, Vector3 },
+    /// };
+    ///
+    /// let camera = Camera3D::new(
+    ///     Vector3::at(0.0, 0.0, 5.0),
+    ///     Vector3::at(0.0, 0.0, 0.0),
+    ///     Vector2::size(800.0, 600.0),
+    /// );
+    ///
+    /// let (origin, direction) = camera.ray_from_screen(Vector2::at(400.0, 300.0));
+    /// assert_eq!(origin, Vector3::at(0.0, 0.0, 5.0));
+    /// assert_eq!(direction, Vector3::at(0.0, 0.0, -1.0));
+    ///
+    /// let (_, direction) = camera.ray_from_screen(Vector2::at(0.0, 0.0));
+    /// assert!(direction[0] < 0.0 && direction[1] > 0.0);
+    /// ```
+    pub fn ray_from_screen(&self, pixel: Vector2<T>) -> (Vector3<T>, Vector3<T>) {
+        let (f, s, u) = self.basis();
+        let one = T::one();
+        let two = one + one;
+
+        let half = self.fov_y / two;
+        let tan = half.sin() / half.cos();
+        let x = (two * pixel.x() / self.viewport.x() - one) * tan * self.aspect_ratio();
+        let y = (one - two * pixel.y() / self.viewport.y()) * tan;
+
+        let direction = (f + s * x + u * y)
+            .normalize()
+            .expect("ray along the viewing direction");
+
+        (self.eye, direction)
+    }
+}
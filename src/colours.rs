@@ -3,41 +3,173 @@
 // Copyright (c) 2023 Antonin Hérault
 
 //! Traits to retrieve the red, blue, green (and alpha) channels of colour 
-//! vectors and the implements of these traits for vectors 3 and 4.
+//! vectors and the implements of these traits for vectors 3 and 4, a
+//! strongly-typed colour wrapper and the parsing of colour strings.
+//! 
+//! Channels are either integers going from zero to their maximum value (`u8`, 
+//! `u16`) or normalized floats going from `0.0` to `1.0` (`f32`, `f64`), see 
+//! the [`Channel`] trait.
 
 use std::ops;
-use crate::vector::{Vector3, Vector4};
+use crate::{ interp::saturate, Error, Float };
+use crate::vector::{Vector, Vector3, Vector4};
 
-/// Implements functions to retrieve the red, blue and green channels of a 
-/// colour
-pub trait RGB: ops::Index<usize, Output = u8> {
+/// Implements the conversions of a colour channel from and to a normalized 
+/// value between `0.0` and `1.0`.
+/// 
+/// This trait is implemented for `u8`, `u16`, `f32` and `f64`.
+pub trait Channel: Copy {
+    /// Returns the channel as a normalized value.
+    fn to_normalized(self) -> f64;
+
+    /// Creates a channel from a normalized value, clamped between `0.0` and 
+    /// `1.0` for integer channels.
+    fn from_normalized(value: f64) -> Self;
+
+    /// Returns the normalized value of the middle of the channel range, being 
+    /// `128` for `u8` channels and `0.5` for float channels.
+    fn middle() -> f64;
+}
+
+macro_rules! impl_integer_channel {
+    ($type:tt) => {
+        impl Channel for $type {
+            fn to_normalized(self) -> f64 {
+                self as f64 / $type::MAX as f64
+            }
+
+            fn from_normalized(value: f64) -> Self {
+                (value.max(0.0).min(1.0) * $type::MAX as f64).round() as $type
+            }
+
+            fn middle() -> f64 {
+                ($type::MAX / 2 + 1) as f64 / $type::MAX as f64
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_channel {
+    ($type:tt) => {
+        impl Channel for $type {
+            fn to_normalized(self) -> f64 {
+                self as f64
+            }
+
+            fn from_normalized(value: f64) -> Self {
+                value as $type
+            }
+
+            fn middle() -> f64 {
+                0.5
+            }
+        }
+    };
+}
+
+impl_integer_channel!(u8);
+impl_integer_channel!(u16);
+impl_float_channel!(f32);
+impl_float_channel!(f64);
+
+/// Implements the conversion between colour vectors of different channel 
+/// types.
+impl<T: Channel, const N: usize> Vector<T, N> {
+    /// Returns the colour with each channel converted to another channel 
+    /// type, going through normalized values.
+    /// 
+    /// ## Formula
+    /// $$ c'_{i} = \operatorname{round} \left( \frac{c_{i}}{max} \times max' \right) $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::{ Vector3, Vector4 };
+    /// 
+    /// let colour = Vector3::<u8>::new([255, 0, 51]);
+    /// 
+    /// assert_eq!(colour.to_channels::<f32>(), Vector3::new([1.0, 0.0, 0.2]));
+    /// assert_eq!(colour.to_channels::<u16>(), Vector3::new([65535, 0, 13107]));
+    /// 
+    /// let normalized = Vector4::<f64>::new([0.5, 1.0, 0.0, 1.0]);
+    /// assert_eq!(normalized.to_channels::<u8>(), Vector4::new([128, 255, 0, 255]));
+    /// ```
+    pub fn to_channels<U: Channel>(self) -> Vector<U, N> {
+        from_normalized(normalized(self))
+    }
+}
+
+/// Implements functions to retrieve and update the red, blue and green 
+/// channels of a colour.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     vector::Vector3,
+///     colours::RGB,
+/// };
+/// 
+/// let mut colour = Vector3::<u8>::rgb(255, 100, 100);
+/// colour.set_g(0);
+/// 
+/// assert_eq!(colour, Vector3::rgb(255, 0, 100));
+/// ```
+pub trait RGB<T: Copy>: ops::Index<usize, Output = T> {
     /// Returns the red channel being the first element of the data structure.
-    fn r(&self) -> u8 {
+    fn r(&self) -> T {
         self[0]
     }
 
     /// Returns the green channel being the second element of the data 
     /// structure.
-    fn g(&self) -> u8 {
+    fn g(&self) -> T {
         self[1]
     }
 
     /// Returns the blue channel being the third element of the data structure.
-    fn b(&self) -> u8 {
+    fn b(&self) -> T {
         self[2]
     }
+
+    /// Sets the red channel being the first element of the data structure.
+    fn set_r(&mut self, r: T) where Self: ops::IndexMut<usize> {
+        self[0] = r;
+    }
+
+    /// Sets the green channel being the second element of the data structure.
+    fn set_g(&mut self, g: T) where Self: ops::IndexMut<usize> {
+        self[1] = g;
+    }
+
+    /// Sets the blue channel being the third element of the data structure.
+    fn set_b(&mut self, b: T) where Self: ops::IndexMut<usize> {
+        self[2] = b;
+    }
 }
 
-/// Implements the [`RGB`] trait for 3-vectors of `u8` values.  
-impl RGB for Vector3<u8> {}
+/// Implements the [`RGB`] trait for 3-vectors.  
+impl<T: Copy> RGB<T> for Vector3<T> {}
 
-/// Implements the [`RGB`] trait for 4-vectors of `u8` values to let the 
-/// implementation of the [`RGBA`] trait possible.
-impl RGB for Vector4<u8> {}
+/// Implements the [`RGB`] trait for 4-vectors to let the implementation of 
+/// the [`RGBA`] trait possible.
+impl<T: Copy> RGB<T> for Vector4<T> {}
 
 /// Implements a named constructor for RGB structures.
-impl Vector3<u8> {
-    fn rgb(r: u8, g: u8, b: u8) -> Self {
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     vector::Vector3,
+///     colours::RGB,
+/// };
+/// 
+/// let colour = Vector3::<u8>::rgb(255, 100, 50);
+/// 
+/// assert_eq!(colour.r(), 255);
+/// assert_eq!(colour.b(), 50);
+/// ```
+impl<T: Copy> Vector3<T> {
+    /// Creates a new RGB colour.
+    pub fn rgb(r: T, g: T, b: T) -> Self {
         [r, g, b].into()
     }
 }
@@ -83,29 +215,67 @@ impl From<u32> for Vector3<u8> {
 /// 
 /// assert_eq!(0xFF0000, hex);
 /// ```
-impl Into<u32> for Vector3<u8> {
-    fn into(self) -> u32 {
-        ((self[0] as u32) << 16) | ((self[1] as u32) << 8) | (self[2] as u32)
+impl From<Vector3<u8>> for u32 {
+    fn from(value: Vector3<u8>) -> Self {
+        ((value[0] as u32) << 16) | ((value[1] as u32) << 8) | (value[2] as u32)
     }
 }
 
-/// Implements a function to retrieve the alpha channel of a colour more than
-/// the red, blue and green channels.
-pub trait RGBA: RGB {
-    /// Returns the alpha channel being the third element of the data structure.
-    fn a(&self) -> u8 {
+/// Implements functions to retrieve and update the alpha channel of a colour 
+/// more than the red, blue and green channels.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     vector::Vector4,
+///     colours::RGBA,
+/// };
+/// 
+/// let colour = Vector4::<u8>::rgba(255, 100, 100, 255);
+/// 
+/// assert_eq!(colour.with_alpha(128), Vector4::rgba(255, 100, 100, 128));
+/// ```
+pub trait RGBA<T: Copy>: RGB<T> {
+    /// Returns the alpha channel being the fourth element of the data 
+    /// structure.
+    fn a(&self) -> T {
         self[3]
     }
+
+    /// Sets the alpha channel being the fourth element of the data structure.
+    fn set_a(&mut self, a: T) where Self: ops::IndexMut<usize> {
+        self[3] = a;
+    }
+
+    /// Returns a copy of the colour with another alpha channel.
+    fn with_alpha(mut self, a: T) -> Self where Self: Sized + ops::IndexMut<usize> {
+        self.set_a(a);
+        self
+    }
 }
 
-/// Implements the [`RGBA`] for 4-vectors of `u8` values. 
+/// Implements the [`RGBA`] for 4-vectors. 
 /// 
 /// The [`RGB`] trait is also implemented for 4-vectors in this module. 
-impl RGBA for Vector4<u8> {}
+impl<T: Copy> RGBA<T> for Vector4<T> {}
 
 /// Implements a named constructor for RGBA structures.
-impl Vector4<u8> {
-    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     vector::Vector4,
+///     colours::{ RGB, RGBA },
+/// };
+/// 
+/// let colour = Vector4::<f32>::rgba(1.0, 0.5, 0.0, 0.25);
+/// 
+/// assert_eq!(colour.g(), 0.5);
+/// assert_eq!(colour.a(), 0.25);
+/// ```
+impl<T: Copy> Vector4<T> {
+    /// Creates a new RGBA colour.
+    pub fn rgba(r: T, g: T, b: T, a: T) -> Self {
         [r, g, b, a].into()
     }
 }
@@ -154,11 +324,958 @@ impl From<u32> for Vector4<u8> {
 /// 
 /// assert_eq!(0xFF0000FF, hex);
 /// ```
-impl Into<u32> for Vector4<u8> {
-    fn into(self) -> u32 {
-        ((self[0] as u32) << 24) | 
-        ((self[1] as u32) << 16) | 
-        ((self[2] as u32) << 8) | 
-        (self[3] as u32)
+impl From<Vector4<u8>> for u32 {
+    fn from(value: Vector4<u8>) -> Self {
+        ((value[0] as u32) << 24) | 
+        ((value[1] as u32) << 16) | 
+        ((value[2] as u32) << 8) | 
+        (value[3] as u32)
+    }
+}
+
+/// Colour with an alpha channel, wrapping the [`Vector4`] of its red, green, 
+/// blue and alpha channels.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     colours::{ Colour, RGB, RGBA },
+///     vector::Vector4
+/// };
+/// 
+/// let colour = Colour::<u8>::new(255, 100, 100, 255);
+/// assert_eq!(colour.r(), 255);
+/// assert_eq!(colour.a(), 255);
+/// 
+/// let vector: Vector4<u8> = colour.into();
+/// assert_eq!(vector, Vector4::new([255, 100, 100, 255]));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Colour<T>(Vector4<T>);
+
+impl<T> Colour<T> {
+    /// Creates a new strongly-typed colour from its red, green, blue and 
+    /// alpha channels.
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Self([r, g, b, a].into())
+    }
+}
+
+/// Wraps a vector into a [`Colour`].
+impl<T> From<Vector4<T>> for Colour<T> {
+    fn from(value: Vector4<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// Unwraps the vector of a [`Colour`].
+impl<T> From<Colour<T>> for Vector4<T> {
+    fn from(value: Colour<T>) -> Self {
+        value.0
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector.
+impl<T> ops::Index<usize> for Colour<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector, as mutable.
+impl<T> ops::IndexMut<usize> for Colour<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
     }
 }
+
+/// Implements the [`RGB`] trait for strongly-typed colours.
+impl<T: Copy> RGB<T> for Colour<T> {}
+
+/// Implements the [`RGBA`] trait for strongly-typed colours.
+impl<T: Copy> RGBA<T> for Colour<T> {}
+
+/// Implements alpha compositing for RGBA colours with straight (not 
+/// premultiplied) alpha, whatever their channel type.
+/// 
+/// Calculations are performed on normalized channels.
+impl<T: Channel> Vector4<T> {
+    /// Returns the colour composited over `background` with the source-over 
+    /// operator, as drawing a sprite over a scene.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \alpha_{o} = \alpha_{s} + \alpha_{b} (1 - \alpha_{s}), \quad 
+    /// C_{o} = \frac{C_{s} \alpha_{s} + C_{b} \alpha_{b} (1 - \alpha_{s})}{\alpha_{o}}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    /// 
+    /// let red = Vector4::<f32>::new([1.0, 0.0, 0.0, 0.5]);
+    /// let blue = Vector4::<f32>::new([0.0, 0.0, 1.0, 1.0]);
+    /// 
+    /// assert_eq!(red.over(blue), Vector4::new([0.5, 0.0, 0.5, 1.0]));
+    /// ```
+    pub fn over(self, background: Self) -> Self {
+        let source = normalized(self);
+        let background = normalized(background);
+
+        let alpha = source[3] + background[3] * (1.0 - source[3]);
+
+        // Both colours are fully transparent.
+        if alpha == 0.0 {
+            return from_normalized([0.0; 4]);
+        }
+
+        let mut output = [alpha; 4];
+        for n in 0..3 {
+            output[n] = (
+                source[n] * source[3] + background[n] * background[3] * (1.0 - source[3])
+            ) / alpha;
+        }
+
+        from_normalized(output)
+    }
+
+    /// Returns the colour with its red, green and blue channels multiplied by 
+    /// its alpha channel.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     r \\\ 
+    ///     g \\\ 
+    ///     b \\\ 
+    ///     \alpha \\\ 
+    /// \end{pmatrix} \rightarrow
+    /// \begin{pmatrix} 
+    ///     r \alpha \\\ 
+    ///     g \alpha \\\ 
+    ///     b \alpha \\\ 
+    ///     \alpha \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    /// 
+    /// let colour = Vector4::<u8>::new([255, 100, 0, 51]);
+    /// assert_eq!(colour.premultiplied(), Vector4::new([51, 20, 0, 51]));
+    /// ```
+    pub fn premultiplied(self) -> Self {
+        let colour = normalized(self);
+        
+        from_normalized([
+            colour[0] * colour[3], 
+            colour[1] * colour[3], 
+            colour[2] * colour[3], 
+            colour[3]
+        ])
+    }
+
+    /// Returns the colour with its red, green and blue channels divided by its 
+    /// alpha channel, reverting [`Vector4::premultiplied`].
+    /// 
+    /// A fully transparent colour becomes a fully transparent black.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     r \\\ 
+    ///     g \\\ 
+    ///     b \\\ 
+    ///     \alpha \\\ 
+    /// \end{pmatrix} \rightarrow
+    /// \begin{pmatrix} 
+    ///     r / \alpha \\\ 
+    ///     g / \alpha \\\ 
+    ///     b / \alpha \\\ 
+    ///     \alpha \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    /// 
+    /// let colour = Vector4::<u8>::new([51, 20, 0, 51]);
+    /// assert_eq!(colour.unpremultiplied(), Vector4::new([255, 100, 0, 51]));
+    /// ```
+    pub fn unpremultiplied(self) -> Self {
+        let colour = normalized(self);
+
+        if colour[3] == 0.0 {
+            return from_normalized([0.0; 4]);
+        }
+
+        from_normalized([
+            colour[0] / colour[3], 
+            colour[1] / colour[3], 
+            colour[2] / colour[3], 
+            colour[3]
+        ])
+    }
+}
+
+/// Returns the normalized channels of the colour.
+fn normalized<T: Channel, const N: usize>(colour: Vector<T, N>) -> [f64; N] {
+    std::array::from_fn(|n| colour[n].to_normalized())
+}
+
+/// Creates a colour from normalized channels.
+fn from_normalized<T: Channel, const N: usize>(channels: [f64; N]) -> Vector<T, N> {
+    Vector::new(channels.map(T::from_normalized))
+}
+
+/// Error returned when a string cannot be parsed as a colour, see 
+/// [`parse_colour`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColourError {
+    /// The string is neither an hexadecimal colour starting with `#` nor a 
+    /// `rgb(r, g, b)` colour.
+    UnknownFormat,
+    /// The hexadecimal colour has not 3, 6 or 8 digits, or the `rgb(...)` 
+    /// colour has not 3 channels. Contains the found length.
+    InvalidLength(usize),
+    /// A channel is not a valid hexadecimal digit or a number between 0 and 
+    /// 255. Contains the invalid channel.
+    InvalidChannel(String),
+}
+
+impl std::fmt::Display for ParseColourError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFormat => write!(f, "unknown colour format"),
+            Self::InvalidLength(length) => write!(f, "invalid colour length {}", length),
+            Self::InvalidChannel(channel) => write!(f, "invalid colour channel \"{}\"", channel),
+        }
+    }
+}
+
+impl std::error::Error for ParseColourError {}
+
+/// Parses a colour from one of the following forms, the alpha channel being 
+/// 255 when not given:
+/// - `#RGB`, each digit being repeated (`#F80` is `#FF8800`),
+/// - `#RRGGBB`,
+/// - `#RRGGBBAA`,
+/// - `rgb(r, g, b)` with decimal channels from 0 to 255.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     colours::{ parse_colour, ParseColourError },
+///     vector::Vector4,
+/// };
+/// 
+/// assert_eq!(parse_colour("#F80"), Ok(Vector4::new([255, 136, 0, 255])));
+/// assert_eq!(parse_colour("#FF880080"), Ok(Vector4::new([255, 136, 0, 128])));
+/// assert_eq!(parse_colour("rgb(255, 136, 0)"), Ok(Vector4::new([255, 136, 0, 255])));
+/// 
+/// assert_eq!(parse_colour("#FF88"), Err(ParseColourError::InvalidLength(4)));
+/// ```
+pub fn parse_colour(string: &str) -> Result<Vector4<u8>, ParseColourError> {
+    let string = string.trim();
+
+    if let Some(digits) = string.strip_prefix('#') {
+        return parse_hexadecimal(digits);
+    }
+
+    if let Some(channels) = string
+        .strip_prefix("rgb(")
+        .and_then(|channels| channels.strip_suffix(')')) 
+    {
+        let channels = channels
+            .split(',')
+            .map(|channel| {
+                let channel = channel.trim();
+                channel
+                    .parse::<u8>()
+                    .map_err(|_| ParseColourError::InvalidChannel(channel.to_string()))
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        if channels.len() != 3 {
+            return Err(ParseColourError::InvalidLength(channels.len()));
+        }
+
+        return Ok(Vector4::rgba(channels[0], channels[1], channels[2], 255));
+    }
+
+    Err(ParseColourError::UnknownFormat)
+}
+
+/// Parses the digits of an hexadecimal colour, without the leading `#`.
+fn parse_hexadecimal(digits: &str) -> Result<Vector4<u8>, ParseColourError> {
+    if let Some(invalid) = digits.chars().find(|digit| !digit.is_ascii_hexdigit()) {
+        return Err(ParseColourError::InvalidChannel(invalid.to_string()));
+    }
+
+    let value = u32::from_str_radix(digits, 16);
+
+    match (digits.len(), value) {
+        (3, Ok(value)) => {
+            // Repeats each digit, `0xF` becoming `0xFF`.
+            let r = ((value >> 8) & 0xF) * 0x11;
+            let g = ((value >> 4) & 0xF) * 0x11;
+            let b = (value & 0xF) * 0x11;
+
+            Ok(Vector4::rgba(r as u8, g as u8, b as u8, 255))
+        },
+        (6, Ok(value)) => Ok(Vector4::from((value << 8) | 0xFF)),
+        (8, Ok(value)) => Ok(Vector4::from(value)),
+        (length, _) => Err(ParseColourError::InvalidLength(length)),
+    }
+}
+
+/// Parses a colour as [`parse_colour`] does, the error being wrapped in 
+/// [`Error::Parse`].
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector4;
+/// 
+/// let colour: Vector4<u8> = "#FF000080".parse().unwrap();
+/// assert_eq!(colour, Vector4::new([255, 0, 0, 128]));
+/// ```
+impl std::str::FromStr for Vector4<u8> {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ok(parse_colour(string)?)
+    }
+}
+
+/// Parses an opaque colour as [`parse_colour`] does, refusing the colours 
+/// with an alpha channel.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let colour: Vector3<u8> = "rgb(255, 0, 0)".parse().unwrap();
+/// assert_eq!(colour, Vector3::new([255, 0, 0]));
+/// 
+/// assert!("#FF000080".parse::<Vector3<u8>>().is_err());
+/// ```
+impl std::str::FromStr for Vector3<u8> {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let digits = string.trim().strip_prefix('#').map(str::len);
+        
+        if digits == Some(8) {
+            return Err(ParseColourError::InvalidLength(8).into());
+        }
+
+        let colour = parse_colour(string)?;
+        Ok(Vector3::rgb(colour[0], colour[1], colour[2]))
+    }
+}
+
+/// Standard defining the weights of the red, green and blue channels in the 
+/// luma of a YCbCr colour.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum YCbCrStandard {
+    /// ITU-R BT.601, used by standard-definition video and JPEG.
+    Bt601,
+    /// ITU-R BT.709, used by high-definition video.
+    Bt709,
+}
+
+impl YCbCrStandard {
+    /// Returns the weights $K_{R}$ and $K_{B}$ of the red and blue channels, 
+    /// the weight of the green channel being $1 - K_{R} - K_{B}$.
+    pub fn weights(self) -> (f64, f64) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// Implements the conversions between RGB and full-range YCbCr colours, 
+/// whatever their channel type.
+/// 
+/// YCbCr colours are stored in 3-vectors as `(Y, Cb, Cr)`, the chroma channels 
+/// being offset by the middle of the channel range $M$ (128 for `u8` channels, 
+/// `0.5` for float channels, see [`Channel::middle`]) so they are never 
+/// negative.
+impl<T: Channel> Vector3<T> {
+    /// Returns the YCbCr colour of this RGB colour.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// Y = K_{R} R + K_{G} G + K_{B} B, \quad 
+    /// C_{b} = \frac{1}{2} \frac{B - Y}{1 - K_{B}} + M, \quad 
+    /// C_{r} = \frac{1}{2} \frac{R - Y}{1 - K_{R}} + M
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     colours::YCbCrStandard,
+    ///     vector::Vector3,
+    /// };
+    /// 
+    /// let white = Vector3::<u8>::new([255, 255, 255]);
+    /// assert_eq!(white.to_ycbcr(YCbCrStandard::Bt601), Vector3::new([255, 128, 128]));
+    /// 
+    /// let red = Vector3::<u8>::new([255, 0, 0]);
+    /// assert_eq!(red.to_ycbcr(YCbCrStandard::Bt601), Vector3::new([76, 85, 255]));
+    /// ```
+    pub fn to_ycbcr(self, standard: YCbCrStandard) -> Self {
+        let (kr, kb) = standard.weights();
+        let kg = 1.0 - kr - kb;
+        let [r, g, b] = normalized(self);
+
+        let y = kr * r + kg * g + kb * b;
+        let cb = 0.5 * (b - y) / (1.0 - kb) + T::middle();
+        let cr = 0.5 * (r - y) / (1.0 - kr) + T::middle();
+
+        from_normalized([y, cb, cr])
+    }
+
+    /// Returns the RGB colour of the YCbCr colour, reverting 
+    /// [`Vector3::to_ycbcr`].
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// R = Y + 2 (1 - K_{R}) (C_{r} - M), \quad 
+    /// B = Y + 2 (1 - K_{B}) (C_{b} - M), \quad 
+    /// G = \frac{Y - K_{R} R - K_{B} B}{K_{G}}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     colours::YCbCrStandard,
+    ///     vector::Vector3,
+    /// };
+    /// 
+    /// let colour = Vector3::<f64>::new([0.2, 0.6, 0.4]);
+    /// let ycbcr = colour.to_ycbcr(YCbCrStandard::Bt709);
+    /// let rgb = Vector3::from_ycbcr(ycbcr, YCbCrStandard::Bt709);
+    /// 
+    /// assert!((rgb - colour).length() < 1e-9);
+    /// ```
+    pub fn from_ycbcr(ycbcr: Self, standard: YCbCrStandard) -> Self {
+        let (kr, kb) = standard.weights();
+        let kg = 1.0 - kr - kb;
+        let [y, cb, cr] = normalized(ycbcr);
+
+        let r = y + 2.0 * (1.0 - kr) * (cr - T::middle());
+        let b = y + 2.0 * (1.0 - kb) * (cb - T::middle());
+        let g = (y - kr * r - kb * b) / kg;
+
+        from_normalized([r, g, b])
+    }
+}
+
+/// Returns the hue in degrees, the saturation and the lightness of normalized 
+/// red, green and blue channels.
+fn rgb_to_hsl([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+
+    // Grey colour, without hue nor saturation.
+    if delta == 0.0 {
+        return [0.0, 0.0, lightness];
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+
+    let hue = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    [hue * 60.0, saturation, lightness]
+}
+
+/// Returns the normalized red, green and blue channels of a hue in degrees, a 
+/// saturation and a lightness.
+fn hsl_to_rgb([hue, saturation, lightness]: [f64; 3]) -> [f64; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = lightness - chroma / 2.0;
+    [r + m, g + m, b + m]
+}
+
+/// Returns the colour with its hue, saturation and lightness modified by the 
+/// given function, keeping the other channels untouched.
+fn adjust_hsl<T: Channel, const N: usize>(
+    colour: Vector<T, N>, 
+    adjust: impl FnOnce([f64; 3]) -> [f64; 3]
+) -> Vector<T, N> {
+    let mut channels = normalized(colour);
+    let [r, g, b] = hsl_to_rgb(adjust(rgb_to_hsl([channels[0], channels[1], channels[2]])));
+
+    channels[0] = r;
+    channels[1] = g;
+    channels[2] = b;
+
+    from_normalized(channels)
+}
+
+macro_rules! impl_colour_adjustments {
+    ($vector:tt, $alpha:literal) => {
+        /// Implements adjustments of the hue, saturation and lightness of 
+        /// colours, going through the HSL colour model.
+        /// 
+        /// The alpha channel of RGBA colours is kept untouched.
+        impl<T: Channel> $vector<T> {
+            /// Returns the colour with its hue rotated by `degrees` on the 
+            /// colour wheel.
+            /// 
+            /// ## Formula
+            /// $$ H' = (H + \theta) \bmod 360 $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            #[doc = concat!("assert_eq!(red.rotate_hue(120.0), ", stringify!($vector), "::new([0, 255, 0", $alpha, "]));")]
+            /// ```
+            pub fn rotate_hue(self, degrees: f64) -> Self {
+                adjust_hsl(self, |[h, s, l]| [h + degrees, s, l])
+            }
+
+            /// Returns the colour with `amount` added to its saturation, a 
+            /// negative amount desaturating the colour.
+            /// 
+            /// ## Formula
+            /// $$ S' = \min(\max(S + a, 0), 1) $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let colour = ", stringify!($vector), "::<u8>::new([191, 64, 64", $alpha, "]);")]
+            #[doc = concat!("assert_eq!(colour.saturate(0.5), ", stringify!($vector), "::new([255, 0, 0", $alpha, "]));")]
+            /// ```
+            pub fn saturate(self, amount: f64) -> Self {
+                adjust_hsl(self, |[h, s, l]| [h, (s + amount).max(0.0).min(1.0), l])
+            }
+
+            /// Returns the colour with `amount` subtracted from its lightness.
+            /// 
+            /// ## Formula
+            /// $$ L' = \min(\max(L - a, 0), 1) $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            #[doc = concat!("assert_eq!(red.darken(0.25), ", stringify!($vector), "::new([128, 0, 0", $alpha, "]));")]
+            /// ```
+            pub fn darken(self, amount: f64) -> Self {
+                self.lighten(-amount)
+            }
+
+            /// Returns the colour with `amount` added to its lightness.
+            /// 
+            /// ## Formula
+            /// $$ L' = \min(\max(L + a, 0), 1) $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            #[doc = concat!("assert_eq!(red.lighten(0.25), ", stringify!($vector), "::new([255, 128, 128", $alpha, "]));")]
+            /// ```
+            pub fn lighten(self, amount: f64) -> Self {
+                adjust_hsl(self, |[h, s, l]| [h, s, (l + amount).max(0.0).min(1.0)])
+            }
+
+            /// Returns the complementary colour, being the opposite one on the 
+            /// colour wheel.
+            /// 
+            /// ## Formula
+            /// $$ H' = (H + 180) \bmod 360 $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            #[doc = concat!("assert_eq!(red.complementary(), ", stringify!($vector), "::new([0, 255, 255", $alpha, "]));")]
+            /// ```
+            pub fn complementary(self) -> Self {
+                self.rotate_hue(180.0)
+            }
+
+            /// Returns the triadic palette of the colour, being itself and the 
+            /// two colours evenly spaced from it on the colour wheel.
+            /// 
+            /// ## Formula
+            /// $$ H_k = (H + 120 k) \bmod 360, \quad k \in \{0, 1, 2\} $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            /// 
+            /// assert_eq!(red.triadic(), [
+            ///     red,
+            #[doc = concat!("    ", stringify!($vector), "::new([0, 255, 0", $alpha, "]),")]
+            #[doc = concat!("    ", stringify!($vector), "::new([0, 0, 255", $alpha, "]),")]
+            /// ]);
+            /// ```
+            pub fn triadic(self) -> [Self; 3] {
+                [self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+            }
+
+            /// Returns the analogous palette of `n` colours, having hues 
+            /// evenly spread over `spread` degrees and centred on the hue of 
+            /// the colour.
+            /// 
+            /// A single colour palette only contains the colour itself.
+            /// 
+            /// ## Formula
+            /// $$ H_k = \left(H + s \left(\frac{k}{n - 1} - \frac{1}{2}\right)\right) \bmod 360, \quad 0 \le k < n $$
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::vector::", stringify!($vector), ";")]
+            /// 
+            #[doc = concat!("let red = ", stringify!($vector), "::<u8>::new([255, 0, 0", $alpha, "]);")]
+            /// 
+            /// assert_eq!(red.analogous(3, 60.0), vec![
+            #[doc = concat!("    ", stringify!($vector), "::new([255, 0, 128", $alpha, "]),")]
+            ///     red,
+            #[doc = concat!("    ", stringify!($vector), "::new([255, 128, 0", $alpha, "]),")]
+            /// ]);
+            /// ```
+            pub fn analogous(self, n: usize, spread: f64) -> Vec<Self> {
+                if n == 1 {
+                    return vec![self];
+                }
+
+                (0..n)
+                    .map(|k| self.rotate_hue(spread * (k as f64 / (n - 1) as f64 - 0.5)))
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_colour_adjustments!(Vector3, "");
+impl_colour_adjustments!(Vector4, ", 255");
+
+/// Implements exposure and tonemapping operators for linear HDR colours of 
+/// [`Float`] values, whose channels are not limited to `1.0`.
+impl<T: Float> Vector3<T> {
+    /// Returns the colour scaled by an exposure given in stops, each 
+    /// stop doubling the light.
+    /// 
+    /// ## Formula
+    /// $$ C' = C \times 2^{EV} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let colour = Vector3::<f32>::new([0.5, 1.0, 2.0]);
+    /// assert_eq!(colour.exposed(1.0), Vector3::new([1.0, 2.0, 4.0]));
+    /// ```
+    pub fn exposed(self, stops: T) -> Self {
+        self * T::from_f64(2.0).powf(stops)
+    }
+
+    /// Returns the colour tonemapped into the `[0, 1]` range with the 
+    /// Reinhard operator.
+    /// 
+    /// ## Formula
+    /// $$ C' = \frac{C}{1 + C} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let colour = Vector3::<f64>::new([0.0, 1.0, 3.0]);
+    /// assert_eq!(colour.tonemap_reinhard(), Vector3::new([0.0, 0.5, 0.75]));
+    /// ```
+    pub fn tonemap_reinhard(self) -> Self {
+        let mut colour = self;
+
+        for n in 0..3 {
+            colour[n] = colour[n] / (T::one() + colour[n]);
+        }
+
+        colour
+    }
+
+    /// Returns the colour tonemapped into the `[0, 1]` range with the 
+    /// ACES filmic curve fitted by Krzysztof Narkowicz.
+    /// 
+    /// ## Formula
+    /// $$ C' = \frac{C (2.51 C + 0.03)}{C (2.43 C + 0.59) + 0.14} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let colour = Vector3::<f32>::new([0.0, 0.18, 100.0]);
+    /// let tonemapped = colour.tonemap_aces();
+    /// 
+    /// assert_eq!(tonemapped[0], 0.0);
+    /// assert!(tonemapped[1] > 0.2 && tonemapped[1] < 0.3);
+    /// assert_eq!(tonemapped[2], 1.0);
+    /// ```
+    pub fn tonemap_aces(self) -> Self {
+        let (a, b) = (T::from_f64(2.51), T::from_f64(0.03));
+        let (c, d, e) = (T::from_f64(2.43), T::from_f64(0.59), T::from_f64(0.14));
+        let mut colour = self;
+
+        for n in 0..3 {
+            let x = colour[n];
+            colour[n] = saturate((x * (a * x + b)) / (x * (c * x + d) + e));
+        }
+
+        colour
+    }
+
+    /// Returns the LDR colour of `u8` values of this linear colour, 
+    /// encoded with the sRGB transfer function after clamping each 
+    /// channel into the `[0, 1]` range.
+    /// 
+    /// HDR colours are expected to be tonemapped before.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// C' = \begin{cases} 
+    ///     12.92 C & C \leq 0.0031308 \\\ 
+    ///     1.055 C^{1/2.4} - 0.055 & C > 0.0031308 \\\ 
+    /// \end{cases}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let colour = Vector3::<f64>::new([0.0, 0.25, 4.0]);
+    /// assert_eq!(colour.to_ldr(), Vector3::new([0, 137, 255]));
+    /// ```
+    pub fn to_ldr(self) -> Vector3<u8> where T: Channel {
+        let threshold = T::from_f64(0.0031308);
+        let (slope, gamma) = (T::from_f64(12.92), T::from_f64(1.0 / 2.4));
+        let (scale, offset) = (T::from_f64(1.055), T::from_f64(0.055));
+        let mut colour = self;
+
+        for n in 0..3 {
+            let c = saturate(colour[n]);
+
+            colour[n] = if c <= threshold {
+                slope * c
+            } else {
+                scale * c.powf(gamma) - offset
+            };
+        }
+
+        colour.to_channels()
+    }
+}
+
+/// Implements the conversion of sRGB colours into the CIELAB colour space and 
+/// the perceptual colour differences, whatever their channel type.
+/// 
+/// Colours are considered as encoded with the sRGB transfer function and lit 
+/// by the D65 standard illuminant.
+impl<T: Channel> Vector3<T> {
+    /// Returns the CIELAB colour of this sRGB colour as `(L, a, b)`, the 
+    /// lightness `L` going from 0 to 100.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// L = 116 f \left( \frac{Y}{Y_{n}} \right) - 16, \quad 
+    /// a = 500 \left( f \left( \frac{X}{X_{n}} \right) - f \left( \frac{Y}{Y_{n}} \right) \right), \quad 
+    /// b = 200 \left( f \left( \frac{Y}{Y_{n}} \right) - f \left( \frac{Z}{Z_{n}} \right) \right)
+    /// $$
+    /// where $X$, $Y$ and $Z$ are the CIEXYZ coordinates of the linear colour 
+    /// and 
+    /// $$ 
+    /// f(t) = \begin{cases} 
+    ///     t^{1/3} & t > \delta^3 \\\ 
+    ///     \frac{t}{3 \delta^2} + \frac{4}{29} & t \leq \delta^3 \\\ 
+    /// \end{cases}, \quad \delta = \frac{6}{29}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let white = Vector3::<u8>::new([255, 255, 255]).to_lab();
+    /// 
+    /// assert!((white[0] - 100.0).abs() < 1e-3);
+    /// assert!(white[1].abs() < 1e-3);
+    /// assert!(white[2].abs() < 1e-3);
+    /// ```
+    pub fn to_lab(self) -> Vector3<f64> {
+        let [r, g, b] = normalized(self).map(|c| {
+            // Decodes the sRGB transfer function.
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        });
+
+        // CIEXYZ coordinates relative to the D65 white point.
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+
+        let f = |t: f64| {
+            let delta: f64 = 6.0 / 29.0;
+
+            if t > delta.powi(3) {
+                t.cbrt()
+            } else {
+                t / (3.0 * delta * delta) + 4.0 / 29.0
+            }
+        };
+
+        Vector3::new([
+            116.0 * f(y) - 16.0, 
+            500.0 * (f(x) - f(y)), 
+            200.0 * (f(y) - f(z))
+        ])
+    }
+
+    /// Returns the CIE76 colour difference between the two colours, being the 
+    /// euclidean distance between their CIELAB coordinates.
+    /// 
+    /// A difference around 2.3 is the just noticeable difference.
+    /// 
+    /// ## Formula
+    /// $$ \Delta E^{*}_{76} = \sqrt{(L_{2} - L_{1})^2 + (a_{2} - a_{1})^2 + (b_{2} - b_{1})^2} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let red = Vector3::<u8>::new([255, 0, 0]);
+    /// let dark_red = Vector3::<u8>::new([250, 0, 0]);
+    /// 
+    /// assert_eq!(red.delta_e(red), 0.0);
+    /// assert!(red.delta_e(dark_red) < 2.3);
+    /// ```
+    pub fn delta_e(self, other: Self) -> f64 {
+        self.to_lab().distance(other.to_lab())
+    }
+
+    /// Returns the CIEDE2000 colour difference between the two colours, 
+    /// correcting the perceptual non-uniformities of [`Vector3::delta_e`] in 
+    /// the saturated and blue colours.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \Delta E^{*}_{00} = \sqrt{
+    ///     \left( \frac{\Delta L'}{S_{L}} \right)^2 + 
+    ///     \left( \frac{\Delta C'}{S_{C}} \right)^2 + 
+    ///     \left( \frac{\Delta H'}{S_{H}} \right)^2 + 
+    ///     R_{T} \frac{\Delta C'}{S_{C}} \frac{\Delta H'}{S_{H}}
+    /// }
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let blue = Vector3::<u8>::new([0, 0, 255]);
+    /// let purple = Vector3::<u8>::new([40, 0, 255]);
+    /// 
+    /// assert!(blue.delta_e_2000(purple) < blue.delta_e(purple));
+    /// ```
+    pub fn delta_e_2000(self, other: Self) -> f64 {
+        ciede2000(self.to_lab(), other.to_lab())
+    }
+}
+
+/// Returns the CIEDE2000 colour difference between two CIELAB colours.
+fn ciede2000(lab1: Vector3<f64>, lab2: Vector3<f64>) -> f64 {
+    let (l1, a1, b1) = (lab1[0], lab1[1], lab1[2]);
+    let (l2, a2, b2) = (lab2[0], lab2[1], lab2[2]);
+
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+
+    // Corrected a-coordinates, chromas and hues.
+    let a1 = a1 * (1.0 + g);
+    let a2 = a2 * (1.0 + g);
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let h1 = b1.atan2(a1).to_degrees().rem_euclid(360.0);
+    let h2 = b2.atan2(a2).to_degrees().rem_euclid(360.0);
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 
+        - 0.17 * (h_mean - 30.0).to_radians().cos() 
+        + 0.24 * (2.0 * h_mean).to_radians().cos() 
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos() 
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+
+    let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+
+    let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let r_t = -r_c * (2.0 * delta_theta).to_radians().sin();
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
@@ -0,0 +1,72 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Error returned by the fallible functions and conversions of the crate,
+//! instead of panicking.
+
+use crate::colours::ParseColourError;
+
+/// Error of the fallible functions and conversions of vectors and matrices.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     vector::Vector3,
+///     Error,
+/// };
+///
+/// let vector = Vector3::<i32>::try_from(vec![1, 2]);
+///
+/// assert_eq!(vector, Err(Error::DimensionMismatch {
+///     expected: (1, 3),
+///     found: (1, 2),
+/// }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The dimensions of the values do not match the expected ones. Both are
+    /// given as `(columns, rows)`, a vector being a single column.
+    DimensionMismatch {
+        /// Expected dimensions.
+        expected: (usize, usize),
+        /// Dimensions of the given values.
+        found: (usize, usize),
+    },
+    /// The matrix has no inverse, its determinant being zero.
+    SingularMatrix,
+    /// The string cannot be parsed, contains the reason.
+    Parse(ParseColourError),
+    /// The vector cannot be normalized since its length is zero.
+    ZeroVector,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimension mismatch, expected {} columns and {} rows but found {} columns and {} rows",
+                expected.0, expected.1, found.0, found.1,
+            ),
+            Self::SingularMatrix => write!(f, "singular matrix"),
+            Self::Parse(error) => write!(f, "parse error: {}", error),
+            Self::ZeroVector => write!(f, "zero vector cannot be normalized"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseColourError> for Error {
+    fn from(value: ParseColourError) -> Self {
+        Self::Parse(value)
+    }
+}
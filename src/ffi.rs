@@ -0,0 +1,103 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Plain structures with the C layout, to pass vectors and matrices of `f32`
+//! values through a C ABI boundary, such as to scripting languages or
+//! plugins.
+//!
+//! They match the following C declarations, and are converted losslessly from
+//! and to the linbra types:
+//! ```c
+//! typedef struct { float x, y; } LinbraVec2f;
+//! typedef struct { float x, y, z; } LinbraVec3f;
+//! typedef struct { float x, y, z, w; } LinbraVec4f;
+//! typedef struct { float columns[4][4]; } LinbraMat4f;
+//! ```
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     ffi::LinbraVec3f,
+//!     vector::Vector3,
+//! };
+//!
+//! extern "C" fn length_squared(vector: LinbraVec3f) -> f32 {
+//!     let vector = Vector3::from(vector);
+//!     vector.dot(vector)
+//! }
+//!
+//! assert_eq!(length_squared(Vector3::new([1.0, 2.0, 2.0]).into()), 9.0);
+//! ```
+
+use crate::{
+    matrix::Matrix4,
+    vector::{ Vector2, Vector3, Vector4 },
+};
+
+macro_rules! impl_ffi_vector {
+    ($name:ident, $vector:ident, $size:literal, $($field:ident => $index:literal),+) => {
+        #[doc = concat!("Vector of ", $size, " `f32` values with the C layout.")]
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $name {
+            $(
+                #[doc = concat!("Value at index ", $index, ".")]
+                pub $field: f32,
+            )+
+        }
+
+        impl From<$vector<f32>> for $name {
+            fn from(value: $vector<f32>) -> Self {
+                Self { $($field: value[$index]),+ }
+            }
+        }
+
+        impl From<$name> for $vector<f32> {
+            fn from(value: $name) -> Self {
+                $vector::new([$(value.$field),+])
+            }
+        }
+    };
+}
+
+impl_ffi_vector!(LinbraVec2f, Vector2, 2, x => 0, y => 1);
+impl_ffi_vector!(LinbraVec3f, Vector3, 3, x => 0, y => 1, z => 2);
+impl_ffi_vector!(LinbraVec4f, Vector4, 4, x => 0, y => 1, z => 2, w => 3);
+
+/// 4x4 matrix of `f32` values with the C layout, stored column by column as
+/// the linbra matrices and the graphics APIs.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     ffi::LinbraMat4f,
+///     matrix::Matrix4,
+/// };
+///
+/// let mut matrix = Matrix4::<f32>::identity();
+/// matrix[3][0] = 5.0;
+///
+/// let exported = LinbraMat4f::from(matrix);
+///
+/// assert_eq!(exported.columns[3][0], 5.0);
+/// assert_eq!(Matrix4::from(exported), matrix);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct LinbraMat4f {
+    /// Values of the matrix, column by column.
+    pub columns: [[f32; 4]; 4],
+}
+
+impl From<Matrix4<f32>> for LinbraMat4f {
+    fn from(value: Matrix4<f32>) -> Self {
+        Self { columns: value.to_cols_array_2d() }
+    }
+}
+
+impl From<LinbraMat4f> for Matrix4<f32> {
+    fn from(value: LinbraMat4f) -> Self {
+        Matrix4::new(value.columns)
+    }
+}
@@ -0,0 +1,196 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Least-squares fitting of transformations and primitives to sets of points,
+//! such as recorded or scanned point clouds.
+//!
+//! As in the [`geometry`](crate::geometry) module, the fitted primitives are
+//! described by vectors only: a plane by one of its points and its normal, and
+//! a line by one of its points and its direction.
+
+use crate::{
+    ga::{ Bivector3, Rotor3 },
+    matrix::{ Matrix, Matrix4 },
+    vector::{ Vector, Vector2, Vector3 },
+    Float,
+};
+
+/// Returns the centroid of the points and the eigenvectors of their covariance
+/// matrix, from the direction of the greatest spread of the points to the one
+/// of the least spread.
+///
+/// The values are `NaN` when there is no point.
+fn principal_axes<T: Float, const N: usize>(
+    points: &[Vector<T, N>],
+) -> (Vector<T, N>, Matrix<T, N, N>) {
+    let (_, axes) = Matrix::covariance(points).symmetric_eigen();
+    (Vector::centroid(points), axes)
+}
+
+/// Returns the plane the closest to the points in the least-squares sense, as
+/// the centroid of the points and the normal of the plane, such as to estimate
+/// the ground under scanned points.
+///
+/// The normal is the direction along which the points spread the least, being
+/// normalized and of an arbitrary side of the plane.
+///
+/// The values are `NaN` when there is no point.
+///
+/// ## Formula
+/// $$ \min_{n} \sum_{i} \left( (p_{i} - \bar{p}) \cdot n \right)^2, \quad \lVert n \rVert = 1 $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     fitting::fit_plane,
+///     vector::Vector3,
+/// };
+///
+/// let points = [
+///     Vector3::<f64>::new([0.0, 1.0, 0.0]),
+///     Vector3::new([4.0, 1.0, 0.0]),
+///     Vector3::new([0.0, 1.0, 4.0]),
+///     Vector3::new([4.0, 1.0, 4.0]),
+/// ];
+///
+/// let (origin, normal) = fit_plane(&points);
+///
+/// assert_eq!(origin, Vector3::new([2.0, 1.0, 2.0]));
+/// assert!((normal[1].abs() - 1.0).abs() < 1e-12);
+///
+/// let (origin, normal) = fit_plane::<f64>(&[]);
+/// assert!(origin[0].is_nan() && normal[0].is_nan());
+/// ```
+pub fn fit_plane<T: Float>(points: &[Vector3<T>]) -> (Vector3<T>, Vector3<T>) {
+    let (centroid, axes) = principal_axes(points);
+    (centroid, Vector::new(axes[2]))
+}
+
+/// Returns the line the closest to the points in the least-squares sense, as
+/// the centroid of the points and the direction of the line, such as to
+/// straighten a drawn path.
+///
+/// The direction is the one along which the points spread the most, being
+/// normalized and of an arbitrary way along the line.
+///
+/// The values are `NaN` when there is no point.
+///
+/// ## Formula
+/// $$ \max_{d} \sum_{i} \left( (p_{i} - \bar{p}) \cdot d \right)^2, \quad \lVert d \rVert = 1 $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     fitting::fit_line,
+///     vector::Vector2,
+/// };
+///
+/// let points = [
+///     Vector2::<f64>::new([0.0, 1.0]),
+///     Vector2::new([1.0, 2.0]),
+///     Vector2::new([2.0, 3.0]),
+///     Vector2::new([3.0, 4.0]),
+/// ];
+///
+/// let (origin, direction) = fit_line(&points);
+///
+/// assert_eq!(origin, Vector2::new([1.5, 2.5]));
+/// assert!((direction[0] * direction[1] - 0.5).abs() < 1e-12);
+///
+/// let (origin, direction) = fit_line::<f64>(&[]);
+/// assert!(origin[0].is_nan() && direction[0].is_nan());
+/// ```
+pub fn fit_line<T: Float>(points: &[Vector2<T>]) -> (Vector2<T>, Vector2<T>) {
+    let (centroid, axes) = principal_axes(points);
+    (centroid, Vector::new(axes[0]))
+}
+
+/// Returns the rotation and then the translation moving the points `from` the
+/// closest to the points `to` of the same indices, in the least-squares
+/// sense, such as to snap a recorded pose onto a ragdoll.
+///
+/// The rotation is computed with the method of Horn, giving the same optimal
+/// rotation as the algorithm of Kabsch: it is the unit quaternion, written as
+/// a rotor, maximizing the alignment of the centered points, being the
+/// eigenvector of the greatest eigenvalue of a 4x4 symmetric matrix built
+/// from their cross-covariance.
+///
+/// The rotation is the identity and the translation is `NaN` when there is no
+/// point.
+///
+/// Panics when the slices do not have the same length.
+///
+/// ## Formula
+/// $$ \min_{R, t} \sum_{i} \lVert R p_{i} + t - q_{i} \rVert^2, \quad t = \bar{q} - R \bar{p} $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     fitting::rigid_transform,
+///     ga::Rotor3,
+///     vector::Vector3,
+/// };
+///
+/// let rotor = Rotor3::from_to(Vector3::new([1.0, 0.0, 0.0]), Vector3::new([0.0, 0.0, 1.0]));
+/// let translation = Vector3::new([5.0, -2.0, 1.0]);
+///
+/// let from = [
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     Vector3::new([1.0, 0.0, 0.0]),
+///     Vector3::new([0.0, 2.0, 0.0]),
+///     Vector3::new([0.0, 0.0, 3.0]),
+/// ];
+/// let to = from.map(|point| rotor.rotate(point) + translation);
+///
+/// let (fitted_rotor, fitted_translation) = rigid_transform(&from, &to);
+///
+/// for (point, expected) in from.into_iter().zip(to) {
+///     assert!((fitted_rotor.rotate(point) + fitted_translation).distance(expected) < 1e-9);
+/// }
+/// ```
+pub fn rigid_transform<T: Float>(
+    from: &[Vector3<T>],
+    to: &[Vector3<T>],
+) -> (Rotor3<T>, Vector3<T>) {
+    assert_eq!(from.len(), to.len(), "as many points to fit as target points");
+
+    let from_centroid = Vector::centroid(from);
+    let to_centroid = Vector::centroid(to);
+
+    // Cross-covariance of the centered points, `s[a][b]` summing the
+    // products of the axis `a` of the points `from` by the axis `b` of the
+    // points `to`.
+    let mut s = [[T::zero(); 3]; 3];
+
+    for (p, q) in from.iter().zip(to) {
+        let p = *p - from_centroid;
+        let q = *q - to_centroid;
+
+        for (a, row) in s.iter_mut().enumerate() {
+            for (b, value) in row.iter_mut().enumerate() {
+                *value += p[a] * q[b];
+            }
+        }
+    }
+
+    let [[xx, xy, xz], [yx, yy, yz], [zx, zy, zz]] = s;
+    let horn = Matrix4::natural([
+        [xx + yy + zz, yz - zy, zx - xz, xy - yx],
+        [yz - zy, xx - yy - zz, xy + yx, zx + xz],
+        [zx - xz, xy + yx, yy - xx - zz, yz + zy],
+        [xy - yx, zx + xz, yz + zy, zz - xx - yy],
+    ]);
+
+    let (_, vectors) = horn.symmetric_eigen();
+    let [w, x, y, z] = vectors[0];
+
+    // The rotor of the quaternion `w + x i + y j + z k`, its bivector being
+    // the plane dual to the axis of rotation.
+    let rotor = Rotor3 {
+        scalar: w,
+        bivector: Bivector3 { xy: -z, yz: -x, zx: -y },
+    }.normalize();
+
+    (rotor, to_centroid - rotor.rotate(from_centroid))
+}
@@ -0,0 +1,496 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Geometric algebra primitives: bivectors and rotors in 2D and 3D.
+//!
+//! A bivector is an oriented plane segment, as a vector is an oriented line
+//! segment. The wedge product of two vectors $a \wedge b$ is the bivector of
+//! the parallelogram they span, oriented from $a$ to $b$. Its components are
+//! the areas of the projections of the parallelogram on the planes of the
+//! basis, `xy`, `yz` and `zx`.
+//!
+//! A rotor is the sum of a scalar and a bivector, rotating vectors in the
+//! plane of its bivector with the "sandwich" product $v' = R v R^{\dagger}$,
+//! where $R^{\dagger}$ is the reverse of the rotor, its bivector being negated.
+//! Rotors play the same role as the unit quaternions, but are built from
+//! planes instead of axes, so they work the same way in 2D and in 3D.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     ga::Rotor3,
+//!     vector::Vector3,
+//! };
+//!
+//! let x = Vector3::new([1.0, 0.0, 0.0]);
+//! let y = Vector3::new([0.0, 1.0, 0.0]);
+//!
+//! // Rotates by a quarter turn from the x-axis towards the y-axis.
+//! let rotor = Rotor3::from_vectors(x, y);
+//!
+//! assert!(rotor.rotate(x).distance(y) < 1e-9);
+//! assert!(rotor.rotate(y).distance(-x) < 1e-9);
+//! ```
+
+use std::ops;
+
+use crate::Float;
+use crate::vector::{Vector2, Vector3};
+
+/// Bivector of the 2D plan, having a single component since there is only one
+/// plane.
+///
+/// $$ B = b_{xy} \, e_{x} \wedge e_{y} $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bivector2<T> {
+    /// Component in the `xy` plane, being the signed area of the bivector.
+    pub xy: T,
+}
+
+impl<T: Float> Bivector2<T> {
+    /// Returns the wedge product of the two vectors, being the bivector of
+    /// the parallelogram they span, oriented from `a` to `b`.
+    ///
+    /// ## Formula
+    /// $$ a \wedge b = (a_{x} b_{y} - a_{y} b_{x}) \, e_{x} \wedge e_{y} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Bivector2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let area = Bivector2::wedge(Vector2::new([2.0, 0.0]), Vector2::new([1.0, 3.0]));
+    /// assert_eq!(area.xy, 6.0);
+    /// ```
+    pub fn wedge(a: Vector2<T>, b: Vector2<T>) -> Self {
+        Self { xy: a.perp_dot(b) }
+    }
+}
+
+/// Bivector of the 3D space, made of its projections on the three planes of
+/// the basis.
+///
+/// $$
+/// B = b_{xy} \, e_{x} \wedge e_{y} + b_{yz} \, e_{y} \wedge e_{z}
+///     + b_{zx} \, e_{z} \wedge e_{x}
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bivector3<T> {
+    /// Component in the `xy` plane.
+    pub xy: T,
+    /// Component in the `yz` plane.
+    pub yz: T,
+    /// Component in the `zx` plane.
+    pub zx: T,
+}
+
+impl<T: Float> Bivector3<T> {
+    /// Returns the wedge product of the two vectors, being the bivector of
+    /// the parallelogram they span, oriented from `a` to `b`.
+    ///
+    /// Its components are the ones of the cross product, which is the normal
+    /// of the plane instead of the plane itself.
+    ///
+    /// ## Formula
+    /// $$
+    /// a \wedge b = (a_{x} b_{y} - a_{y} b_{x}) \, e_{x} \wedge e_{y}
+    ///     + (a_{y} b_{z} - a_{z} b_{y}) \, e_{y} \wedge e_{z}
+    ///     + (a_{z} b_{x} - a_{x} b_{z}) \, e_{z} \wedge e_{x}
+    /// $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Bivector3,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let x = Vector3::new([1.0, 0.0, 0.0]);
+    /// let y = Vector3::new([0.0, 1.0, 0.0]);
+    ///
+    /// assert_eq!(Bivector3::wedge(x, y), Bivector3 { xy: 1.0, yz: 0.0, zx: 0.0 });
+    /// ```
+    pub fn wedge(a: Vector3<T>, b: Vector3<T>) -> Self {
+        let [ax, ay, az]: [T; 3] = a.into();
+        let [bx, by, bz]: [T; 3] = b.into();
+
+        Self {
+            xy: ax * by - ay * bx,
+            yz: ay * bz - az * by,
+            zx: az * bx - ax * bz,
+        }
+    }
+
+    /// Returns the magnitude of the bivector, being the area of the
+    /// parallelogram it represents.
+    ///
+    /// ## Formula
+    /// $$ \lVert B \rVert = \sqrt{b_{xy}^2 + b_{yz}^2 + b_{zx}^2} $$
+    pub fn magnitude(self) -> T {
+        (self.xy * self.xy + self.yz * self.yz + self.zx * self.zx).sqrt()
+    }
+}
+
+/// Rotor of the 2D plan, rotating vectors around the origin.
+///
+/// $$ R = s + b_{xy} \, e_{x} \wedge e_{y} $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor2<T> {
+    /// Scalar part, being the cosine of half the angle of rotation.
+    pub scalar: T,
+    /// Bivector part, being the opposite of the sine of half the angle of
+    /// rotation.
+    pub xy: T,
+}
+
+impl<T: Float> Rotor2<T> {
+    /// Creates a new rotor rotating by `angle` radians counter-clockwise.
+    ///
+    /// ## Formula
+    /// $$ R = \cos \frac{\theta}{2} - \sin \frac{\theta}{2} \, e_{x} \wedge e_{y} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Rotor2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let rotor = Rotor2::from_angle(std::f64::consts::FRAC_PI_2);
+    /// let rotated = rotor.rotate(Vector2::new([1.0, 0.0]));
+    ///
+    /// assert!(rotated.distance(Vector2::new([0.0, 1.0])) < 1e-9);
+    /// ```
+    pub fn from_angle(angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        Self { scalar: half.cos(), xy: -half.sin() }
+    }
+
+    /// Creates a new rotor rotating the unit vector `from` onto the unit
+    /// vector `to`.
+    ///
+    /// The vectors must not be opposite, the plane of rotation being
+    /// undefined.
+    ///
+    /// ## Formula
+    /// $$ R = \frac{1 + b \cdot a + b \wedge a}{\lVert 1 + b \cdot a + b \wedge a \rVert} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Rotor2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let from = Vector2::new([1.0, 0.0]);
+    /// let to = Vector2::new([0.6, 0.8]);
+    ///
+    /// assert!(Rotor2::from_vectors(from, to).rotate(from).distance(to) < 1e-9);
+    /// ```
+    pub fn from_vectors(from: Vector2<T>, to: Vector2<T>) -> Self {
+        Self {
+            scalar: T::one() + to.dot(from),
+            xy: Bivector2::wedge(to, from).xy,
+        }.normalize()
+    }
+
+    /// Returns the rotor scaled to a magnitude of one, as needed to rotate
+    /// without scaling.
+    ///
+    /// ## Formula
+    /// $$ \hat{R} = \frac{R}{\sqrt{s^2 + b_{xy}^2}} $$
+    pub fn normalize(self) -> Self {
+        let length = (self.scalar * self.scalar + self.xy * self.xy).sqrt();
+        Self { scalar: self.scalar / length, xy: self.xy / length }
+    }
+
+    /// Returns the reverse of the rotor, rotating in the opposite direction.
+    ///
+    /// ## Formula
+    /// $$ R^{\dagger} = s - b_{xy} \, e_{x} \wedge e_{y} $$
+    pub fn reverse(self) -> Self {
+        Self { scalar: self.scalar, xy: -self.xy }
+    }
+
+    /// Returns the vector rotated by the rotor.
+    ///
+    /// ## Formula
+    /// $$ v' = R v R^{\dagger} $$
+    pub fn rotate(self, vector: Vector2<T>) -> Vector2<T> {
+        let [x, y]: [T; 2] = vector.into();
+        let Self { scalar: s, xy: b } = self;
+
+        let qx = s * x + b * y;
+        let qy = s * y - b * x;
+
+        Vector2::new([s * qx + b * qy, s * qy - b * qx])
+    }
+}
+
+/// Composition of two rotors, rotating by `rhs` and then by `self`.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     ga::Rotor2,
+///     vector::Vector2,
+/// };
+///
+/// let eighth = Rotor2::from_angle(std::f64::consts::FRAC_PI_4);
+/// let rotated = (eighth * eighth).rotate(Vector2::new([1.0, 0.0]));
+///
+/// assert!(rotated.distance(Vector2::new([0.0, 1.0])) < 1e-9);
+/// ```
+impl<T: Float> ops::Mul<Self> for Rotor2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            scalar: self.scalar * rhs.scalar - self.xy * rhs.xy,
+            xy: self.scalar * rhs.xy + self.xy * rhs.scalar,
+        }
+    }
+}
+
+/// Rotor of the 3D space, rotating vectors around the origin.
+///
+/// $$
+/// R = s + b_{xy} \, e_{x} \wedge e_{y} + b_{yz} \, e_{y} \wedge e_{z}
+///     + b_{zx} \, e_{z} \wedge e_{x}
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor3<T> {
+    /// Scalar part, being the cosine of half the angle of rotation.
+    pub scalar: T,
+    /// Bivector part, being the plane of rotation scaled by the opposite of
+    /// the sine of half the angle of rotation.
+    pub bivector: Bivector3<T>,
+}
+
+impl<T: Float> Rotor3<T> {
+    /// Creates a new rotor rotating by `angle` radians in the `plane`, from
+    /// its first vector towards its second one.
+    ///
+    /// The plane is normalized, so only its orientation matters.
+    ///
+    /// ## Formula
+    /// $$ R = \cos \frac{\theta}{2} - \sin \frac{\theta}{2} \, \hat{B} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::{ Bivector3, Rotor3 },
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let y = Vector3::new([0.0, 1.0, 0.0]);
+    /// let z = Vector3::new([0.0, 0.0, 1.0]);
+    ///
+    /// let rotor = Rotor3::from_angle_plane(std::f64::consts::FRAC_PI_2, Bivector3::wedge(y, z));
+    /// assert!(rotor.rotate(y).distance(z) < 1e-9);
+    /// ```
+    pub fn from_angle_plane(angle: T, plane: Bivector3<T>) -> Self {
+        let half = angle / (T::one() + T::one());
+        let factor = -half.sin() / plane.magnitude();
+
+        Self {
+            scalar: half.cos(),
+            bivector: Bivector3 {
+                xy: plane.xy * factor,
+                yz: plane.yz * factor,
+                zx: plane.zx * factor,
+            },
+        }
+    }
+
+    /// Creates a new rotor rotating the unit vector `from` onto the unit
+    /// vector `to`, in the plane they span.
+    ///
+    /// The vectors must not be opposite, the plane of rotation being
+    /// undefined.
+    ///
+    /// ## Formula
+    /// $$ R = \frac{1 + b \cdot a + b \wedge a}{\lVert 1 + b \cdot a + b \wedge a \rVert} $$
+    pub fn from_vectors(from: Vector3<T>, to: Vector3<T>) -> Self {
+        Self {
+            scalar: T::one() + to.dot(from),
+            bivector: Bivector3::wedge(to, from),
+        }.normalize()
+    }
+
+    /// Creates a new rotor rotating the direction `from` onto the direction
+    /// `to` along the shortest arc, as a homing missile turning towards its
+    /// target.
+    ///
+    /// Unlike [`Rotor3::from_vectors`], the directions do not need to be
+    /// normalized, and opposite directions are rotated by a half turn in a
+    /// plane containing them.
+    ///
+    /// Panics when one of the directions is the zero vector.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Rotor3,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let x = Vector3::new([2.0, 0.0, 0.0]);
+    /// let y = Vector3::new([0.0, 3.0, 0.0]);
+    ///
+    /// assert!(Rotor3::from_to(x, y).rotate(x).distance(Vector3::new([0.0, 2.0, 0.0])) < 1e-9);
+    /// assert!(Rotor3::from_to(x, -x).rotate(x).distance(-x) < 1e-9);
+    /// ```
+    pub fn from_to(from: Vector3<T>, to: Vector3<T>) -> Self {
+        let from = from.normalize().expect("zero direction");
+        let to = to.normalize().expect("zero direction");
+
+        let rotor = Self {
+            scalar: T::one() + to.dot(from),
+            bivector: Bivector3::wedge(to, from),
+        };
+
+        if rotor.scalar > T::zero() || rotor.bivector.magnitude() > T::zero() {
+            return rotor.normalize();
+        }
+
+        // Opposite directions, rotated by a half turn in the plane of `from`
+        // and the axis of the basis the most perpendicular to it.
+        let (zero, one) = (T::zero(), T::one());
+        let axis = if from[0] * from[0] < from[1] * from[1] {
+            Vector3::new([one, zero, zero])
+        } else {
+            Vector3::new([zero, one, zero])
+        };
+        Self::from_angle_plane(T::PI, Bivector3::wedge(from, from.cross(axis)))
+    }
+
+    /// Creates a new rotor rotating the z-axis onto `forward` and the y-axis
+    /// towards `up`, orienting an object such as a turret to face a
+    /// direction while staying upright.
+    ///
+    /// The y-axis is rotated onto the part of `up` perpendicular to
+    /// `forward`.
+    ///
+    /// Panics when `forward` is the zero vector, or when `up` is parallel to
+    /// it.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     ga::Rotor3,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let forward = Vector3::new([1.0, 0.0, 0.0]);
+    /// let up = Vector3::new([1.0, 0.0, 1.0]);
+    ///
+    /// let rotor = Rotor3::look_rotation(forward, up);
+    ///
+    /// assert!(rotor.rotate(Vector3::new([0.0, 0.0, 1.0])).distance(forward) < 1e-9);
+    /// assert!(rotor.rotate(Vector3::new([0.0, 1.0, 0.0])).distance(Vector3::new([0.0, 0.0, 1.0])) < 1e-9);
+    /// ```
+    pub fn look_rotation(forward: Vector3<T>, up: Vector3<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let forward = forward.normalize().expect("zero forward direction");
+        let up = (up - forward * up.dot(forward))
+            .normalize()
+            .expect("up parallel to the forward direction");
+
+        let aim = Self::from_to(Vector3::new([zero, zero, one]), forward);
+
+        // Twists around the forward direction to bring the rotated y-axis
+        // onto the up direction.
+        let y = aim.rotate(Vector3::new([zero, one, zero]));
+        let angle = forward.dot(y.cross(up)).atan2(y.dot(up));
+        let twist = Self::from_angle_plane(angle, Bivector3::wedge(y, forward.cross(y)));
+
+        twist * aim
+    }
+
+    /// Returns the rotor scaled to a magnitude of one, as needed to rotate
+    /// without scaling.
+    ///
+    /// ## Formula
+    /// $$ \hat{R} = \frac{R}{\sqrt{s^2 + b_{xy}^2 + b_{yz}^2 + b_{zx}^2}} $$
+    pub fn normalize(self) -> Self {
+        let Bivector3 { xy, yz, zx } = self.bivector;
+        let length = (self.scalar * self.scalar + xy * xy + yz * yz + zx * zx).sqrt();
+
+        Self {
+            scalar: self.scalar / length,
+            bivector: Bivector3 { xy: xy / length, yz: yz / length, zx: zx / length },
+        }
+    }
+
+    /// Returns the reverse of the rotor, rotating in the opposite direction.
+    ///
+    /// ## Formula
+    /// $$ R^{\dagger} = s - B $$
+    pub fn reverse(self) -> Self {
+        let Bivector3 { xy, yz, zx } = self.bivector;
+        Self { scalar: self.scalar, bivector: Bivector3 { xy: -xy, yz: -yz, zx: -zx } }
+    }
+
+    /// Returns the vector rotated by the rotor.
+    ///
+    /// ## Formula
+    /// $$ v' = R v R^{\dagger} $$
+    ///
+    /// The product $q = R v$ is computed first, being made of a vector and a
+    /// trivector, and then $q R^{\dagger}$, whose trivector part cancels out.
+    pub fn rotate(self, vector: Vector3<T>) -> Vector3<T> {
+        let [x, y, z]: [T; 3] = vector.into();
+        let s = self.scalar;
+        let Bivector3 { xy, yz, zx } = self.bivector;
+
+        let qx = s * x + xy * y - zx * z;
+        let qy = s * y - xy * x + yz * z;
+        let qz = s * z - yz * y + zx * x;
+        let qxyz = xy * z + yz * x + zx * y;
+
+        Vector3::new([
+            s * qx + xy * qy + yz * qxyz - zx * qz,
+            s * qy - xy * qx + yz * qz + zx * qxyz,
+            s * qz + xy * qxyz - yz * qy + zx * qx,
+        ])
+    }
+}
+
+/// Composition of two rotors, rotating by `rhs` and then by `self`.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     ga::Rotor3,
+///     vector::Vector3,
+/// };
+///
+/// let x = Vector3::new([1.0, 0.0, 0.0]);
+/// let y = Vector3::new([0.0, 1.0, 0.0]);
+/// let z = Vector3::new([0.0, 0.0, 1.0]);
+///
+/// let rotor = Rotor3::from_vectors(y, z) * Rotor3::from_vectors(x, y);
+/// assert!(rotor.rotate(x).distance(z) < 1e-9);
+/// ```
+impl<T: Float> ops::Mul<Self> for Rotor3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.scalar, rhs.scalar);
+        let Bivector3 { xy: a12, yz: a23, zx: a31 } = self.bivector;
+        let Bivector3 { xy: b12, yz: b23, zx: b31 } = rhs.bivector;
+
+        Self {
+            scalar: a * b - a12 * b12 - a23 * b23 - a31 * b31,
+            bivector: Bivector3 {
+                xy: a * b12 + a12 * b - a23 * b31 + a31 * b23,
+                yz: a * b23 + a23 * b + a12 * b31 - a31 * b12,
+                zx: a * b31 + a31 * b - a12 * b23 + a23 * b12,
+            },
+        }
+    }
+}
@@ -0,0 +1,907 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Geometric queries between points and primitives, such as the closest point
+//! of a segment, a plane or an axis-aligned bounding box to a given point.
+//!
+//! Primitives are described by vectors only:
+//! - a segment by its two ends `a` and `b`,
+//! - a plane by one of its points `origin` and its `normal` (not necessarily
+//!   normalized),
+//! - an axis-aligned bounding box by its `min` and `max` corners,
+//! - a sphere by its `centre` and its `radius`,
+//! - a ray by its `origin` and its `direction` (not necessarily normalized),
+//!   its points being $o + t d$ for $t \ge 0$.
+//!
+//! The [`Capsule`] is the exception, being a segment thickened by a radius as
+//! used for the bodies of characters.
+//!
+//! The intersections of rays with primitives give a [`Hit`], to be used by
+//! simple ray tracers or to resolve the impacts of projectiles.
+
+use crate::{ Error, Float };
+use crate::vector::Vector;
+
+/// Intersection of a ray with a primitive.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hit<T, const N: usize> {
+    /// Parameter of the intersection along the ray, being the distance from
+    /// its origin in lengths of its direction.
+    pub t: T,
+    /// Point of intersection.
+    pub point: Vector<T, N>,
+    /// Normalized normal of the primitive at the point of intersection.
+    pub normal: Vector<T, N>,
+}
+
+/// Returns the first intersection of the ray with the plane, or `None` when
+/// the ray is parallel to the plane or goes away from it.
+///
+/// The normal of the hit faces the origin of the ray, as needed to reflect
+/// it.
+///
+/// ## Formula
+/// $$ t = \frac{(o_{p} - o) \cdot n}{d \cdot n} $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::intersect_ray_plane,
+///     vector::Vector3,
+/// };
+///
+/// let hit = intersect_ray_plane(
+///     Vector3::new([1.0, 5.0, 2.0]),
+///     Vector3::new([0.0, -2.0, 0.0]),
+///     Vector3::new([0.0, 1.0, 0.0]),
+///     Vector3::new([0.0, 3.0, 0.0]),
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 2.0);
+/// assert_eq!(hit.point, Vector3::new([1.0, 1.0, 2.0]));
+/// assert_eq!(hit.normal, Vector3::new([0.0, 1.0, 0.0]));
+/// ```
+pub fn intersect_ray_plane<T: Float, const N: usize>(
+    origin: Vector<T, N>,
+    direction: Vector<T, N>,
+    plane_origin: Vector<T, N>,
+    plane_normal: Vector<T, N>,
+) -> Option<Hit<T, N>> {
+    let normal = plane_normal.normalize().ok()?;
+    let denominator = direction.dot(normal);
+
+    if denominator == T::zero() {
+        return None;
+    }
+
+    let t = (plane_origin - origin).dot(normal) / denominator;
+
+    if t < T::zero() {
+        return None;
+    }
+
+    Some(Hit {
+        t,
+        point: origin + direction * t,
+        normal: if denominator > T::zero() { -normal } else { normal },
+    })
+}
+
+/// Returns the first intersection of the ray with the sphere, or `None` when
+/// the ray misses it or when the radius is not positive.
+///
+/// When the origin of the ray is inside the sphere, the hit is where the ray
+/// leaves it. The normal of the hit always points out of the sphere.
+///
+/// ## Formula
+/// With $c$ the centre and $r$ the radius, $t$ is the smallest non-negative
+/// solution of:
+/// $$ \lVert o + t d - c \rVert^2 = r^2 $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::intersect_ray_sphere,
+///     vector::Vector3,
+/// };
+///
+/// let hit = intersect_ray_sphere(
+///     Vector3::new([0.0, 0.0, -5.0]),
+///     Vector3::new([0.0, 0.0, 1.0]),
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     2.0,
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 3.0);
+/// assert_eq!(hit.point, Vector3::new([0.0, 0.0, -2.0]));
+/// assert_eq!(hit.normal, Vector3::new([0.0, 0.0, -1.0]));
+///
+/// let miss = intersect_ray_sphere(
+///     Vector3::new([0.0, 3.0, -5.0]),
+///     Vector3::new([0.0, 0.0, 1.0]),
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     2.0,
+/// );
+///
+/// assert_eq!(miss, None);
+///
+/// let point = intersect_ray_sphere(
+///     Vector3::new([0.0, 0.0, -5.0]),
+///     Vector3::new([0.0, 0.0, 1.0]),
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     0.0,
+/// );
+///
+/// assert_eq!(point, None);
+/// ```
+pub fn intersect_ray_sphere<T: Float, const N: usize>(
+    origin: Vector<T, N>,
+    direction: Vector<T, N>,
+    centre: Vector<T, N>,
+    radius: T,
+) -> Option<Hit<T, N>> {
+    let a = direction.length_squared();
+
+    // The normal of a sphere without a positive radius would not be a unit
+    // vector, dividing by the radius.
+    if a == T::zero() || radius <= T::zero() {
+        return None;
+    }
+
+    let offset = origin - centre;
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - a * c;
+
+    if discriminant < T::zero() {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let mut t = (-b - root) / a;
+
+    if t < T::zero() {
+        t = (-b + root) / a;
+    }
+
+    if t < T::zero() {
+        return None;
+    }
+
+    let point = origin + direction * t;
+    let normal = (point - centre) * (T::one() / radius);
+
+    Some(Hit { t, point, normal })
+}
+
+/// Returns the value clamped between `min` and `max`.
+fn clamp<T: Float>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Implements the closest-point and distance queries for types implementing
+/// the [`Float`] trait.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the point of the segment `[a, b]` which is the closest
+    /// to this point.
+    ///
+    /// ## Formula
+    /// $$
+    /// t = \frac{(p - a) \cdot (b - a)}{\lVert b - a \rVert^2}, \quad
+    /// c = a + \min(\max(t, 0), 1) \times (b - a)
+    /// $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let point = Vector2::new([2.0, 3.0]);
+    /// let closest = point.closest_point_on_segment(
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([4.0, 0.0]),
+    /// );
+    ///
+    /// assert_eq!(closest, Vector2::new([2.0, 0.0]));
+    /// ```
+    pub fn closest_point_on_segment(self, a: Self, b: Self) -> Self {
+        let ab = b - a;
+        let length_squared = ab.length_squared();
+
+        // Degenerated segment, both ends are the same point.
+        if length_squared == T::zero() {
+            return a;
+        }
+
+        let t = (self - a).dot(ab) / length_squared;
+        a + ab * clamp(t, T::zero(), T::one())
+    }
+
+    /// Returns the distance between this point and the segment
+    /// `[a, b]`.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let point = Vector2::new([7.0, 4.0]);
+    /// let distance = point.distance_to_segment(
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([4.0, 0.0]),
+    /// );
+    ///
+    /// assert_eq!(distance, 5.0);
+    /// ```
+    pub fn distance_to_segment(self, a: Self, b: Self) -> T {
+        self.distance(self.closest_point_on_segment(a, b))
+    }
+
+    /// Returns the point of the plane going through `origin` and
+    /// oriented by `normal` which is the closest to this point.
+    ///
+    /// Fails with [`Error::ZeroVector`] when the normal is the zero vector,
+    /// not orienting any plane.
+    ///
+    /// ## Formula
+    /// $$
+    /// c = p - \frac{(p - o) \cdot n}{\lVert n \rVert^2} \times n
+    /// $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     vector::Vector3,
+    ///     Error,
+    /// };
+    ///
+    /// let point = Vector3::new([1.0, 5.0, 2.0]);
+    /// let origin = Vector3::new([0.0, 1.0, 0.0]);
+    ///
+    /// let closest = point.closest_point_on_plane(origin, Vector3::new([0.0, 2.0, 0.0]));
+    ///
+    /// assert_eq!(closest, Ok(Vector3::new([1.0, 1.0, 2.0])));
+    /// assert_eq!(point.closest_point_on_plane(origin, Vector3::zeroed()), Err(Error::ZeroVector));
+    /// ```
+    pub fn closest_point_on_plane(self, origin: Self, normal: Self) -> Result<Self, Error> {
+        let length_squared = normal.length_squared();
+
+        if length_squared == T::zero() {
+            return Err(Error::ZeroVector);
+        }
+
+        let t = (self - origin).dot(normal) / length_squared;
+        Ok(self - normal * t)
+    }
+
+    /// Returns the distance between this point and the plane going
+    /// through `origin` and oriented by `normal`.
+    ///
+    /// The distance is positive when the point is in front of the
+    /// plane and negative when it is behind. Fails with [`Error::ZeroVector`]
+    /// when the normal is the zero vector, not orienting any plane.
+    ///
+    /// ## Formula
+    /// $$ d = \frac{(p - o) \cdot n}{\lVert n \rVert} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    ///
+    /// let point = Vector3::new([1.0, -3.0, 2.0]);
+    /// let distance = point.distance_to_plane(
+    ///     Vector3::new([0.0, 1.0, 0.0]),
+    ///     Vector3::new([0.0, 2.0, 0.0]),
+    /// );
+    ///
+    /// assert_eq!(distance, Ok(-4.0));
+    /// ```
+    pub fn distance_to_plane(self, origin: Self, normal: Self) -> Result<T, Error> {
+        let length = normal.length();
+
+        if length == T::zero() {
+            return Err(Error::ZeroVector);
+        }
+
+        Ok((self - origin).dot(normal) / length)
+    }
+
+    /// Returns the point of the axis-aligned bounding box going from
+    /// `min` to `max` which is the closest to this point.
+    ///
+    /// When the point is inside the box, the point itself is returned.
+    ///
+    /// ## Formula
+    /// $$ c_{i} = \min(\max(p_{i}, min_{i}), max_{i}) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let point = Vector2::new([5.0, -1.0]);
+    /// let closest = point.closest_point_on_aabb(
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([2.0, 2.0]),
+    /// );
+    ///
+    /// assert_eq!(closest, Vector2::new([2.0, 0.0]));
+    /// ```
+    pub fn closest_point_on_aabb(self, min: Self, max: Self) -> Self {
+        let mut closest = self;
+
+        for n in 0..N {
+            closest[n] = clamp(closest[n], min[n], max[n]);
+        }
+
+        closest
+    }
+
+    /// Returns the distance between this point and the axis-aligned
+    /// bounding box going from `min` to `max`.
+    ///
+    /// The distance is zero when the point is inside the box.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let point = Vector2::new([5.0, 6.0]);
+    /// let distance = point.distance_to_aabb(
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([2.0, 2.0]),
+    /// );
+    ///
+    /// assert_eq!(distance, 5.0);
+    /// ```
+    pub fn distance_to_aabb(self, min: Self, max: Self) -> T {
+        self.distance(self.closest_point_on_aabb(min, max))
+    }
+}
+
+/// Implements the closest-point query of a triangle for 3D points of types
+/// implementing the [`Float`] trait.
+impl<T: Float> Vector<T, 3> {
+    /// Returns the point of the triangle `abc` which is the closest to this
+    /// point.
+    ///
+    /// It is the projection of the point on the plane of the triangle when
+    /// it falls inside the triangle, otherwise the closest point of its
+    /// edges.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    ///
+    /// let (a, b, c) = (
+    ///     Vector3::new([0.0, 0.0, 0.0]),
+    ///     Vector3::new([4.0, 0.0, 0.0]),
+    ///     Vector3::new([0.0, 4.0, 0.0]),
+    /// );
+    ///
+    /// assert_eq!(Vector3::new([1.0, 1.0, 5.0]).closest_point_on_triangle(a, b, c), Vector3::new([1.0, 1.0, 0.0]));
+    /// assert_eq!(Vector3::new([-2.0, 1.0, 0.0]).closest_point_on_triangle(a, b, c), Vector3::new([0.0, 1.0, 0.0]));
+    /// ```
+    pub fn closest_point_on_triangle(self, a: Self, b: Self, c: Self) -> Self {
+        if let Ok(normal) = (b - a).cross(c - a).normalize() {
+            let projection = self - normal * (self - a).dot(normal);
+
+            if is_inside_triangle(projection, a, b, c, normal) {
+                return projection;
+            }
+        }
+
+        [(a, b), (b, c), (c, a)]
+            .map(|(start, end)| self.closest_point_on_segment(start, end))
+            .into_iter()
+            .reduce(|closest, point| {
+                if self.distance(point) < self.distance(closest) { point } else { closest }
+            })
+            .expect("three edges")
+    }
+}
+
+/// Returns whether the point of the plane of the triangle `abc`, whose normal
+/// is `normal`, is inside the triangle.
+fn is_inside_triangle<T: Float>(
+    point: Vector<T, 3>,
+    a: Vector<T, 3>,
+    b: Vector<T, 3>,
+    c: Vector<T, 3>,
+    normal: Vector<T, 3>,
+) -> bool {
+    [(a, b), (b, c), (c, a)]
+        .into_iter()
+        .all(|(start, end)| (end - start).cross(point - start).dot(normal) >= T::zero())
+}
+
+/// Returns the hit of a sphere moved to `centre` against the primitive, whose
+/// closest point to the centre is `closest`.
+///
+/// The normal is the zero vector when the centre is on the primitive.
+fn sweep_hit<T: Float, const N: usize>(t: T, centre: Vector<T, N>, closest: Vector<T, N>) -> Hit<T, N> {
+    Hit {
+        t,
+        point: closest,
+        normal: (centre - closest).normalize().unwrap_or(Vector::zeroed()),
+    }
+}
+
+/// Returns the first contact of a sphere moving by `velocity` during a step
+/// with the plane, or `None` when it does not touch the plane during the
+/// step.
+///
+/// The time of impact `t` of the hit is in $[0, 1]$, the sphere touching the
+/// plane when its centre is at $c + t v$. The point of the hit is the point of
+/// contact on the plane, and its normal faces the sphere. A sphere already
+/// touching the plane hits it at $t = 0$.
+///
+/// ## Formula
+/// With $d$ the distance from the centre to the plane and $n$ the normal
+/// facing the sphere:
+/// $$ t = \frac{r - d}{v \cdot n} $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::sweep_sphere_plane,
+///     vector::Vector3,
+/// };
+///
+/// let hit = sweep_sphere_plane(
+///     Vector3::new([0.0, 5.0, 0.0]),
+///     1.0,
+///     Vector3::new([2.0, -8.0, 0.0]),
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     Vector3::new([0.0, 1.0, 0.0]),
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 0.5);
+/// assert_eq!(hit.point, Vector3::new([1.0, 0.0, 0.0]));
+/// assert_eq!(hit.normal, Vector3::new([0.0, 1.0, 0.0]));
+/// ```
+pub fn sweep_sphere_plane<T: Float, const N: usize>(
+    centre: Vector<T, N>,
+    radius: T,
+    velocity: Vector<T, N>,
+    plane_origin: Vector<T, N>,
+    plane_normal: Vector<T, N>,
+) -> Option<Hit<T, N>> {
+    let mut normal = plane_normal.normalize().ok()?;
+    let mut distance = (centre - plane_origin).dot(normal);
+
+    if distance < T::zero() {
+        normal = -normal;
+        distance = -distance;
+    }
+
+    let t = if distance <= radius {
+        T::zero()
+    } else {
+        let speed = velocity.dot(normal);
+
+        if speed >= T::zero() {
+            return None;
+        }
+
+        (radius - distance) / speed
+    };
+
+    if t > T::one() {
+        return None;
+    }
+
+    let moved = centre + velocity * t;
+    let point = moved - normal * (moved - plane_origin).dot(normal);
+
+    Some(Hit { t, point, normal })
+}
+
+/// Returns the first contact of a sphere moving by `velocity` during a step
+/// with the axis-aligned bounding box going from `min` to `max`, or `None`
+/// when it does not touch the box during the step.
+///
+/// The time of impact `t` of the hit is in $[0, 1]$, the sphere touching the
+/// box when its centre is at $c + t v$. The point of the hit is the point of
+/// contact on the box, and its normal goes from this point to the centre. A
+/// sphere already touching the box hits it at $t = 0$.
+///
+/// The squared distance from the moving centre to the box is a quadratic
+/// function of the time between the times the centre crosses the planes of
+/// the faces, so the time of impact is solved exactly on each of these
+/// intervals.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::sweep_sphere_aabb,
+///     vector::Vector2,
+/// };
+///
+/// let hit = sweep_sphere_aabb(
+///     Vector2::new([-4.0, 1.0]),
+///     1.0,
+///     Vector2::new([8.0, 0.0]),
+///     Vector2::new([0.0, 0.0]),
+///     Vector2::new([2.0, 2.0]),
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 0.375);
+/// assert_eq!(hit.point, Vector2::new([0.0, 1.0]));
+/// assert_eq!(hit.normal, Vector2::new([-1.0, 0.0]));
+///
+/// // Passing beside the corner of the box.
+/// let miss = sweep_sphere_aabb(
+///     Vector2::new([-4.0, 3.5]),
+///     1.0,
+///     Vector2::new([8.0, 0.0]),
+///     Vector2::new([0.0, 0.0]),
+///     Vector2::new([2.0, 2.0]),
+/// );
+///
+/// assert_eq!(miss, None);
+/// ```
+pub fn sweep_sphere_aabb<T: Float, const N: usize>(
+    centre: Vector<T, N>,
+    radius: T,
+    velocity: Vector<T, N>,
+    min: Vector<T, N>,
+    max: Vector<T, N>,
+) -> Option<Hit<T, N>> {
+    let (zero, one) = (T::zero(), T::one());
+    let two = one + one;
+    let squared_radius = radius * radius;
+
+    let closest = centre.closest_point_on_aabb(min, max);
+    if (centre - closest).length_squared() <= squared_radius {
+        return Some(sweep_hit(zero, centre, closest));
+    }
+
+    // Times at which the centre crosses the planes of the faces.
+    let mut times = vec![zero, one];
+
+    for n in 0..N {
+        if velocity[n] != zero {
+            for bound in [min[n], max[n]] {
+                let t = (bound - centre[n]) / velocity[n];
+
+                if t > zero && t < one {
+                    times.push(t);
+                }
+            }
+        }
+    }
+
+    times.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    for interval in times.windows(2) {
+        let (start, end) = (interval[0], interval[1]);
+        let middle = centre + velocity * ((start + end) / two);
+
+        // Squared distance as `a t^2 + b t + c`, summing the axes on which
+        // the centre is outside the box.
+        let (mut a, mut b, mut c) = (zero, zero, -squared_radius);
+
+        for n in 0..N {
+            let bound = if middle[n] < min[n] {
+                min[n]
+            } else if middle[n] > max[n] {
+                max[n]
+            } else {
+                continue;
+            };
+
+            let offset = centre[n] - bound;
+            a += velocity[n] * velocity[n];
+            b += two * offset * velocity[n];
+            c += offset * offset;
+        }
+
+        let discriminant = b * b - two * two * a * c;
+
+        if a == zero || discriminant < zero {
+            continue;
+        }
+
+        let t = (-b - discriminant.sqrt()) / (two * a);
+
+        if t >= start && t <= end {
+            let moved = centre + velocity * t;
+            return Some(sweep_hit(t, moved, moved.closest_point_on_aabb(min, max)));
+        }
+    }
+
+    None
+}
+
+/// Returns the first contact of a sphere moving by `velocity` during a step
+/// with the triangle `abc`, or `None` when it does not touch the triangle
+/// during the step.
+///
+/// The time of impact `t` of the hit is in $[0, 1]$, the sphere touching the
+/// triangle when its centre is at $c + t v$. The point of the hit is the
+/// point of contact on the triangle, and its normal goes from this point to
+/// the centre. A sphere already touching the triangle hits it at $t = 0$.
+///
+/// The sphere first touches either the inside of the triangle, found by
+/// sweeping it against the plane of the triangle, or otherwise one of its
+/// edges or vertices, found by intersecting the path of the centre with the
+/// cylinders and spheres of radius $r$ around them.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::sweep_sphere_triangle,
+///     vector::Vector3,
+/// };
+///
+/// let (a, b, c) = (
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     Vector3::new([4.0, 0.0, 0.0]),
+///     Vector3::new([0.0, 0.0, 4.0]),
+/// );
+///
+/// // Falling onto the triangle.
+/// let hit = sweep_sphere_triangle(
+///     Vector3::new([1.0, 3.0, 1.0]),
+///     1.0,
+///     Vector3::new([0.0, -4.0, 0.0]),
+///     a, b, c,
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 0.5);
+/// assert_eq!(hit.point, Vector3::new([1.0, 0.0, 1.0]));
+/// assert_eq!(hit.normal, Vector3::new([0.0, 1.0, 0.0]));
+///
+/// // Sliding against the edge from `c` to `a`.
+/// let hit = sweep_sphere_triangle(
+///     Vector3::new([-3.0, 0.0, 2.0]),
+///     1.0,
+///     Vector3::new([4.0, 0.0, 0.0]),
+///     a, b, c,
+/// ).unwrap();
+///
+/// assert_eq!(hit.t, 0.5);
+/// assert_eq!(hit.point, Vector3::new([0.0, 0.0, 2.0]));
+/// ```
+pub fn sweep_sphere_triangle<T: Float>(
+    centre: Vector<T, 3>,
+    radius: T,
+    velocity: Vector<T, 3>,
+    a: Vector<T, 3>,
+    b: Vector<T, 3>,
+    c: Vector<T, 3>,
+) -> Option<Hit<T, 3>> {
+    let (zero, one) = (T::zero(), T::one());
+    let normal = (b - a).cross(c - a).normalize().ok()?;
+
+    let closest = centre.closest_point_on_triangle(a, b, c);
+    if centre.distance(closest) <= radius {
+        return Some(sweep_hit(zero, centre, closest));
+    }
+
+    let hit_at = |t: T| {
+        let moved = centre + velocity * t;
+        sweep_hit(t, moved, moved.closest_point_on_triangle(a, b, c))
+    };
+
+    if let Some(hit) = sweep_sphere_plane(centre, radius, velocity, a, normal) {
+        if is_inside_triangle(hit.point, a, b, c, normal) {
+            return Some(hit_at(hit.t));
+        }
+    }
+
+    let mut first: Option<T> = None;
+    let mut keep = |t: T| {
+        if t >= zero && t <= one && first.is_none_or(|first| t < first) {
+            first = Some(t);
+        }
+    };
+
+    for vertex in [a, b, c] {
+        if let Some(hit) = intersect_ray_sphere(centre, velocity, vertex, radius) {
+            keep(hit.t);
+        }
+    }
+
+    for (start, end) in [(a, b), (b, c), (c, a)] {
+        // Path of the centre against the infinite cylinder around the edge,
+        // the contact being kept when it is between the ends of the edge.
+        let edge = end - start;
+        let offset = centre - start;
+
+        let edge_squared = edge.length_squared();
+        let edge_velocity = edge.dot(velocity);
+        let edge_offset = edge.dot(offset);
+
+        let qa = edge_squared * velocity.length_squared() - edge_velocity * edge_velocity;
+        let qb = edge_squared * offset.dot(velocity) - edge_offset * edge_velocity;
+        let qc = edge_squared * (offset.length_squared() - radius * radius) - edge_offset * edge_offset;
+        let discriminant = qb * qb - qa * qc;
+
+        if qa == zero || discriminant < zero {
+            continue;
+        }
+
+        let t = (-qb - discriminant.sqrt()) / qa;
+        let along = (edge_offset + t * edge_velocity) / edge_squared;
+
+        if along >= zero && along <= one {
+            keep(t);
+        }
+    }
+
+    first.map(hit_at)
+}
+
+/// Returns the closest points of the segments `[a0, a1]` and `[b0, b1]`, the
+/// first one being on the segment `[a0, a1]`.
+///
+/// ## Formula
+/// The points are $a_{0} + s (a_{1} - a_{0})$ and $b_{0} + t (b_{1} - b_{0})$,
+/// with $s$ and $t$ minimizing their distance, clamped into $[0, 1]$.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::closest_points_segment_segment,
+///     vector::Vector3,
+/// };
+///
+/// let (on_a, on_b) = closest_points_segment_segment(
+///     Vector3::new([0.0, 0.0, 0.0]),
+///     Vector3::new([4.0, 0.0, 0.0]),
+///     Vector3::new([1.0, 1.0, -2.0]),
+///     Vector3::new([1.0, 1.0, 2.0]),
+/// );
+///
+/// assert_eq!(on_a, Vector3::new([1.0, 0.0, 0.0]));
+/// assert_eq!(on_b, Vector3::new([1.0, 1.0, 0.0]));
+/// ```
+pub fn closest_points_segment_segment<T: Float, const N: usize>(
+    a0: Vector<T, N>,
+    a1: Vector<T, N>,
+    b0: Vector<T, N>,
+    b1: Vector<T, N>,
+) -> (Vector<T, N>, Vector<T, N>) {
+    let (zero, one) = (T::zero(), T::one());
+
+    let da = a1 - a0;
+    let db = b1 - b0;
+    let offset = a0 - b0;
+
+    let a = da.length_squared();
+    let e = db.length_squared();
+    let f = db.dot(offset);
+
+    let (s, t) = if a == zero && e == zero {
+        // Both segments are degenerated into points.
+        (zero, zero)
+    } else if a == zero {
+        (zero, clamp(f / e, zero, one))
+    } else {
+        let c = da.dot(offset);
+
+        if e == zero {
+            (clamp(-c / a, zero, one), zero)
+        } else {
+            let b = da.dot(db);
+            let denominator = a * e - b * b;
+
+            // Parallel segments, any point of the first one does.
+            let s = if denominator == zero {
+                zero
+            } else {
+                clamp((b * f - c * e) / denominator, zero, one)
+            };
+            let t = (b * s + f) / e;
+
+            if t < zero {
+                (clamp(-c / a, zero, one), zero)
+            } else if t > one {
+                (clamp((b - c) / a, zero, one), one)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    (a0 + da * s, b0 + db * t)
+}
+
+/// Returns the distance between the segments `[a0, a1]` and `[b0, b1]`.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::distance_segment_segment,
+///     vector::Vector2,
+/// };
+///
+/// let distance = distance_segment_segment(
+///     Vector2::new([0.0, 0.0]),
+///     Vector2::new([4.0, 0.0]),
+///     Vector2::new([7.0, 4.0]),
+///     Vector2::new([7.0, 8.0]),
+/// );
+///
+/// assert_eq!(distance, 5.0);
+/// ```
+pub fn distance_segment_segment<T: Float, const N: usize>(
+    a0: Vector<T, N>,
+    a1: Vector<T, N>,
+    b0: Vector<T, N>,
+    b1: Vector<T, N>,
+) -> T {
+    let (on_a, on_b) = closest_points_segment_segment(a0, a1, b0, b1);
+    on_a.distance(on_b)
+}
+
+/// Capsule, being the points at a distance of at most `radius` from the
+/// segment `[a, b]`, as the bodies of characters.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     geometry::Capsule,
+///     vector::Vector3,
+/// };
+///
+/// let player = Capsule::new(Vector3::new([0.0, 0.5, 0.0]), Vector3::new([0.0, 1.5, 0.0]), 0.5);
+/// let enemy = Capsule::new(Vector3::new([0.8, 0.5, 0.0]), Vector3::new([0.8, 1.5, 0.0]), 0.5);
+///
+/// assert!(player.overlaps(&enemy));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Capsule<T, const N: usize> {
+    /// First end of the segment of the capsule.
+    pub a: Vector<T, N>,
+    /// Second end of the segment of the capsule.
+    pub b: Vector<T, N>,
+    /// Radius of the capsule around its segment.
+    pub radius: T,
+}
+
+impl<T: Float, const N: usize> Capsule<T, N> {
+    /// Creates a new capsule around the segment `[a, b]`.
+    pub fn new(a: Vector<T, N>, b: Vector<T, N>, radius: T) -> Self {
+        Self { a, b, radius }
+    }
+
+    /// Returns the distance between the surfaces of the two capsules, being
+    /// negative when they overlap.
+    ///
+    /// ## Formula
+    /// $$ d = d_{segments} - r_{1} - r_{2} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     geometry::Capsule,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let a = Capsule::new(Vector2::new([0.0, 0.0]), Vector2::new([0.0, 2.0]), 1.0);
+    /// let b = Capsule::new(Vector2::new([5.0, 1.0]), Vector2::new([8.0, 1.0]), 1.5);
+    ///
+    /// assert_eq!(a.distance(&b), 2.5);
+    /// ```
+    pub fn distance(&self, other: &Self) -> T {
+        distance_segment_segment(self.a, self.b, other.a, other.b) - self.radius - other.radius
+    }
+
+    /// Returns whether the two capsules overlap or touch.
+    ///
+    /// ## Formula
+    /// $$ d_{segments}^2 \le (r_{1} + r_{2})^2 $$
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (on_self, on_other) = closest_points_segment_segment(self.a, self.b, other.a, other.b);
+        let radii = self.radius + other.radius;
+
+        (on_self - on_other).length_squared() <= radii * radii
+    }
+}
@@ -0,0 +1,294 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Overlap tests between convex shapes with the Gilbert-Johnson-Keerthi (GJK)
+//! algorithm, for the narrow phase of collision detection.
+//!
+//! A shape only has to give its farthest point along any direction, its
+//! [support](Support) point, to be tested against any other shape. Spheres,
+//! axis-aligned boxes, capsules and convex hulls of points, given as slices of
+//! points, already do.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     gjk::{ intersects, Sphere },
+//!     vector::Vector3,
+//! };
+//!
+//! let wedge = [
+//!     Vector3::new([0.0, 0.0, 0.0]),
+//!     Vector3::new([2.0, 0.0, 0.0]),
+//!     Vector3::new([0.0, 2.0, 0.0]),
+//!     Vector3::new([0.0, 0.0, 2.0]),
+//! ];
+//!
+//! assert!(intersects(&wedge[..], &Sphere::new(Vector3::new([1.0, 1.0, 1.0]), 0.6)));
+//! assert!(!intersects(&wedge[..], &Sphere::new(Vector3::new([2.0, 2.0, 2.0]), 0.5)));
+//! ```
+
+use crate::{
+    geometry::Capsule,
+    vector::Vector3,
+    Float,
+};
+
+/// Maximum number of support points searched, the algorithm ending in a few
+/// of them unless the shapes are touching.
+const MAX_ITERATIONS: usize = 64;
+
+/// Convex shape described by its support function.
+pub trait Support<T> {
+    /// Returns the point of the shape the farthest along the direction, any
+    /// of them when there are several.
+    fn support(&self, direction: Vector3<T>) -> Vector3<T>;
+}
+
+/// Sphere of a `centre` and a `radius`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sphere<T> {
+    /// Centre of the sphere.
+    pub centre: Vector3<T>,
+    /// Radius of the sphere.
+    pub radius: T,
+}
+
+impl<T> Sphere<T> {
+    /// Creates a new sphere.
+    pub fn new(centre: Vector3<T>, radius: T) -> Self {
+        Self { centre, radius }
+    }
+}
+
+impl<T: Float> Support<T> for Sphere<T> {
+    /// ## Formula
+    /// $$ s(d) = c + r \frac{d}{\lVert d \rVert} $$
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        match direction.normalize() {
+            Ok(direction) => self.centre + direction * self.radius,
+            Err(_) => self.centre,
+        }
+    }
+}
+
+/// Axis-aligned box of a `min` and a `max` corner.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb<T> {
+    /// Corner of the least coordinates.
+    pub min: Vector3<T>,
+    /// Corner of the greatest coordinates.
+    pub max: Vector3<T>,
+}
+
+impl<T> Aabb<T> {
+    /// Creates a new axis-aligned box.
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Float> Support<T> for Aabb<T> {
+    /// ## Formula
+    /// $$ s(d)_{i} = \begin{cases} max_{i} & d_{i} > 0 \\\ min_{i} & d_{i} \le 0 \end{cases} $$
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        Vector3::new(std::array::from_fn(|n| {
+            if direction[n] > T::zero() { self.max[n] } else { self.min[n] }
+        }))
+    }
+}
+
+impl<T: Float> Support<T> for Capsule<T, 3> {
+    /// ## Formula
+    /// $$ s(d) = \begin{cases} a & d \cdot a \ge d \cdot b \\\ b & d \cdot a < d \cdot b \end{cases} + r \frac{d}{\lVert d \rVert} $$
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let end = if direction.dot(self.a) >= direction.dot(self.b) { self.a } else { self.b };
+
+        match direction.normalize() {
+            Ok(direction) => end + direction * self.radius,
+            Err(_) => end,
+        }
+    }
+}
+
+/// Convex hull of the points of the slice, which must not be empty.
+impl<T: Float> Support<T> for [Vector3<T>] {
+    /// ## Formula
+    /// $$ s(d) = \underset{p_{i}}{\operatorname{argmax}} \; d \cdot p_{i} $$
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let mut points = self.iter();
+        let mut farthest = *points.next().expect("no point in the convex hull");
+        let mut greatest = direction.dot(farthest);
+
+        for point in points {
+            let distance = direction.dot(*point);
+
+            if distance > greatest {
+                farthest = *point;
+                greatest = distance;
+            }
+        }
+
+        farthest
+    }
+}
+
+/// Returns the support point of the Minkowski difference $A - B$ along the
+/// direction.
+fn support<T: Float, A, B>(a: &A, b: &B, direction: Vector3<T>) -> Vector3<T>
+where
+    A: Support<T> + ?Sized,
+    B: Support<T> + ?Sized,
+{
+    a.support(direction) - b.support(-direction)
+}
+
+/// Returns the vector $(u \times v) \times u$, perpendicular to `u` towards
+/// `v`.
+fn triple<T: Float>(u: Vector3<T>, v: Vector3<T>) -> Vector3<T> {
+    u.cross(v).cross(u)
+}
+
+/// Simplex of the Minkowski difference getting closer to the origin, its last
+/// point being the latest found.
+struct Simplex<T> {
+    points: [Vector3<T>; 4],
+    len: usize,
+}
+
+impl<T: Float> Simplex<T> {
+    fn set(&mut self, points: &[Vector3<T>]) {
+        self.points[..points.len()].copy_from_slice(points);
+        self.len = points.len();
+    }
+
+    /// Reduces the simplex to its feature the closest to the origin and
+    /// returns the direction from it towards the origin, or `None` when the
+    /// origin is in the simplex.
+    fn evolve(&mut self) -> Option<Vector3<T>> {
+        let direction = match self.len {
+            2 => self.line(),
+            3 => self.triangle(),
+            _ => return self.tetrahedron(),
+        };
+
+        if direction.length_squared() == T::zero() {
+            // The origin lies on the simplex.
+            None
+        } else {
+            Some(direction)
+        }
+    }
+
+    fn line(&mut self) -> Vector3<T> {
+        let [b, a, ..] = self.points;
+        let (ab, ao) = (b - a, -a);
+
+        if ab.dot(ao) > T::zero() {
+            triple(ab, ao)
+        } else {
+            self.set(&[a]);
+            ao
+        }
+    }
+
+    fn triangle(&mut self) -> Vector3<T> {
+        let [c, b, a, _] = self.points;
+        let (ab, ac, ao) = (b - a, c - a, -a);
+        let normal = ab.cross(ac);
+
+        if normal.cross(ac).dot(ao) > T::zero() {
+            if ac.dot(ao) > T::zero() {
+                self.set(&[c, a]);
+                triple(ac, ao)
+            } else {
+                self.set(&[b, a]);
+                self.line()
+            }
+        } else if ab.cross(normal).dot(ao) > T::zero() {
+            self.set(&[b, a]);
+            self.line()
+        } else if normal.dot(ao) >= T::zero() {
+            normal
+        } else {
+            -normal
+        }
+    }
+
+    fn tetrahedron(&mut self) -> Option<Vector3<T>> {
+        let [d, c, b, a] = self.points;
+        let ao = -a;
+
+        // The faces around the latest point, with the vertex opposite to
+        // each of them.
+        for (x, y, opposite) in [(c, b, d), (d, c, b), (b, d, c)] {
+            let mut normal = (x - a).cross(y - a);
+
+            if normal.dot(opposite - a) > T::zero() {
+                normal = -normal;
+            }
+
+            if normal.dot(ao) > T::zero() {
+                self.set(&[x, y, a]);
+                return self.evolve();
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns whether the two convex shapes overlap or touch.
+///
+/// The shapes overlap when their Minkowski difference contains the origin,
+/// which is searched by a simplex of support points of the difference moving
+/// towards the origin.
+///
+/// ## Formula
+/// $$ A \cap B \neq \emptyset \iff 0 \in A - B = \left\{ a - b \mid a \in A, b \in B \right\} $$
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     gjk::{ intersects, Aabb, Sphere },
+///     vector::Vector3,
+/// };
+///
+/// let crate_box = Aabb::new(Vector3::new([0.0, 0.0, 0.0]), Vector3::new([1.0, 1.0, 1.0]));
+///
+/// assert!(intersects(&crate_box, &Sphere::new(Vector3::new([1.5, 0.5, 0.5]), 0.6)));
+/// assert!(!intersects(&crate_box, &Sphere::new(Vector3::new([1.5, 1.5, 1.5]), 0.6)));
+/// ```
+pub fn intersects<T: Float, A, B>(a: &A, b: &B) -> bool
+where
+    A: Support<T> + ?Sized,
+    B: Support<T> + ?Sized,
+{
+    let first = support(a, b, Vector3::new([T::one(), T::zero(), T::zero()]));
+    let mut simplex = Simplex { points: [first; 4], len: 1 };
+    let mut direction = -first;
+
+    for _ in 0..MAX_ITERATIONS {
+        if direction.length_squared() == T::zero() {
+            return true;
+        }
+
+        let point = support(a, b, direction);
+
+        if point.dot(direction) < T::zero() {
+            // The origin is beyond the farthest point of the difference.
+            return false;
+        }
+
+        simplex.points[simplex.len] = point;
+        simplex.len += 1;
+
+        match simplex.evolve() {
+            Some(next) => direction = next,
+            None => return true,
+        }
+    }
+
+    // Not converging, the origin being on the boundary of the difference.
+    true
+}
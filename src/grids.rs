@@ -0,0 +1,170 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Grid of cells indexed by `(x, y)` positions, for tile maps and
+//! heightfields.
+//!
+//! As for the screen coordinates, `x` goes from the left to the right and `y`
+//! goes from the top to the bottom, the cell `(0, 0)` being the top-left one.
+//!
+//! ## Example
+//! ```
+//! use linbra::grids::Grid2;
+//!
+//! let mut tiles = Grid2::<char, 3, 2>::splat('.');
+//! tiles[(2, 1)] = '#';
+//!
+//! let walls = tiles
+//!     .neighbours8(1, 0)
+//!     .filter(|&position| tiles[position] == '#')
+//!     .count();
+//!
+//! assert_eq!(walls, 1);
+//! assert_eq!(tiles.get(3, 0), None);
+//! ```
+
+use std::ops;
+
+use crate::matrix::Matrix;
+
+/// Offsets of the 4-connected neighbours, being the cells sharing a side.
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8-connected neighbours, being the cells sharing a side or a
+/// corner.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+/// Grid of `W` columns and `H` rows of cells, wrapping a
+/// [`Matrix<T, W, H>`](Matrix) whose column `x` and row `y` is the cell
+/// `(x, y)`.
+///
+/// The underlying matrix is retrieved or wrapped with the `From`/`Into`
+/// conversions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Grid2<T, const W: usize, const H: usize>(Matrix<T, W, H>);
+
+impl<T, const W: usize, const H: usize> Grid2<T, W, H> {
+    /// Returns the number of columns of the grid.
+    pub fn width(&self) -> usize {
+        W
+    }
+
+    /// Returns the number of rows of the grid.
+    pub fn height(&self) -> usize {
+        H
+    }
+
+    /// Returns the cell at the position `(x, y)`, or `None` when it is
+    /// outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < W && y < H {
+            Some(&self.0[x][y])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at the position `(x, y)` as mutable, or `None` when
+    /// it is outside of the grid.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < W && y < H {
+            Some(&mut self.0[x][y])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the positions of the cells of the grid around `(x, y)` with
+    /// the given offsets.
+    fn neighbours<const N: usize>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: [(isize, isize); N],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        offsets.into_iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < W)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < H)?;
+
+            Some((x, y))
+        })
+    }
+
+    /// Returns the positions of the cells sharing a side with the cell at
+    /// `(x, y)`, and being inside the grid.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::grids::Grid2;
+    ///
+    /// let grid = Grid2::<u8, 3, 3>::splat(0);
+    /// let neighbours: Vec<_> = grid.neighbours4(0, 1).collect();
+    ///
+    /// assert_eq!(neighbours, vec![(0, 0), (1, 1), (0, 2)]);
+    /// ```
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, NEIGHBOURS4)
+    }
+
+    /// Returns the positions of the cells sharing a side or a corner with the
+    /// cell at `(x, y)`, and being inside the grid.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::grids::Grid2;
+    ///
+    /// let grid = Grid2::<u8, 3, 3>::splat(0);
+    ///
+    /// assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    /// assert_eq!(grid.neighbours8(0, 0).count(), 3);
+    /// ```
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, NEIGHBOURS8)
+    }
+}
+
+impl<T: Clone, const W: usize, const H: usize> Grid2<T, W, H> {
+    /// Creates a new grid with all its cells set to `value`.
+    pub fn splat(value: T) -> Self {
+        Self(Matrix::splat(value))
+    }
+}
+
+/// Wraps a matrix into a [`Grid2`].
+impl<T, const W: usize, const H: usize> From<Matrix<T, W, H>> for Grid2<T, W, H> {
+    fn from(value: Matrix<T, W, H>) -> Self {
+        Self(value)
+    }
+}
+
+/// Unwraps the matrix of a [`Grid2`].
+impl<T, const W: usize, const H: usize> From<Grid2<T, W, H>> for Matrix<T, W, H> {
+    fn from(value: Grid2<T, W, H>) -> Self {
+        value.0
+    }
+}
+
+/// Returns the cell at the position `(x, y)` in the grid.
+///
+/// Panics when the position is outside of the grid, see [`Grid2::get`].
+impl<T, const W: usize, const H: usize> ops::Index<(usize, usize)> for Grid2<T, W, H> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.0[x][y]
+    }
+}
+
+/// Returns the cell at the position `(x, y)` in the grid, as mutable.
+///
+/// Panics when the position is outside of the grid, see [`Grid2::get_mut`].
+impl<T, const W: usize, const H: usize> ops::IndexMut<(usize, usize)> for Grid2<T, W, H> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[x][y]
+    }
+}
@@ -0,0 +1,143 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Deterministic hashes of vectors of integers into pseudo-random values, the
+//! same on every platform and every run, such as to place props procedurally
+//! on the cells of a grid or to sample white noise.
+//!
+//! The hashes mix the components one by one with the permuted congruential
+//! hash of Jarzynski and Olano, which is fast and of a good quality for
+//! noise, but not suited to cryptography.
+//!
+//! ## Example
+//! ```
+//! use linbra::vector::Vector2;
+//!
+//! let cell = Vector2::<i32>::new([12, -7]);
+//!
+//! let density = cell.hash_to_f32();
+//! assert!((0.0..1.0).contains(&density));
+//! assert_eq!(density, cell.hash_to_f32());
+//! ```
+
+use crate::vector::{ Vector, Vector2, Vector3 };
+
+/// Returns the permuted congruential hash of the value, its bits being well
+/// spread by the one of any other value.
+///
+/// ## Formula
+/// With $s = 747796405 v + 2891336453$ and
+/// $w = 277803737 \left( (s \gg ((s \gg 28) + 4)) \oplus s \right)$, modulo
+/// $2^{32}$:
+/// $$ h(v) = (w \gg 22) \oplus w $$
+///
+/// ## Example
+/// ```
+/// use linbra::hashing::pcg_hash;
+///
+/// assert_ne!(pcg_hash(0), pcg_hash(1));
+/// assert_eq!(pcg_hash(42), pcg_hash(42));
+/// ```
+pub fn pcg_hash(value: u32) -> u32 {
+    let state = value.wrapping_mul(747796405).wrapping_add(2891336453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277803737);
+
+    (word >> 22) ^ word
+}
+
+/// Returns the value in $[0, 1)$ of the 24 greatest bits of the hash, exactly
+/// represented by a `f32` value.
+fn unit_f32(hash: u32) -> f32 {
+    (hash >> 8) as f32 / (1u32 << 24) as f32
+}
+
+macro_rules! impl_hash {
+    ($type:tt, $bits:tt) => {
+        /// Implements the hashes of vectors of
+        #[doc = concat!("`", stringify!($type), "` values.")]
+        impl<const N: usize> Vector<$type, N> {
+            /// Returns the hash of the vector, mixing its components one by
+            /// one.
+            ///
+            /// ## Formula
+            /// $$ h_{0} = 0, \quad h_{i} = \operatorname{pcg}(h_{i - 1} + v_{i}) $$
+            ///
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector2;
+            ///
+            #[doc = concat!("let a = Vector2::<", stringify!($type), ">::new([1, 2]);")]
+            #[doc = concat!("let b = Vector2::<", stringify!($type), ">::new([2, 1]);")]
+            ///
+            /// assert_ne!(a.hash_u32(), b.hash_u32());
+            /// ```
+            pub fn hash_u32(self) -> u32 {
+                self.into_iter().fold(0, |hash, value| {
+                    let bits = value as $bits as u64;
+                    pcg_hash(hash.wrapping_add((bits ^ (bits >> 32)) as u32))
+                })
+            }
+
+            /// Returns a pseudo-random value in $[0, 1)$ from the vector, the
+            /// same for the same vector.
+            ///
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector3;
+            ///
+            #[doc = concat!("let value = Vector3::<", stringify!($type), ">::new([4, 8, 15]).hash_to_f32();")]
+            ///
+            /// assert!((0.0..1.0).contains(&value));
+            /// ```
+            pub fn hash_to_f32(self) -> f32 {
+                unit_f32(self.hash_u32())
+            }
+
+            /// Returns two independent pseudo-random values in $[0, 1)$ from
+            /// the vector, such as an offset of a prop inside its cell.
+            ///
+            /// ## Formula
+            /// $$ h = \operatorname{hash}(v), \quad \left( h, \operatorname{pcg}(h) \right) $$
+            ///
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector2;
+            ///
+            #[doc = concat!("let offset = Vector2::<", stringify!($type), ">::new([3, 5]).hash2();")]
+            ///
+            /// assert!(offset.into_iter().all(|value| (0.0..1.0).contains(&value)));
+            /// ```
+            pub fn hash2(self) -> Vector2<f32> {
+                let first = self.hash_u32();
+                Vector2::new([unit_f32(first), unit_f32(pcg_hash(first))])
+            }
+
+            /// Returns three independent pseudo-random values in $[0, 1)$
+            /// from the vector, such as a random colour per cell.
+            ///
+            /// ## Formula
+            /// $$ h = \operatorname{hash}(v), \quad \left( h, \operatorname{pcg}(h), \operatorname{pcg}(\operatorname{pcg}(h)) \right) $$
+            ///
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector3;
+            ///
+            #[doc = concat!("let colour = Vector3::<", stringify!($type), ">::new([1, 1, 2]).hash3();")]
+            ///
+            /// assert!(colour.into_iter().all(|value| (0.0..1.0).contains(&value)));
+            /// ```
+            pub fn hash3(self) -> Vector3<f32> {
+                let first = self.hash_u32();
+                let second = pcg_hash(first);
+
+                Vector3::new([unit_f32(first), unit_f32(second), unit_f32(pcg_hash(second))])
+            }
+        }
+    };
+}
+
+impl_hash!(i32, u32);
+impl_hash!(u32, u32);
+impl_hash!(i64, u64);
+impl_hash!(u64, u64);
@@ -0,0 +1,112 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Implementations of the `approx` traits for vectors and matrices, available 
+//! with the `approx` feature.
+//! 
+//! Two vectors or matrices are approximately equal when all their values are 
+//! approximately equal, compared one by one with the same tolerance.
+//! 
+//! ## Example
+//! ```
+//! use approx::{ assert_relative_eq, assert_ulps_ne };
+//! use linbra::{
+//!     matrix::Matrix2,
+//!     vector::Vector2,
+//! };
+//! 
+//! let vector = Vector2::<f32>::new([0.1 + 0.2, 1.0]);
+//! assert_relative_eq!(vector, Vector2::new([0.3, 1.0]));
+//! 
+//! let matrix = Matrix2::<f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+//! assert_ulps_ne!(matrix, Matrix2::new([[1.0, 0.0], [0.0, 1.001]]));
+//! ```
+
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+impl<T: AbsDiffEq, const N: usize> AbsDiffEq for Vector<T, N> 
+where 
+    T::Epsilon: Clone 
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        (0..N).all(|n| self[n].abs_diff_eq(&other[n], epsilon.clone()))
+    }
+}
+
+impl<T: RelativeEq, const N: usize> RelativeEq for Vector<T, N> 
+where 
+    T::Epsilon: Clone 
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        (0..N).all(|n| self[n].relative_eq(&other[n], epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<T: UlpsEq, const N: usize> UlpsEq for Vector<T, N> 
+where 
+    T::Epsilon: Clone 
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        (0..N).all(|n| self[n].ulps_eq(&other[n], epsilon.clone(), max_ulps))
+    }
+}
+
+impl<T: AbsDiffEq, const C: usize, const R: usize> AbsDiffEq for Matrix<T, C, R> 
+where 
+    T::Epsilon: Clone 
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        (0..C).all(|column| self[column].abs_diff_eq(&other[column], epsilon.clone()))
+    }
+}
+
+impl<T: RelativeEq, const C: usize, const R: usize> RelativeEq for Matrix<T, C, R> 
+where 
+    T::Epsilon: Clone 
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        (0..C).all(|column| {
+            self[column].relative_eq(&other[column], epsilon.clone(), max_relative.clone())
+        })
+    }
+}
+
+impl<T: UlpsEq, const C: usize, const R: usize> UlpsEq for Matrix<T, C, R> 
+where 
+    T::Epsilon: Clone 
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        (0..C).all(|column| self[column].ulps_eq(&other[column], epsilon.clone(), max_ulps))
+    }
+}
@@ -0,0 +1,73 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions from and to the `cgmath` types, available with the `cgmath` 
+//! feature.
+//! 
+//! ## Example
+//! ```
+//! use linbra::{
+//!     matrix::Matrix4,
+//!     vector::Vector3,
+//! };
+//! 
+//! let point: cgmath::Point3<f32> = Vector3::new([1.0, 2.0, 3.0]).into();
+//! assert_eq!(point, cgmath::Point3::new(1.0, 2.0, 3.0));
+//! 
+//! let translation = cgmath::Matrix4::from_translation(cgmath::vec3(1.0, 2.0, 3.0));
+//! let matrix: Matrix4<f32> = translation.into();
+//! assert_eq!(matrix[3], [1.0, 2.0, 3.0, 1.0]);
+//! 
+//! assert_eq!(cgmath::Matrix4::from(matrix), translation);
+//! ```
+
+use crate::{
+    matrix::{Matrix2, Matrix3, Matrix4},
+    vector::{Vector2, Vector3, Vector4},
+};
+
+macro_rules! impl_cgmath_vector {
+    ($vector:tt, $cgmath:tt, [$($field:ident),+]) => {
+        impl<T> From<::cgmath::$cgmath<T>> for $vector<T> {
+            fn from(value: ::cgmath::$cgmath<T>) -> Self {
+                Self::new([$(value.$field),+])
+            }
+        }
+
+        impl<T> From<$vector<T>> for ::cgmath::$cgmath<T> {
+            fn from(value: $vector<T>) -> Self {
+                let mut values = value.into_iter();
+                Self { $($field: values.next().unwrap()),+ }
+            }
+        }
+    };
+}
+
+impl_cgmath_vector!(Vector2, Vector2, [x, y]);
+impl_cgmath_vector!(Vector3, Vector3, [x, y, z]);
+impl_cgmath_vector!(Vector4, Vector4, [x, y, z, w]);
+impl_cgmath_vector!(Vector2, Point2, [x, y]);
+impl_cgmath_vector!(Vector3, Point3, [x, y, z]);
+
+macro_rules! impl_cgmath_matrix {
+    ($matrix:tt, $cgmath:tt, $size:literal) => {
+        impl<T: Copy> From<::cgmath::$cgmath<T>> for $matrix<T> {
+            fn from(value: ::cgmath::$cgmath<T>) -> Self {
+                let columns: &[[T; $size]; $size] = value.as_ref();
+                Self::new(*columns)
+            }
+        }
+
+        impl<T: Copy> From<$matrix<T>> for ::cgmath::$cgmath<T> {
+            fn from(value: $matrix<T>) -> Self {
+                let columns: [[T; $size]; $size] = std::array::from_fn(|column| value[column]);
+                columns.into()
+            }
+        }
+    };
+}
+
+impl_cgmath_matrix!(Matrix2, Matrix2, 2);
+impl_cgmath_matrix!(Matrix3, Matrix3, 3);
+impl_cgmath_matrix!(Matrix4, Matrix4, 4);
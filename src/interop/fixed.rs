@@ -0,0 +1,56 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Support of the fixed-point types of the `fixed` crate, available with the 
+//! `fixed` feature.
+//! 
+//! Fixed-point calculations give the same results on every platform, which is 
+//! useful for deterministic simulations such as lockstep multiplayer games.
+//! 
+//! ## Example
+//! ```
+//! use fixed::types::I16F16;
+//! use linbra::vector::Vector2;
+//! 
+//! let position = Vector2::new([I16F16::from_num(1.5), I16F16::from_num(-2)]);
+//! let velocity = Vector2::new([I16F16::from_num(0.25), I16F16::from_num(1)]);
+//! 
+//! assert_eq!(
+//!     position + velocity * I16F16::from_num(2),
+//!     Vector2::new([I16F16::from_num(2), I16F16::ZERO]),
+//! );
+//! ```
+
+#[cfg(not(feature = "num-traits"))]
+macro_rules! impl_fixed_numbers {
+    ($fixed:tt, $leq:tt) => {
+        impl<Frac: ::fixed::types::extra::$leq> crate::Zero for ::fixed::$fixed<Frac> {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<Frac: ::fixed::types::extra::$leq> crate::Num for ::fixed::$fixed<Frac> {}
+    };
+    ($fixed:tt, $leq:tt, signed) => {
+        impl_fixed_numbers!($fixed, $leq);
+
+        impl<Frac: ::fixed::types::extra::$leq> crate::Signed for ::fixed::$fixed<Frac> {}
+    };
+}
+
+#[cfg(not(feature = "num-traits"))]
+mod numbers {
+    impl_fixed_numbers!(FixedI8, LeEqU8, signed);
+    impl_fixed_numbers!(FixedI16, LeEqU16, signed);
+    impl_fixed_numbers!(FixedI32, LeEqU32, signed);
+    impl_fixed_numbers!(FixedI64, LeEqU64, signed);
+    impl_fixed_numbers!(FixedI128, LeEqU128, signed);
+
+    impl_fixed_numbers!(FixedU8, LeEqU8);
+    impl_fixed_numbers!(FixedU16, LeEqU16);
+    impl_fixed_numbers!(FixedU32, LeEqU32);
+    impl_fixed_numbers!(FixedU64, LeEqU64);
+    impl_fixed_numbers!(FixedU128, LeEqU128);
+}
@@ -0,0 +1,81 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions from and to the `glam` types, available with the `glam` 
+//! feature.
+//! 
+//! Vectors and matrices of `f32` values are converted from and to the `Vec` 
+//! and `Mat` types, and the ones of `f64` values from and to the `DVec` and 
+//! `DMat` types.
+//! 
+//! ## Example
+//! ```
+//! use linbra::{
+//!     matrix::Matrix4,
+//!     vector::Vector3,
+//! };
+//! 
+//! let vector = Vector3::<f32>::new([1.0, 2.0, 3.0]);
+//! let vec3: glam::Vec3 = vector.into();
+//! assert_eq!(vec3, glam::Vec3::new(1.0, 2.0, 3.0));
+//! 
+//! let translation = glam::Mat4::from_translation(vec3);
+//! let matrix: Matrix4<f32> = translation.into();
+//! assert_eq!(matrix[3], [1.0, 2.0, 3.0, 1.0]);
+//! 
+//! assert_eq!(glam::Mat4::from(matrix), translation);
+//! ```
+
+use crate::{
+    matrix::{Matrix2, Matrix3, Matrix4},
+    vector::{Vector2, Vector3, Vector4},
+};
+
+macro_rules! impl_glam_vector {
+    ($vector:tt, $type:tt, $glam:tt, $length:literal) => {
+        impl From<::glam::$glam> for $vector<$type> {
+            fn from(value: ::glam::$glam) -> Self {
+                Self::new(value.to_array())
+            }
+        }
+
+        impl From<$vector<$type>> for ::glam::$glam {
+            fn from(value: $vector<$type>) -> Self {
+                let values: [$type; $length] = std::array::from_fn(|n| value[n]);
+                Self::from_array(values)
+            }
+        }
+    };
+}
+
+impl_glam_vector!(Vector2, f32, Vec2, 2);
+impl_glam_vector!(Vector3, f32, Vec3, 3);
+impl_glam_vector!(Vector4, f32, Vec4, 4);
+impl_glam_vector!(Vector2, f64, DVec2, 2);
+impl_glam_vector!(Vector3, f64, DVec3, 3);
+impl_glam_vector!(Vector4, f64, DVec4, 4);
+
+macro_rules! impl_glam_matrix {
+    ($matrix:tt, $type:tt, $glam:tt, $size:literal) => {
+        impl From<::glam::$glam> for $matrix<$type> {
+            fn from(value: ::glam::$glam) -> Self {
+                Self::new(value.to_cols_array_2d())
+            }
+        }
+
+        impl From<$matrix<$type>> for ::glam::$glam {
+            fn from(value: $matrix<$type>) -> Self {
+                let columns: [[$type; $size]; $size] = std::array::from_fn(|column| value[column]);
+                Self::from_cols_array_2d(&columns)
+            }
+        }
+    };
+}
+
+impl_glam_matrix!(Matrix2, f32, Mat2, 2);
+impl_glam_matrix!(Matrix3, f32, Mat3, 3);
+impl_glam_matrix!(Matrix4, f32, Mat4, 4);
+impl_glam_matrix!(Matrix2, f64, DMat2, 2);
+impl_glam_matrix!(Matrix3, f64, DMat3, 3);
+impl_glam_matrix!(Matrix4, f64, DMat4, 4);
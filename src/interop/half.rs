@@ -0,0 +1,82 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Support of the half-precision `f16` type of the `half` crate, available 
+//! with the `half` feature.
+//! 
+//! Vectors and matrices of `f16` values are compact, which is useful for 
+//! vertex data sent to the GPU. They are converted without loss to the ones of 
+//! `f32` and `f64` values, and from them with rounding.
+//! 
+//! ## Example
+//! ```
+//! use half::f16;
+//! use linbra::vector::Vector3;
+//! 
+//! let position = Vector3::<f32>::new([1.0, 0.5, -2.0]);
+//! let compact = position.to_f16();
+//! assert_eq!(compact[1], f16::from_f32(0.5));
+//! 
+//! let sum = compact + compact;
+//! assert_eq!(Vector3::<f32>::from(sum), Vector3::new([2.0, 1.0, -4.0]));
+//! ```
+
+use ::half::f16;
+
+use crate::{matrix::Matrix, vector::Vector};
+
+#[cfg(not(feature = "num-traits"))]
+impl crate::Zero for f16 {
+    fn zero() -> Self {
+        f16::ZERO
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl crate::One for f16 {
+    fn one() -> Self {
+        f16::ONE
+    }
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl crate::Num for f16 {}
+
+#[cfg(not(feature = "num-traits"))]
+impl crate::Signed for f16 {}
+
+macro_rules! impl_half_conversions {
+    ($type:tt, $from:ident, $to:ident) => {
+        impl<const N: usize> From<Vector<f16, N>> for Vector<$type, N> {
+            fn from(value: Vector<f16, N>) -> Self {
+                Self::new(std::array::from_fn(|n| value[n].$to()))
+            }
+        }
+
+        impl<const C: usize, const R: usize> From<Matrix<f16, C, R>> for Matrix<$type, C, R> {
+            fn from(value: Matrix<f16, C, R>) -> Self {
+                Self::new(std::array::from_fn(|c| value[c].map(f16::$to)))
+            }
+        }
+
+        impl<const N: usize> Vector<$type, N> {
+            /// Returns the vector with its values rounded to the nearest 
+            /// half-precision values.
+            pub fn to_f16(self) -> Vector<f16, N> {
+                Vector::new(std::array::from_fn(|n| f16::$from(self[n])))
+            }
+        }
+
+        impl<const C: usize, const R: usize> Matrix<$type, C, R> {
+            /// Returns the matrix with its values rounded to the nearest 
+            /// half-precision values.
+            pub fn to_f16(self) -> Matrix<f16, C, R> {
+                Matrix::new(std::array::from_fn(|c| self[c].map(f16::$from)))
+            }
+        }
+    };
+}
+
+impl_half_conversions!(f32, from_f32, to_f32);
+impl_half_conversions!(f64, from_f64, to_f64);
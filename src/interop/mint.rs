@@ -0,0 +1,93 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions from and to the `mint` types, available with the `mint` 
+//! feature.
+//! 
+//! ## Example
+//! ```
+//! use linbra::{
+//!     matrix::Matrix3,
+//!     vector::Vector3,
+//! };
+//! 
+//! let vector = Vector3::new([1.0, 2.0, 3.0]);
+//! let point: mint::Point3<f32> = vector.into();
+//! assert_eq!(point.z, 3.0);
+//! 
+//! let matrix = Matrix3::<f32>::natural([
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 9.0],
+//! ]);
+//! let columns: mint::ColumnMatrix3<f32> = matrix.into();
+//! assert_eq!(columns.x, mint::Vector3 { x: 1.0, y: 4.0, z: 7.0 });
+//! 
+//! assert_eq!(Matrix3::from(columns), matrix);
+//! ```
+
+use crate::{
+    matrix::{Matrix2, Matrix3, Matrix4},
+    vector::{Vector2, Vector3, Vector4},
+};
+
+macro_rules! impl_mint_vector {
+    ($vector:tt, $mint:tt, [$($field:ident),+]) => {
+        impl<T> From<::mint::$mint<T>> for $vector<T> {
+            fn from(value: ::mint::$mint<T>) -> Self {
+                Self::new([$(value.$field),+])
+            }
+        }
+
+        impl<T> From<$vector<T>> for ::mint::$mint<T> {
+            fn from(value: $vector<T>) -> Self {
+                let mut values = value.into_iter();
+                Self { $($field: values.next().unwrap()),+ }
+            }
+        }
+    };
+}
+
+impl_mint_vector!(Vector2, Vector2, [x, y]);
+impl_mint_vector!(Vector3, Vector3, [x, y, z]);
+impl_mint_vector!(Vector4, Vector4, [x, y, z, w]);
+impl_mint_vector!(Vector2, Point2, [x, y]);
+impl_mint_vector!(Vector3, Point3, [x, y, z]);
+
+macro_rules! impl_mint_into {
+    ($vector:tt, $mint:tt) => {
+        impl<T> ::mint::IntoMint for $vector<T> {
+            type MintType = ::mint::$mint<T>;
+        }
+    };
+}
+
+impl_mint_into!(Vector2, Vector2);
+impl_mint_into!(Vector3, Vector3);
+impl_mint_into!(Vector4, Vector4);
+
+macro_rules! impl_mint_matrix {
+    ($matrix:tt, $mint:tt, $size:literal) => {
+        impl<T> From<::mint::$mint<T>> for $matrix<T> {
+            fn from(value: ::mint::$mint<T>) -> Self {
+                Self::new(value.into())
+            }
+        }
+
+        impl<T: Copy> From<$matrix<T>> for ::mint::$mint<T> {
+            fn from(value: $matrix<T>) -> Self {
+                let columns: [[T; $size]; $size] = std::array::from_fn(|column| value[column]);
+                columns.into()
+            }
+        }
+
+        impl<T: Copy> ::mint::IntoMint for $matrix<T> {
+            type MintType = ::mint::$mint<T>;
+        }
+    };
+}
+
+impl_mint_matrix!(Matrix2, ColumnMatrix2, 2);
+impl_mint_matrix!(Matrix3, ColumnMatrix3, 3);
+impl_mint_matrix!(Matrix4, ColumnMatrix4, 4);
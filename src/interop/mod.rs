@@ -0,0 +1,22 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions between the linbra types and the types of other linear algebra 
+//! or numeric crates, and implementations of their traits, each one being 
+//! available with the feature of the same name.
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "cgmath")]
+mod cgmath;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,58 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Conversions from and to the `nalgebra` statically-sized types, available 
+//! with the `nalgebra` feature.
+//! 
+//! Note `nalgebra` gives the number of rows before the number of columns, a 
+//! `Matrix<T, C, R>` being converted from and to a `SMatrix<T, R, C>`.
+//! 
+//! ## Example
+//! ```
+//! use linbra::{
+//!     matrix::Matrix,
+//!     vector::Vector3,
+//! };
+//! 
+//! let matrix = Matrix::<f64, 3, 2>::natural([
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//! ]);
+//! let smatrix: nalgebra::SMatrix<f64, 2, 3> = matrix.into();
+//! assert_eq!(smatrix[(1, 0)], 4.0);
+//! 
+//! let vector: nalgebra::SVector<f64, 3> = Vector3::new([1.0, 0.0, -1.0]).into();
+//! let product: Vector3<f64> = (smatrix.transpose() * smatrix * vector).into();
+//! assert_eq!(product, Vector3::new([-10.0, -14.0, -18.0]));
+//! ```
+
+use ::nalgebra::{SMatrix, SVector, Scalar};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+impl<T: Scalar, const N: usize> From<SVector<T, N>> for Vector<T, N> {
+    fn from(value: SVector<T, N>) -> Self {
+        Self::new(std::array::from_fn(|n| value[n].clone()))
+    }
+}
+
+impl<T: Scalar, const N: usize> From<Vector<T, N>> for SVector<T, N> {
+    fn from(value: Vector<T, N>) -> Self {
+        Self::from_iterator(value)
+    }
+}
+
+impl<T: Scalar, const C: usize, const R: usize> From<SMatrix<T, R, C>> for Matrix<T, C, R> {
+    fn from(value: SMatrix<T, R, C>) -> Self {
+        Self::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| value[(row, column)].clone())
+        }))
+    }
+}
+
+impl<T: Scalar, const C: usize, const R: usize> From<Matrix<T, C, R>> for SMatrix<T, R, C> {
+    fn from(value: Matrix<T, C, R>) -> Self {
+        Self::from_fn(|row, column| value[column][row].clone())
+    }
+}
@@ -0,0 +1,224 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Interpolation functions for animations and easings, on [`Float`] values 
+//! and component-wise on vectors of them, and smoothing of vectors over
+//! time.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     interp,
+//!     vector::Vector2,
+//! };
+//!
+//! assert_eq!(interp::smoothstep(0.0f32, 2.0, 1.0), 0.5);
+//! assert_eq!(interp::remap(5.0f64, (0.0, 10.0), (100.0, 200.0)), 150.0);
+//!
+//! let progress = interp::inverse_lerp(
+//!     Vector2::<f32>::new([0.0, 10.0]),
+//!     Vector2::new([4.0, 20.0]),
+//!     Vector2::new([1.0, 15.0]),
+//! );
+//! assert_eq!(progress, Vector2::new([0.25, 0.5]));
+//! ```
+
+use crate::{ vector::Vector, Float };
+
+/// Implements the interpolation functions of this module for a type of
+/// values, vectors being interpolated component by component.
+pub trait Interpolate: Sized {
+    /// See [`smoothstep`].
+    fn smoothstep(edge0: Self, edge1: Self, x: Self) -> Self;
+
+    /// See [`smootherstep`].
+    fn smootherstep(edge0: Self, edge1: Self, x: Self) -> Self;
+
+    /// See [`inverse_lerp`].
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> Self;
+
+    /// See [`remap`].
+    fn remap(value: Self, in_range: (Self, Self), out_range: (Self, Self)) -> Self;
+}
+
+/// Returns a smooth transition from zero to one when `x` goes from `edge0` to
+/// `edge1`, the result being clamped outside.
+///
+/// ## Formula
+/// $$
+/// t = \operatorname{clamp} \left( \frac{x - e_{0}}{e_{1} - e_{0}}, 0, 1 \right),
+/// \quad s(t) = 3t^2 - 2t^3
+/// $$
+///
+/// ## Example
+/// ```
+/// use linbra::interp::smoothstep;
+///
+/// assert_eq!(smoothstep(0.0f64, 4.0, 1.0), 0.15625);
+/// assert_eq!(smoothstep(0.0f64, 4.0, 8.0), 1.0);
+/// ```
+pub fn smoothstep<T: Interpolate>(edge0: T, edge1: T, x: T) -> T {
+    T::smoothstep(edge0, edge1, x)
+}
+
+/// Returns a smoother transition than [`smoothstep`] from zero to one when `x`
+/// goes from `edge0` to `edge1`, with null first and second derivatives at
+/// both edges.
+///
+/// ## Formula
+/// $$ s(t) = 6t^5 - 15t^4 + 10t^3 $$
+///
+/// ## Example
+/// ```
+/// use linbra::interp::smootherstep;
+///
+/// assert_eq!(smootherstep(0.0f64, 2.0, 1.0), 0.5);
+/// assert_eq!(smootherstep(0.0f64, 2.0, -1.0), 0.0);
+/// ```
+pub fn smootherstep<T: Interpolate>(edge0: T, edge1: T, x: T) -> T {
+    T::smootherstep(edge0, edge1, x)
+}
+
+/// Returns the factor by which `value` is between `a` and `b`, being the
+/// inverse of a linear interpolation. The result is not clamped.
+///
+/// ## Formula
+/// $$ t = \frac{v - a}{b - a} $$
+///
+/// ## Example
+/// ```
+/// use linbra::interp::inverse_lerp;
+///
+/// assert_eq!(inverse_lerp(10.0f32, 20.0, 12.5), 0.25);
+/// assert_eq!(inverse_lerp(10.0f32, 20.0, 30.0), 2.0);
+/// ```
+pub fn inverse_lerp<T: Interpolate>(a: T, b: T, value: T) -> T {
+    T::inverse_lerp(a, b, value)
+}
+
+/// Returns `value` moved from the range `in_range` to the range `out_range`,
+/// keeping its relative position. The result is not clamped.
+///
+/// ## Formula
+/// $$ o_{0} + (o_{1} - o_{0}) \times \frac{v - i_{0}}{i_{1} - i_{0}} $$
+///
+/// ## Example
+/// ```
+/// use linbra::interp::remap;
+///
+/// assert_eq!(remap(0.5f32, (0.0, 1.0), (-1.0, 1.0)), 0.0);
+/// ```
+pub fn remap<T: Interpolate>(value: T, in_range: (T, T), out_range: (T, T)) -> T {
+    T::remap(value, in_range, out_range)
+}
+
+/// Returns the value clamped between zero and one.
+pub(crate) fn saturate<T: Float>(value: T) -> T {
+    if value < T::zero() {
+        T::zero()
+    } else if value > T::one() {
+        T::one()
+    } else {
+        value
+    }
+}
+
+impl<T: Float> Interpolate for T {
+    fn smoothstep(edge0: Self, edge1: Self, x: Self) -> Self {
+        let (two, three) = (T::from_f64(2.0), T::from_f64(3.0));
+
+        let t = saturate((x - edge0) / (edge1 - edge0));
+        t * t * (three - two * t)
+    }
+
+    fn smootherstep(edge0: Self, edge1: Self, x: Self) -> Self {
+        let (six, ten, fifteen) = (T::from_f64(6.0), T::from_f64(10.0), T::from_f64(15.0));
+
+        let t = saturate((x - edge0) / (edge1 - edge0));
+        t * t * t * (t * (six * t - fifteen) + ten)
+    }
+
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> Self {
+        (value - a) / (b - a)
+    }
+
+    fn remap(value: Self, in_range: (Self, Self), out_range: (Self, Self)) -> Self {
+        let t = Self::inverse_lerp(in_range.0, in_range.1, value);
+        out_range.0 + (out_range.1 - out_range.0) * t
+    }
+}
+
+impl<T: Float, const N: usize> Interpolate for Vector<T, N> {
+    fn smoothstep(edge0: Self, edge1: Self, x: Self) -> Self {
+        Vector::new(std::array::from_fn(|n| T::smoothstep(edge0[n], edge1[n], x[n])))
+    }
+
+    fn smootherstep(edge0: Self, edge1: Self, x: Self) -> Self {
+        Vector::new(std::array::from_fn(|n| T::smootherstep(edge0[n], edge1[n], x[n])))
+    }
+
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> Self {
+        Vector::new(std::array::from_fn(|n| T::inverse_lerp(a[n], b[n], value[n])))
+    }
+
+    fn remap(value: Self, in_range: (Self, Self), out_range: (Self, Self)) -> Self {
+        Vector::new(std::array::from_fn(|n| {
+            T::remap(value[n], (in_range.0[n], in_range.1[n]), (out_range.0[n], out_range.1[n]))
+        }))
+    }
+}
+
+/// Implements the smoothing over time of vectors of [`Float`] values.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the vector moved towards `target` as a critically damped 
+    /// spring, after a time step `dt`, updating its `velocity`.
+    /// 
+    /// The spring reaches the target in about `smooth_time` without 
+    /// overshooting it, which is the usual smoothing of a camera following a 
+    /// character. The velocity must be kept between the calls, starting from 
+    /// zero.
+    /// 
+    /// ## Formula
+    /// The critically damped spring of angular frequency 
+    /// $\omega = \frac{2}{T}$ follows:
+    /// $$ \ddot{x} = -\omega^2 (x - x_{t}) - 2 \omega \dot{x} $$
+    /// 
+    /// With $c = x - x_{t}$ and $k = \dot{x} + \omega c$ at the beginning of 
+    /// the step, its exact solution after $\Delta t$ is:
+    /// $$ 
+    /// x = x_{t} + (c + k \Delta t) e^{-\omega \Delta t}, \quad 
+    /// \dot{x} = (\dot{x} - \omega k \Delta t) e^{-\omega \Delta t} 
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let target = Vector2::new([10.0, 5.0]);
+    /// let mut position = Vector2::<f32>::new([0.0, 0.0]);
+    /// let mut velocity = Vector2::new([0.0, 0.0]);
+    /// 
+    /// for _ in 0..100 {
+    ///     position = position.smooth_damp(target, &mut velocity, 1.0, 0.1);
+    /// }
+    /// 
+    /// assert!(position.distance(target) < 0.01);
+    /// ```
+    pub fn smooth_damp(
+        self, 
+        target: Self, 
+        velocity: &mut Self, 
+        smooth_time: T, 
+        dt: T
+    ) -> Self {
+        let omega = T::from_f64(2.0) / smooth_time;
+        let decay = (-omega * dt).exp();
+
+        let change = self - target;
+        let k = *velocity + change * omega;
+
+        *velocity = (*velocity - k * (omega * dt)) * decay;
+        target + (change + k * dt) * decay
+    }
+}
@@ -0,0 +1,286 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Byte layouts of vectors and matrices of `f32` values, following the `std140`
+//! and `std430` rules of the GLSL uniform and storage buffers.
+//!
+//! The rules differing from the memory layout of the linbra types are:
+//! - a `vec3` is aligned on 16 bytes, so it is followed by 4 padding bytes in
+//!   arrays and before any other vector,
+//! - each column of a matrix is stored as a vector aligned on 16 bytes with
+//!   `std140`, and as a vector of its own alignment with `std430`.
+//!
+//! Bytes are written in the native endianness, as expected by the graphics
+//! APIs when uploading buffers.
+//!
+//! The [`Align16`] wrapper also aligns any vector or matrix on 16 bytes, as
+//! required by SIMD loads and some GPU buffer layouts.
+//!
+//! Vertex buffers are built from slices of vertex attributes with
+//! [`interleave_vertices`], following a [`VertexLayout`].
+
+use std::ops;
+
+use crate::{
+    matrix::{Matrix, Matrix2, Matrix3, Matrix4},
+    vector::{Vector, Vector2, Vector3},
+};
+
+/// Returns the bytes of the matrix columns, each one starting every `stride`
+/// bytes, and the remaining bytes being padding set to zero.
+fn padded_columns<const C: usize, const R: usize, const SIZE: usize>(
+    matrix: Matrix<f32, C, R>,
+    stride: usize,
+) -> [u8; SIZE] {
+    let mut bytes = [0; SIZE];
+
+    for column in 0..C {
+        for row in 0..R {
+            let offset = column * stride + row * 4;
+            bytes[offset..offset + 4].copy_from_slice(&matrix[column][row].to_ne_bytes());
+        }
+    }
+
+    bytes
+}
+
+impl Vector3<f32> {
+    /// Returns the bytes of the vector padded to 16 bytes, being its stride
+    /// in arrays with both the `std140` and `std430` layouts.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    ///
+    /// let bytes = Vector3::<f32>::new([1.0, 2.0, 3.0]).as_std140();
+    ///
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(bytes[8..12], 3.0f32.to_ne_bytes());
+    /// assert_eq!(bytes[12..], [0; 4]);
+    /// ```
+    pub fn as_std140(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+
+        for n in 0..3 {
+            bytes[n * 4..n * 4 + 4].copy_from_slice(&self[n].to_ne_bytes());
+        }
+
+        bytes
+    }
+
+    /// Returns the bytes of the vector padded to 16 bytes, the `std430`
+    /// layout being the same as the [`std140`](Vector3::as_std140) one for
+    /// a `vec3`.
+    pub fn as_std430(self) -> [u8; 16] {
+        self.as_std140()
+    }
+}
+
+impl Matrix2<f32> {
+    /// Returns the bytes of the matrix with the `std140` layout, each column
+    /// being padded to 16 bytes.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    ///
+    /// let bytes = Matrix2::<f32>::new([[1.0, 2.0], [3.0, 4.0]]).as_std140();
+    ///
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(bytes[16..20], 3.0f32.to_ne_bytes());
+    /// ```
+    pub fn as_std140(self) -> [u8; 32] {
+        padded_columns(self, 16)
+    }
+
+    /// Returns the bytes of the matrix with the `std430` layout, where the
+    /// columns are tightly packed.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    ///
+    /// let bytes = Matrix2::<f32>::new([[1.0, 2.0], [3.0, 4.0]]).as_std430();
+    ///
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(bytes[8..12], 3.0f32.to_ne_bytes());
+    /// ```
+    pub fn as_std430(self) -> [u8; 16] {
+        padded_columns(self, 8)
+    }
+}
+
+impl Matrix3<f32> {
+    /// Returns the bytes of the matrix with the `std140` layout, each column
+    /// being padded to 16 bytes.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    ///
+    /// let bytes = Matrix3::<f32>::new([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    /// ]).as_std140();
+    ///
+    /// assert_eq!(bytes.len(), 48);
+    /// assert_eq!(bytes[12..16], [0; 4]);
+    /// assert_eq!(bytes[16..20], 4.0f32.to_ne_bytes());
+    /// ```
+    pub fn as_std140(self) -> [u8; 48] {
+        padded_columns(self, 16)
+    }
+
+    /// Returns the bytes of the matrix with the `std430` layout, each column
+    /// still being padded to 16 bytes since a `vec3` is aligned on 16 bytes.
+    pub fn as_std430(self) -> [u8; 48] {
+        self.as_std140()
+    }
+}
+
+impl Matrix4<f32> {
+    /// Returns the bytes of the matrix with the `std140` layout, which is
+    /// the same as its memory layout.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix4;
+    ///
+    /// let mut matrix = Matrix4::<f32>::natural([[0.0; 4]; 4]);
+    /// matrix[3][0] = 5.0;
+    ///
+    /// let bytes = matrix.as_std140();
+    ///
+    /// assert_eq!(bytes.len(), 64);
+    /// assert_eq!(bytes[48..52], 5.0f32.to_ne_bytes());
+    /// ```
+    pub fn as_std140(self) -> [u8; 64] {
+        padded_columns(self, 16)
+    }
+
+    /// Returns the bytes of the matrix with the `std430` layout, which is
+    /// the same as the [`std140`](Matrix4::as_std140) one for a `mat4`.
+    pub fn as_std430(self) -> [u8; 64] {
+        self.as_std140()
+    }
+}
+
+/// Wrapper aligning its value on 16 bytes, such as the vectors and matrices of
+/// `f32` values for SIMD loads and GPU buffers.
+///
+/// The wrapper dereferences to its value, so the functions of the vectors and
+/// matrices are still available.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     layout::Align16,
+///     vector::Vector4,
+/// };
+///
+/// let vector = Align16(Vector4::<f32>::new([1.0, 2.0, 3.0, 4.0]));
+///
+/// assert_eq!(std::mem::align_of_val(&vector), 16);
+/// assert_eq!(vector.dot(*vector), 30.0);
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[repr(C, align(16))]
+pub struct Align16<T>(pub T);
+
+impl<T> From<T> for Align16<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> ops::Deref for Align16<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> ops::DerefMut for Align16<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Byte layout of interleaved vertices, giving the offsets of their attributes
+/// and the stride between two vertices, as declared to the graphics APIs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VertexLayout {
+    /// Number of bytes between the starts of two vertices.
+    pub stride: usize,
+    /// Offset in bytes of the position, a `vec3` of `f32` values.
+    pub position: usize,
+    /// Offset in bytes of the normal, a `vec3` of `f32` values.
+    pub normal: usize,
+    /// Offset in bytes of the texture coordinates, a `vec2` of `f32` values.
+    pub uv: usize,
+}
+
+impl VertexLayout {
+    /// Layout of tightly packed vertices made of a position, a normal and
+    /// texture coordinates, in this order.
+    pub const POSITION_NORMAL_UV: Self = Self {
+        stride: 32,
+        position: 0,
+        normal: 12,
+        uv: 24,
+    };
+}
+
+/// Writes the bytes of the vector at the start of `bytes`.
+fn write_vector<const N: usize>(bytes: &mut [u8], vector: Vector<f32, N>) {
+    for n in 0..N {
+        bytes[n * 4..n * 4 + 4].copy_from_slice(&vector[n].to_ne_bytes());
+    }
+}
+
+/// Returns the bytes of the vertices made of the given positions, normals and
+/// texture coordinates, interleaved with the
+/// [`VertexLayout::POSITION_NORMAL_UV`] layout.
+///
+/// Panics when the slices do not have the same length.
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     layout::{ interleave_vertices, VertexLayout },
+///     vector::{ Vector2, Vector3 },
+/// };
+///
+/// let positions = [Vector3::new([1.0, 2.0, 3.0]), Vector3::new([4.0, 5.0, 6.0])];
+/// let normals = [Vector3::new([0.0, 0.0, 1.0]); 2];
+/// let uvs = [Vector2::new([0.0, 0.0]), Vector2::new([1.0, 0.5])];
+///
+/// let bytes = interleave_vertices(&positions, &normals, &uvs);
+/// let layout = VertexLayout::POSITION_NORMAL_UV;
+///
+/// assert_eq!(bytes.len(), 2 * layout.stride);
+/// assert_eq!(bytes[layout.stride..][..4], 4.0f32.to_ne_bytes());
+/// assert_eq!(bytes[layout.stride + layout.uv + 4..][..4], 0.5f32.to_ne_bytes());
+/// ```
+pub fn interleave_vertices(
+    positions: &[Vector3<f32>],
+    normals: &[Vector3<f32>],
+    uvs: &[Vector2<f32>],
+) -> Vec<u8> {
+    assert_eq!(positions.len(), normals.len(), "as many normals as positions");
+    assert_eq!(positions.len(), uvs.len(), "as many texture coordinates as positions");
+
+    let layout = VertexLayout::POSITION_NORMAL_UV;
+    let mut bytes = vec![0; positions.len() * layout.stride];
+
+    for (n, vertex) in bytes.chunks_exact_mut(layout.stride).enumerate() {
+        write_vector(&mut vertex[layout.position..], positions[n]);
+        write_vector(&mut vertex[layout.normal..], normals[n]);
+        write_vector(&mut vertex[layout.uv..], uvs[n]);
+    }
+
+    bytes
+}
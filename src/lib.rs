@@ -16,6 +16,8 @@
 //! | $$ \begin{pmatrix} x_{1,1} & x_{1,2} & \dots & x_{1,C} \\\ x_{2,1} & x_{2,2} & \dots & x_{2,C} \\\ \vdots & \vdots & \ddots & \vdots \\\ x_{R,1} & x_{R,2} & \dots & x_{R,C} \\\ \end{pmatrix} $$ | [`Matrix<T, C, R>`](matrix::Matrix) | <ul><li>[`Matrix2<T>`](matrix::Matrix2)</li> <li>[`Matrix3<T>`](matrix::Matrix3)</li> <li>[`Matrix4<T>`](matrix::Matrix4)</li></ul> |
 //! | | | |
 //! | $$ \begin{pmatrix} a_{1} \\\ a_{2} \\\ \vdots \\\ a_{n} \\\ \end{pmatrix} $$ | [`Vector<T, N>`](vector::Vector) | <ul><li>[`Vector2<T>`](vector::Vector2)</li> <li>[`Vector3<T>`](vector::Vector3)</li> <li>[`Vector4<T>`](vector::Vector4)</li></ul> |
+//! | | | |
+//! | $$ \left[ x, x + w \right] \times \left[ y, y + h \right] $$ | [`Rect<T>`](rects::Rect) | |
 //! 
 //! - Tools:
 //! 
@@ -26,30 +28,122 @@
 //! | $$ \begin{pmatrix} w \\\ h \\\ \end{pmatrix} or \begin{pmatrix} w \\\ h \\\ d \\\ \end{pmatrix} $$ | <ul><li>[`Size2<T>`](sizes::Size2)</li><li>[`Size3<T>`](sizes::Size3)</li></ul> | <ul><li>[`::w()`](sizes::Size2::w)</li><li>[`::h()`](sizes::Size2::h)</li><li>[`::d()`](sizes::Size3::d)</li></ul> |
 //! | | | |
 //! | $$ \begin{pmatrix} r \\\ g \\\ b \\\ \end{pmatrix} or \begin{pmatrix} r \\\ g \\\ b \\\ a \\\ \end{pmatrix} $$ | <ul><li>[`RGB<T>`](colours::RGB)</li><li>[`RGBA<T>`](colours::RGBA)</li></ul> | <ul><li>[`::r()`](colours::RGB::r)</li><li>[`::g()`](colours::RGB::g)</li><li>[`::b()`](colours::RGB::b)</li><li>[`::a()`](colours::RGBA::a)</li></ul> |
+//! 
+//! The strongly-typed [`Point2D`](points::Point2D), [`Size2D`](sizes::Size2D) 
+//! and [`Colour`](colours::Colour) wrap vectors so that a point, a size or a 
+//! colour cannot be mixed accidentally with vectors having another meaning. 
+//! The underlying vector is retrieved or wrapped with the `From`/`Into` 
+//! conversions.
+//! 
+//! ## Features
+//! Optional features add interoperability with other crates:
+//! 
+//! | Feature | Description |
+//! | --- | --- |
+//! | `approx` | `AbsDiffEq`, `RelativeEq` and `UlpsEq` for vectors and matrices, to compare them with a tolerance |
+//! | `bytemuck` | `Pod` and `Zeroable` for vectors and matrices, to cast them from and to bytes, and `Matrix::as_bytes` |
+//! | `cgmath` | Conversions from and to the `cgmath` vectors, points and matrices |
+//! | `fixed` | Fixed-point values of the `fixed` crate in vectors and matrices, for deterministic calculations |
+//! | `glam` | Conversions from and to the `glam` vectors and matrices of `f32` and `f64` values |
+//! | `half` | Half-precision `f16` values in vectors and matrices, and conversions from and to the ones of `f32` and `f64` values |
+//! | `libm` | Implements [`Float`] with the functions of the `libm` crate instead of the ones of `std`, giving the same results on all platforms |
+//! | `mint` | Conversions from and to the `mint` vectors, points and column matrices |
+//! | `nalgebra` | Conversions from and to the `nalgebra` statically-sized vectors and matrices |
+//! | `num-rational` | Enables `num-traits`, so that matrices of rational values from `num-rational` are reduced exactly |
+//! | `num-traits` | Implements [`Zero`], [`One`], [`Num`] and [`Signed`] for all the types implementing their `num-traits` equivalents |
+//! | `rand` | Random vectors and matrices from the `StandardUniform` and `Uniform` distributions, and samplers of directions |
+//! | `rayon` | `Matrix::par_mul`, `Matrix::par_rref`, `Matrix::par_determinant` and `Matrix::par_solve_system`, computing the products and the eliminations of large matrices in parallel |
+//! | `render` | `Matrix::to_latex` and `Matrix::to_markdown_table`, rendering matrices as LaTeX or Markdown source |
+//! | `rkyv` | `Archive`, `Serialize` and `Deserialize` for vectors and matrices, to access them from bytes without parsing |
+//! | `serde` | `Serialize` and `Deserialize` for vectors and matrices, as flat sequences of values |
+//! | `simd` | `add_simd`, `mul_simd`, `dot_simd`, `transform_simd` and `inverse_simd` for the `f32` 4D vectors and 4x4 matrices, written with SSE or NEON instructions |
 
 #![warn(missing_docs)]
 #![warn(missing_debug_implementations)]
 
 use std::ops;
 
+pub mod angles;
+pub mod cameras;
 pub mod colours;
+mod error;
+pub mod ffi;
+pub mod fitting;
+pub mod ga;
+pub mod geometry;
+pub mod gjk;
+pub mod grids;
+pub mod hashing;
+pub mod interp;
+#[cfg(any(
+    feature = "approx",
+    feature = "cgmath",
+    feature = "fixed",
+    feature = "glam",
+    feature = "half",
+    feature = "mint",
+    feature = "nalgebra",
+))]
+mod interop;
+pub mod layout;
 pub mod matrix;
 mod operations;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "bytemuck")]
+mod pod;
 pub mod points;
+#[cfg(feature = "rand")]
+mod random;
+pub mod rects;
+#[cfg(feature = "render")]
+mod render;
+pub mod sat;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod sparse;
+mod statistics;
+pub mod tensor;
 pub mod vector;
 pub mod sizes;
 
+pub use error::Error;
+pub use operations::Dot;
+
 /// Implements a function to get the zero-value of the type.
 /// 
-/// This trait is implemented for all the number-primitive types.
-pub trait Zero: Copy {
+/// This trait is implemented for all the number-primitive types. With the 
+/// `num-traits` feature, it is implemented instead for all the types 
+/// implementing `num_traits::Zero`.
+pub trait Zero: Clone {
     /// Returns a zero-value of this type.
     fn zero() -> Self;
 }
 
+/// Implements a function to get the one-value of the type, being the 
+/// multiplicative identity.
+/// 
+/// This trait is implemented for all the number-primitive types. With the 
+/// `num-traits` feature, it is implemented instead for all the types 
+/// implementing `num_traits::One`.
+pub trait One: Clone {
+    /// Returns a one-value of this type.
+    fn one() -> Self;
+}
+
 /// Common properties to all the number-primitive types.
 /// 
 /// No function provided.
+/// 
+/// Only [`Clone`] is required, and not [`Copy`], so that arbitrary-precision 
+/// types can be used as well.
+/// 
+/// With the `num-traits` feature, this trait is implemented instead for all 
+/// the types implementing `num_traits::Num` and 
+/// `num_traits::NumAssignOps`, letting third-party scalar types be used in 
+/// vectors and matrices.
 pub trait Num
 where 
     Self: ops::Add<Output = Self> 
@@ -59,35 +153,272 @@ where
         + ops::SubAssign
         + ops::MulAssign
         + PartialEq 
-        + Copy 
+        + Clone 
+{}
+
+/// Common properties to the signed number-primitive types, being the ones 
+/// implementing the negation.
+/// 
+/// No function provided.
+/// 
+/// With the `num-traits` feature, this trait is implemented instead for all 
+/// the types implementing `num_traits::Signed` and 
+/// `num_traits::NumAssignOps`.
+pub trait Signed: Num + ops::Neg<Output = Self> {}
+
+/// Functions of the floating-point number-primitive types, so that the 
+/// calculations requiring square roots or trigonometry are written once for 
+/// both `f32` and `f64`.
+/// 
+/// This trait is implemented for `f32` and `f64` with the functions of `std`, 
+/// or of the `libm` crate with the `libm` feature.
+pub trait Float: Signed + Zero + One + PartialOrd + Copy + ops::Div<Output = Self> {
+    /// Archimedes' constant $\pi$, being the half turn in radians.
+    const PI: Self;
+
+    /// Difference between one and the next greater representable value, to 
+    /// derive the tolerances of the calculations.
+    const EPSILON: Self;
+
+    /// Returns the nearest value to the `f64` one, such as a constant of a 
+    /// formula.
+    fn from_f64(value: f64) -> Self;
+
+    /// Returns the nearest value to the `usize` one, such as a number of 
+    /// values to divide by.
+    fn from_usize(value: usize) -> Self;
+
+    /// Returns the square root of the value.
+    fn sqrt(self) -> Self;
+
+    /// Returns the sine of the value, in radians.
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of the value, in radians.
+    fn cos(self) -> Self;
+
+    /// Returns the angle in radians between the x-axis and the point 
+    /// `(other, self)`, in the range $[-\pi, \pi]$.
+    fn atan2(self, other: Self) -> Self;
+
+    /// Returns the value raised to the power `n`.
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns $e$ raised to the power of the value.
+    fn exp(self) -> Self;
+
+    /// Returns the nearest integer to the value, rounding half-way cases away 
+    /// from zero.
+    fn round(self) -> Self;
+
+    /// Returns the greatest integer less than or equal to the value.
+    fn floor(self) -> Self;
+}
+
+/// Common properties to the types whose division is the inverse of the 
+/// multiplication, being the floating-point and the rational ones, unlike the 
+/// integer ones whose divisions are truncated.
+/// 
+/// This trait is implemented for `f32` and `f64`, and for the rational values 
+/// of `num-rational` with the `num-rational` feature.
+pub trait Field: Zero + One + Num + ops::Div<Output = Self> {
+    /// Returns the relative rounding error of the calculations, being 
+    /// [`Float::EPSILON`] for the floating-point values and zero for the exact 
+    /// ones, by default.
+    fn epsilon() -> Self {
+        Self::zero()
+    }
+}
+
+impl Field for f32 {
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+}
+
+impl Field for f64 {
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl<T> Field for num_rational::Ratio<T> 
+where 
+    Self: Zero + One + Num + ops::Div<Output = Self> 
 {}
 
 macro_rules! impl_primitive_numbers {
-    ($type:tt, $zero:literal) => {
+    ($type:tt, $zero:literal, $one:literal) => {
+        #[cfg(not(feature = "num-traits"))]
         impl Zero for $type {
             fn zero() -> Self {
                 $zero
             }
         }
 
+        #[cfg(not(feature = "num-traits"))]
+        impl One for $type {
+            fn one() -> Self {
+                $one
+            }
+        }
+
+        #[cfg(not(feature = "num-traits"))]
         impl Num for $type {}
     };
 }
 
-impl_primitive_numbers!(i8, 0);
-impl_primitive_numbers!(i16, 0);
-impl_primitive_numbers!(i32, 0);
-impl_primitive_numbers!(i64, 0);
-impl_primitive_numbers!(i128, 0);
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Zero + Clone> Zero for T {
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+}
+
+macro_rules! impl_signed_numbers {
+    ($type:tt) => {
+        #[cfg(not(feature = "num-traits"))]
+        impl Signed for $type {}
+    };
+}
+
+macro_rules! impl_float_numbers {
+    ($type:tt, $sqrt:ident, $sin:ident, $cos:ident, $atan2:ident, $powf:ident, $exp:ident, $round:ident, $floor:ident) => {
+        #[cfg(not(feature = "libm"))]
+        impl Float for $type {
+            const PI: Self = std::$type::consts::PI;
+            const EPSILON: Self = $type::EPSILON;
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+
+            fn from_usize(value: usize) -> Self {
+                value as $type
+            }
+
+            fn sqrt(self) -> Self {
+                $type::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                $type::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $type::cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $type::atan2(self, other)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $type::powf(self, n)
+            }
+
+            fn exp(self) -> Self {
+                $type::exp(self)
+            }
+
+            fn round(self) -> Self {
+                $type::round(self)
+            }
+
+            fn floor(self) -> Self {
+                $type::floor(self)
+            }
+        }
+
+        #[cfg(feature = "libm")]
+        impl Float for $type {
+            const PI: Self = std::$type::consts::PI;
+            const EPSILON: Self = $type::EPSILON;
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+
+            fn from_usize(value: usize) -> Self {
+                value as $type
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm::$powf(self, n)
+            }
+
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::One + Clone> One for T {
+    fn one() -> Self {
+        num_traits::One::one()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Num + num_traits::NumAssignOps + Clone> Num for T {}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Signed + num_traits::NumAssignOps + Clone> Signed for T {}
+
+impl_primitive_numbers!(i8, 0, 1);
+impl_primitive_numbers!(i16, 0, 1);
+impl_primitive_numbers!(i32, 0, 1);
+impl_primitive_numbers!(i64, 0, 1);
+impl_primitive_numbers!(i128, 0, 1);
+
+impl_primitive_numbers!(u8, 0, 1);
+impl_primitive_numbers!(u16, 0, 1);
+impl_primitive_numbers!(u32, 0, 1);
+impl_primitive_numbers!(u64, 0, 1);
+impl_primitive_numbers!(u128, 0, 1);
+
+impl_primitive_numbers!(isize, 0, 1);
+impl_primitive_numbers!(usize, 0, 1);
+
+impl_primitive_numbers!(f32, 0.0, 1.0);
+impl_primitive_numbers!(f64, 0.0, 1.0);
 
-impl_primitive_numbers!(u8, 0);
-impl_primitive_numbers!(u16, 0);
-impl_primitive_numbers!(u32, 0);
-impl_primitive_numbers!(u64, 0);
-impl_primitive_numbers!(u128, 0);
+impl_signed_numbers!(i8);
+impl_signed_numbers!(i16);
+impl_signed_numbers!(i32);
+impl_signed_numbers!(i64);
+impl_signed_numbers!(i128);
+impl_signed_numbers!(isize);
 
-impl_primitive_numbers!(isize, 0);
-impl_primitive_numbers!(usize, 0);
+impl_signed_numbers!(f32);
+impl_signed_numbers!(f64);
 
-impl_primitive_numbers!(f32, 0.0);
-impl_primitive_numbers!(f64, 0.0);
+impl_float_numbers!(f32, sqrtf, sinf, cosf, atan2f, powf, expf, roundf, floorf);
+impl_float_numbers!(f64, sqrt, sin, cos, atan2, pow, exp, round, floor);
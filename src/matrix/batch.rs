@@ -0,0 +1,89 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Transformations of slices of points and directions by a single 4x4 matrix,
+//! such as the vertices and normals of a mesh.
+
+use crate::Float;
+use crate::matrix::Matrix4;
+use crate::vector::Vector3;
+
+impl<T: Float> Matrix4<T> {
+    /// Transforms in place the points of the slice, as homogeneous
+    /// coordinates with $w = 1$ divided back by their $w$ afterwards, so that
+    /// projections are applied as well.
+    ///
+    /// The values of the matrix are read once, and the points are updated in
+    /// a single pass over the slice.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} x' \\\ y' \\\ z' \\\ w' \end{pmatrix} = M \begin{pmatrix} x \\\ y \\\ z \\\ 1 \end{pmatrix}, \quad p' = \frac{1}{w'} \begin{pmatrix} x' \\\ y' \\\ z' \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix4,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let translation = Matrix4::<f32>::natural([
+    ///     [1.0, 0.0, 0.0, 5.0],
+    ///     [0.0, 1.0, 0.0, 6.0],
+    ///     [0.0, 0.0, 1.0, 7.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// let mut vertices = [Vector3::new([0.0, 0.0, 0.0]), Vector3::new([1.0, 2.0, 3.0])];
+    /// translation.transform_points(&mut vertices);
+    ///
+    /// assert_eq!(vertices, [Vector3::new([5.0, 6.0, 7.0]), Vector3::new([6.0, 8.0, 10.0])]);
+    /// ```
+    pub fn transform_points(&self, points: &mut [Vector3<T>]) {
+        let [x, y, z, w] = self.to_cols_array_2d();
+
+        for point in points {
+            let [px, py, pz] = [point[0], point[1], point[2]];
+            let row = |n: usize| x[n] * px + y[n] * py + z[n] * pz + w[n];
+            let divisor = row(3);
+
+            *point = Vector3::new([row(0) / divisor, row(1) / divisor, row(2) / divisor]);
+        }
+    }
+
+    /// Transforms in place the directions of the slice, as homogeneous
+    /// coordinates with $w = 0$, so that translations are not applied.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} x' \\\ y' \\\ z' \\\ w' \end{pmatrix} = M \begin{pmatrix} x \\\ y \\\ z \\\ 0 \end{pmatrix}, \quad v' = \begin{pmatrix} x' \\\ y' \\\ z' \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix4,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let transform = Matrix4::<f32>::natural([
+    ///     [2.0, 0.0, 0.0, 5.0],
+    ///     [0.0, 2.0, 0.0, 6.0],
+    ///     [0.0, 0.0, 2.0, 7.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// let mut directions = [Vector3::new([1.0, 0.0, 0.0]), Vector3::new([0.0, 1.0, 1.0])];
+    /// transform.transform_vectors(&mut directions);
+    ///
+    /// assert_eq!(directions, [Vector3::new([2.0, 0.0, 0.0]), Vector3::new([0.0, 2.0, 2.0])]);
+    /// ```
+    pub fn transform_vectors(&self, vectors: &mut [Vector3<T>]) {
+        let [x, y, z, _] = self.to_cols_array_2d();
+
+        for vector in vectors {
+            let [vx, vy, vz] = [vector[0], vector[1], vector[2]];
+            let row = |n: usize| x[n] * vx + y[n] * vy + z[n] * vz;
+
+            *vector = Vector3::new([row(0), row(1), row(2)]);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Discrete 2D convolutions of matrices by kernels, such as to blur or sharpen
+//! grids of data like influence maps or heightmaps.
+
+use crate::{ Num, Zero };
+use crate::matrix::Matrix;
+
+/// Values read outside of a matrix by a convolution keeping its dimensions.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Edges<T> {
+    /// The values outside are all the same one, being zero for most filters.
+    Constant(T),
+    /// The values outside are the ones of the closest edge.
+    Clamp,
+    /// The matrix repeats itself, as for tiling textures.
+    Wrap,
+}
+
+impl<T: Clone, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the value at the column and the row, possibly outside of the
+    /// matrix.
+    fn sample(&self, column: isize, row: isize, edges: &Edges<T>) -> T {
+        let inside = |index: isize, length: usize| -> Option<usize> {
+            match edges {
+                Edges::Constant(_) => usize::try_from(index).ok().filter(|index| *index < length),
+                Edges::Clamp => Some(index.clamp(0, length as isize - 1) as usize),
+                Edges::Wrap => Some(index.rem_euclid(length as isize) as usize),
+            }
+        };
+
+        match (inside(column, C), inside(row, R), edges) {
+            (Some(column), Some(row), _) => self[column][row].clone(),
+            (_, _, Edges::Constant(value)) => value.clone(),
+            _ => unreachable!("only constant edges are out of the matrix"),
+        }
+    }
+}
+
+impl<T: Num + Zero, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the convolution of the matrix by the kernel, keeping its
+    /// dimensions, the values outside of the matrix being given by `edges`.
+    ///
+    /// The kernel is centred on each value, its centre being at the column
+    /// $\lfloor KC / 2 \rfloor$ and the row $\lfloor KR / 2 \rfloor$. As a
+    /// convolution, the kernel is flipped in both directions, which does not
+    /// change the symmetric kernels of blurs and sharpens.
+    ///
+    /// ## Formula
+    /// With the centre $(c_{x}, c_{y})$ of the kernel $K$:
+    /// $$ (M * K)_{x,y} = \sum_{i,j} K_{i,j} \, M_{x + c_{x} - i, \, y + c_{y} - j} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::{ Edges, Matrix, Matrix3 };
+    ///
+    /// let heights = Matrix::<i32, 4, 3>::natural([
+    ///     [0, 0, 0, 0],
+    ///     [0, 9, 0, 0],
+    ///     [0, 0, 0, 0],
+    /// ]);
+    /// let cross = Matrix3::natural([
+    ///     [0, 1, 0],
+    ///     [1, 1, 1],
+    ///     [0, 1, 0],
+    /// ]);
+    ///
+    /// assert_eq!(heights.convolve(&cross, Edges::Constant(0)), Matrix::natural([
+    ///     [0, 9, 0, 0],
+    ///     [9, 9, 9, 0],
+    ///     [0, 9, 0, 0],
+    /// ]));
+    /// assert_eq!(heights.convolve(&cross, Edges::Wrap)[1][0], 9);
+    /// ```
+    pub fn convolve<const KC: usize, const KR: usize>(
+        &self,
+        kernel: &Matrix<T, KC, KR>,
+        edges: Edges<T>,
+    ) -> Self {
+        let (centre_column, centre_row) = ((KC / 2) as isize, (KR / 2) as isize);
+
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| {
+                let mut sum = T::zero();
+
+                for (i, kernel_column) in kernel.into_iter().enumerate() {
+                    for (j, weight) in kernel_column.iter().enumerate() {
+                        let value = self.sample(
+                            column as isize + centre_column - i as isize,
+                            row as isize + centre_row - j as isize,
+                            &edges,
+                        );
+
+                        sum += weight.clone() * value;
+                    }
+                }
+
+                sum
+            })
+        }))
+    }
+
+    /// Returns the convolution of the matrix by the kernel only where the
+    /// kernel fits entirely in the matrix, being a matrix of `C2 = C - KC + 1`
+    /// columns and `R2 = R - KR + 1` rows.
+    ///
+    /// The dimensions are checked at compile time.
+    ///
+    /// ## Formula
+    /// $$ (M * K)_{x,y} = \sum_{i,j} K_{i,j} \, M_{x + KC - 1 - i, \, y + KR - 1 - j} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::{ Matrix, Matrix2 };
+    ///
+    /// let grid = Matrix::<i32, 3, 3>::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ]);
+    /// let box_sum = Matrix2::splat(1);
+    ///
+    /// assert_eq!(grid.convolve_valid::<2, 2, 2, 2>(&box_sum), Matrix2::natural([
+    ///     [12, 16],
+    ///     [24, 28],
+    /// ]));
+    /// ```
+    ///
+    /// The following fails to compile since the output has the dimensions of
+    /// the input:
+    /// ```compile_fail
+    /// use linbra::matrix::{ Matrix2, Matrix3 };
+    ///
+    /// let grid = Matrix3::splat(1);
+    /// grid.convolve_valid::<2, 2, 3, 3>(&Matrix2::splat(1));
+    /// ```
+    pub fn convolve_valid<const KC: usize, const KR: usize, const C2: usize, const R2: usize>(
+        &self,
+        kernel: &Matrix<T, KC, KR>,
+    ) -> Matrix<T, C2, R2> {
+        const {
+            assert!(
+                KC <= C && KR <= R && C2 == C - KC + 1 && R2 == R - KR + 1,
+                "valid convolution to dimensions other than the input minus the kernel plus one"
+            )
+        };
+
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| {
+                let mut sum = T::zero();
+
+                for (i, kernel_column) in kernel.into_iter().enumerate() {
+                    for (j, weight) in kernel_column.iter().enumerate() {
+                        sum += weight.clone() * self[column + KC - 1 - i][row + KR - 1 - j].clone();
+                    }
+                }
+
+                sum
+            })
+        }))
+    }
+}
@@ -0,0 +1,535 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Matrix whose dimensions are only known at runtime, its values being stored
+//! on the heap.
+//!
+//! Operations between matrices or vectors panic when their dimensions do not
+//! match.
+
+use std::ops;
+
+use crate::{ Error, Field, Num, One, Signed, Zero };
+use crate::matrix::{echelon, Matrix};
+use crate::vector::DVector;
+
+/// Matrix with dimensions known at runtime, for data such as heightmaps or
+/// adjacency matrices loaded from files.
+///
+/// As [`Matrix`], the values are stored column by column.
+///
+/// $$
+/// \begin{pmatrix}
+///     x_{1,1} & \dots & x_{1,C} \\\
+///     \vdots & \ddots & \vdots \\\
+///     x_{R,1} & \dots & x_{R,C} \\\
+/// \end{pmatrix}
+/// $$
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DMatrix<T> {
+    columns: usize,
+    rows: usize,
+    /// Values of the matrix, column by column.
+    data: Vec<T>
+}
+
+impl<T> DMatrix<T> {
+    /// Creates a new matrix from its values, column by column.
+    ///
+    /// Panics when the number of values is not `columns * rows`.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::DMatrix;
+    ///
+    /// let matrix = DMatrix::new(3, 2, vec![
+    ///     10, 15,
+    ///     20, 25,
+    ///     30, 35,
+    /// ]);
+    ///
+    /// assert_eq!(matrix[1], [20, 25]);
+    /// ```
+    pub fn new(columns: usize, rows: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), columns * rows, "wrong number of values for the dimensions");
+
+        Self { columns, rows, data }
+    }
+
+    /// Creates a new matrix from its rows, in the natural order.
+    ///
+    /// Without any row, the number of columns is unknown and the matrix is 
+    /// 0x0, see [`DMatrix::from_rows`] to keep it.
+    ///
+    /// Panics when the rows are not all of the same length.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::DMatrix;
+    ///
+    /// let matrix = DMatrix::natural(vec![
+    ///     vec![10, 20, 30],
+    ///     vec![15, 25, 35],
+    /// ]);
+    ///
+    /// assert_eq!(matrix, DMatrix::new(3, 2, vec![10, 15, 20, 25, 30, 35]));
+    /// ```
+    pub fn natural(data: Vec<Vec<T>>) -> Self {
+        let columns = data.first().map_or(0, Vec::len);
+        Self::from_rows(columns, data)
+    }
+
+    /// Creates a new matrix of `columns` columns from its rows, in the 
+    /// natural order, keeping the number of columns even without any row.
+    ///
+    /// Panics when a row is not of `columns` values.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::DMatrix;
+    ///
+    /// let empty = DMatrix::<i32>::from_rows(3, vec![]);
+    ///
+    /// assert_eq!((empty.columns(), empty.rows()), (3, 0));
+    /// assert_eq!(DMatrix::<i32>::natural(vec![]).columns(), 0);
+    /// ```
+    pub fn from_rows(columns: usize, data: Vec<Vec<T>>) -> Self {
+        let rows = data.len();
+
+        let mut iterators: Vec<_> = data
+            .into_iter()
+            .map(|row| {
+                assert_eq!(row.len(), columns, "rows of different lengths");
+                row.into_iter()
+            })
+            .collect();
+
+        let mut values = Vec::with_capacity(columns * rows);
+        for _ in 0..columns {
+            for row in &mut iterators {
+                values.extend(row.next());
+            }
+        }
+
+        Self::new(columns, rows, values)
+    }
+
+    /// Returns the number of columns of the matrix.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows of the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns all the values of the matrix, column by column.
+    pub(crate) fn values(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns all the values of the matrix, column by column, as mutable.
+    pub(crate) fn values_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T: Zero> DMatrix<T> {
+    /// Creates a new matrix filled with zeros.
+    pub fn zeroed(columns: usize, rows: usize) -> Self {
+        Self::new(columns, rows, vec![T::zero(); columns * rows])
+    }
+}
+
+impl<T: Field + PartialOrd> DMatrix<T> {
+    /// Returns the reduced row echelon form of the matrix, as
+    /// [`Matrix::rref`].
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::DMatrix;
+    ///
+    /// let matrix = DMatrix::<f64>::natural(vec![
+    ///     vec![1.0, 2.0, 3.0],
+    ///     vec![2.0, 4.0, 8.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.rref(), DMatrix::natural(vec![
+    ///     vec![1.0, 2.0, 0.0],
+    ///     vec![0.0, 0.0, 1.0],
+    /// ]));
+    /// ```
+    pub fn rref(self) -> Self {
+        let mut matrix = self;
+        let tolerance = echelon::tolerance(&matrix.data, matrix.rows);
+        echelon::reduce(&mut matrix.data, matrix.rows, &tolerance);
+        matrix
+    }
+}
+
+impl<T: Zero + One + Num + ops::Div<Output = T> + PartialOrd> DMatrix<T> {
+    /// Returns the determinant of the matrix, as [`Matrix::determinant`].
+    ///
+    /// Panics when the matrix is not square.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::DMatrix;
+    ///
+    /// let matrix = DMatrix::<f64>::natural(vec![
+    ///     vec![0.0, 2.0, 1.0],
+    ///     vec![1.0, 1.0, 0.0],
+    ///     vec![2.0, 0.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.determinant(), -10.0);
+    /// assert_eq!(DMatrix::<f64>::zeroed(0, 0).determinant(), 1.0);
+    /// ```
+    pub fn determinant(self) -> T {
+        assert_eq!(self.columns, self.rows, "determinant of a non-square matrix");
+
+        let mut matrix = self;
+        echelon::eliminate(&mut matrix.data, matrix.rows)
+    }
+}
+
+impl<T: Field + PartialOrd> DMatrix<T> {
+    /// Returns the values of the augmented matrix $(A \mid B)$ reduced to 
+    /// their reduced row echelon form, or [`Error::SingularMatrix`] when $A$ 
+    /// is not invertible, its reduced form not being the identity.
+    ///
+    /// Only the values of $A$ give the tolerance of the pivots, a nearly 
+    /// singular $A$ of floating-point values being singular whatever $B$.
+    fn reduce_augmented(self, augmentation: Vec<T>) -> Result<Vec<T>, Error> {
+        assert_eq!(self.columns, self.rows, "not a square matrix");
+
+        let size = self.rows;
+        let tolerance = echelon::tolerance(&self.data, size);
+        let mut values = self.data;
+        values.extend(augmentation);
+
+        echelon::reduce(&mut values, size, &tolerance);
+
+        if (0..size).any(|n| values[n * size + n] != T::one()) {
+            return Err(Error::SingularMatrix);
+        }
+
+        Ok(values.split_off(size * size))
+    }
+
+    /// Returns the inverse of the square matrix, by Gauss-Jordan elimination 
+    /// of $(A \mid I)$, or [`Error::SingularMatrix`] when the matrix is not 
+    /// invertible.
+    ///
+    /// Panics when the matrix is not square.
+    ///
+    /// ## Formula
+    /// $$ (A \mid I) \to (I \mid A^{-1}) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::DMatrix,
+    ///     Error,
+    /// };
+    ///
+    /// let matrix = DMatrix::<f64>::natural(vec![
+    ///     vec![2.0, 0.0],
+    ///     vec![1.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.inverse(), Ok(DMatrix::natural(vec![
+    ///     vec![0.5, 0.0],
+    ///     vec![-0.125, 0.25],
+    /// ])));
+    ///
+    /// let singular = DMatrix::<f64>::natural(vec![
+    ///     vec![1.0, 2.0],
+    ///     vec![2.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(singular.inverse(), Err(Error::SingularMatrix));
+    ///
+    /// // Singular matrix whose elimination leaves rounding errors instead of
+    /// // a zero row.
+    /// let nearly_singular = DMatrix::<f64>::natural(vec![
+    ///     vec![0.1, 0.2, 0.3],
+    ///     vec![0.4, 0.5, 0.6],
+    ///     vec![0.7, 0.8, 0.9],
+    /// ]);
+    ///
+    /// assert_eq!(nearly_singular.inverse(), Err(Error::SingularMatrix));
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        let size = self.rows;
+        let identity = (0..size * size)
+            .map(|n| if n % (size + 1) == 0 { T::one() } else { T::zero() })
+            .collect();
+
+        Ok(Self::new(size, size, self.reduce_augmented(identity)?))
+    }
+
+    /// Solves the square linear system $Ax = b$ with a unique solution, by 
+    /// Gauss-Jordan elimination of $(A \mid b)$, or fails with 
+    /// [`Error::SingularMatrix`] when the system has no solution or 
+    /// infinitely many ones.
+    ///
+    /// Panics when the matrix is not square or when the vector is not of its 
+    /// size.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::DMatrix,
+    ///     vector::DVector,
+    ///     Error,
+    /// };
+    ///
+    /// // x + 2y = 5 and 3x - y = 1
+    /// let a = DMatrix::<f64>::natural(vec![
+    ///     vec![1.0, 2.0],
+    ///     vec![3.0, -1.0],
+    /// ]);
+    ///
+    /// assert_eq!(a.solve(DVector::new(vec![5.0, 1.0])), Ok(DVector::new(vec![1.0, 2.0])));
+    ///
+    /// let nearly_singular = DMatrix::<f64>::natural(vec![
+    ///     vec![0.1, 0.2, 0.3],
+    ///     vec![0.4, 0.5, 0.6],
+    ///     vec![0.7, 0.8, 0.9],
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     nearly_singular.solve(DVector::new(vec![1.0, 2.0, 3.0])), 
+    ///     Err(Error::SingularMatrix),
+    /// );
+    /// ```
+    pub fn solve(self, b: DVector<T>) -> Result<DVector<T>, Error> {
+        assert_eq!(self.rows, b.len(), "matrix and vector of incompatible dimensions");
+
+        Ok(DVector::new(self.reduce_augmented(b.into_iter().collect())?))
+    }
+}
+
+/// Creates a dynamic matrix from the values of a fixed-size matrix.
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::{ DMatrix, Matrix2 };
+///
+/// let matrix: DMatrix<i32> = Matrix2::new([[1, 2], [3, 4]]).into();
+/// assert_eq!(matrix[1], [3, 4]);
+/// ```
+impl<T, const C: usize, const R: usize> From<Matrix<T, C, R>> for DMatrix<T> {
+    fn from(value: Matrix<T, C, R>) -> Self {
+        let data = value.to_cols_array_2d().into_iter().flatten().collect();
+        Self::new(C, R, data)
+    }
+}
+
+/// Creates a fixed-size matrix from the values of a dynamic matrix.
+///
+/// Fails with [`Error::DimensionMismatch`] when the dimensions differ.
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::{ DMatrix, Matrix2 };
+///
+/// let matrix = DMatrix::natural(vec![
+///     vec![1, 2],
+///     vec![3, 4],
+/// ]);
+///
+/// assert_eq!(Matrix2::try_from(matrix), Ok(Matrix2::natural([[1, 2], [3, 4]])));
+/// assert!(Matrix2::try_from(DMatrix::<i32>::zeroed(3, 2)).is_err());
+/// ```
+impl<T, const C: usize, const R: usize> TryFrom<DMatrix<T>> for Matrix<T, C, R> {
+    type Error = Error;
+
+    fn try_from(value: DMatrix<T>) -> Result<Self, Self::Error> {
+        if value.columns != C || value.rows != R {
+            return Err(Error::DimensionMismatch { 
+                expected: (C, R), 
+                found: (value.columns, value.rows),
+            });
+        }
+
+        let mut values = value.data.into_iter();
+
+        Ok(Matrix::new(std::array::from_fn(|_| {
+            std::array::from_fn(|_| values.next().expect("C * R values"))
+        })))
+    }
+}
+
+/// Returns the column at index `n` in the matrix.
+impl<T> ops::Index<usize> for DMatrix<T> {
+    type Output = [T];
+
+    fn index(&self, column: usize) -> &Self::Output {
+        &self.data[column * self.rows..(column + 1) * self.rows]
+    }
+}
+
+/// Returns the column at index `n` in the matrix, as mutable.
+impl<T> ops::IndexMut<usize> for DMatrix<T> {
+    fn index_mut(&mut self, column: usize) -> &mut Self::Output {
+        &mut self.data[column * self.rows..(column + 1) * self.rows]
+    }
+}
+
+/// Implementation for matrices product, as for [`Matrix`].
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::DMatrix;
+///
+/// let a = DMatrix::natural(vec![
+///     vec![1, 2],
+///     vec![3, 4],
+/// ]);
+/// let b = DMatrix::natural(vec![
+///     vec![5, 6, 7],
+///     vec![8, 9, 10],
+/// ]);
+///
+/// assert_eq!(a * b, DMatrix::natural(vec![
+///     vec![21, 24, 27],
+///     vec![47, 54, 61],
+/// ]));
+/// ```
+impl<T: Zero + Num> ops::Mul<Self> for DMatrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.columns, rhs.rows, "matrices of incompatible dimensions");
+
+        let mut output = Self::zeroed(rhs.columns, self.rows);
+
+        for column in 0..rhs.columns {
+            for k in 0..self.columns {
+                let value = rhs[column][k].clone();
+
+                for row in 0..self.rows {
+                    output[column][row] += self[k][row].clone() * value.clone();
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// Implementation for matrix-vector product, as for [`Matrix`].
+///
+/// ## Example
+/// ```
+/// use linbra::{
+///     matrix::DMatrix,
+///     vector::DVector,
+/// };
+///
+/// let matrix = DMatrix::natural(vec![
+///     vec![1, 0, 5],
+///     vec![0, 1, 6],
+/// ]);
+///
+/// assert_eq!(matrix * DVector::new(vec![1, 2, 1]), DVector::new(vec![6, 8]));
+/// ```
+impl<T: Zero + Num> ops::Mul<DVector<T>> for DMatrix<T> {
+    type Output = DVector<T>;
+
+    fn mul(self, rhs: DVector<T>) -> Self::Output {
+        assert_eq!(self.columns, rhs.len(), "matrix and vector of incompatible dimensions");
+
+        let mut output = DVector::zeroed(self.rows);
+
+        for (column, value) in rhs.into_iter().enumerate() {
+            for row in 0..self.rows {
+                output[row] += self[column][row].clone() * value.clone();
+            }
+        }
+
+        output
+    }
+}
+
+/// Implementation for scalar product, as for [`Vector`](crate::vector::Vector).
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::DMatrix;
+///
+/// let matrix = DMatrix::natural(vec![
+///     vec![1, 2],
+///     vec![3, 4],
+/// ]);
+///
+/// assert_eq!(matrix * 2, DMatrix::natural(vec![
+///     vec![2, 4],
+///     vec![6, 8],
+/// ]));
+/// ```
+impl<T: Num + ops::MulAssign<U>, U: Num> ops::Mul<U> for DMatrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: U) -> Self::Output {
+        let mut matrix = self;
+
+        for value in &mut matrix.data {
+            *value *= rhs.clone();
+        }
+
+        matrix
+    }
+}
+
+/// Implementation for matrix negation, negating each value.
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::DMatrix;
+///
+/// let matrix = DMatrix::natural(vec![vec![1, -2]]);
+/// assert_eq!(-matrix, DMatrix::natural(vec![vec![-1, 2]]));
+/// ```
+impl<T: Signed> ops::Neg for DMatrix<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let Self { columns, rows, data } = self;
+        Self::new(columns, rows, data.into_iter().map(|value| -value).collect())
+    }
+}
+
+macro_rules! impl_component_wise {
+    ($trait:ident, $function:ident, $operator:tt, $description:literal) => {
+        #[doc = concat!("Implementation for matrices ", $description, ", value by value.")]
+        ///
+        /// Panics when the matrices are not of the same dimensions.
+        impl<T: Num> ops::$trait<Self> for DMatrix<T> {
+            type Output = Self;
+
+            fn $function(self, rhs: Self) -> Self::Output {
+                assert_eq!(
+                    (self.columns, self.rows), 
+                    (rhs.columns, rhs.rows), 
+                    "matrices of different dimensions"
+                );
+
+                let mut output = self;
+
+                for (value, other) in output.data.iter_mut().zip(rhs.data) {
+                    *value $operator other;
+                }
+
+                output
+            }
+        }
+    };
+}
+
+impl_component_wise!(Add, add, +=, "addition");
+impl_component_wise!(Sub, sub, -=, "subtraction");
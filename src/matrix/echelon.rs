@@ -0,0 +1,580 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Gaussian elimination on matrices, to reduce them, to compute their
+//! determinant, to solve linear systems and to find bases of their null and
+//! column spaces.
+//!
+//! The calculations only use additions, subtractions, multiplications and
+//! divisions, so they are exact for rational values (see the `num-rational`
+//! feature) and approximated for floating-point values. They are not meant for
+//! integer values, since the divisions would be truncated.
+//!
+//! With floating-point values, the rounding errors rarely leave exact zeros, 
+//! so the values whose magnitude is within the rounding error of the matrix 
+//! (see [`Field::epsilon`]) are considered zero when looking for the pivots, 
+//! a nearly singular matrix being reduced as a singular one.
+
+use std::ops::Div;
+
+use crate::{ Field, Num, One, Zero };
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// Swaps the rows `a` and `b` of the values, stored column by column with 
+/// `rows` values per column.
+fn swap_rows<T>(values: &mut [T], rows: usize, a: usize, b: usize) {
+    for column in 0..values.len() / rows {
+        values.swap(column * rows + a, column * rows + b);
+    }
+}
+
+/// Returns the absolute value of the value.
+fn magnitude<T: Zero + Num + PartialOrd>(value: T) -> T {
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+/// Returns the magnitude up to which a value of the values, stored column by 
+/// column with `rows` values per column, is considered zero.
+///
+/// ## Formula
+/// $$ \tau = \varepsilon \max(C, R) \max_{i,j} |a_{i,j}| $$
+///
+/// Where $\varepsilon$ is [`Field::epsilon`], so the tolerance is zero for the 
+/// exact values.
+pub(crate) fn tolerance<T: Field + PartialOrd>(values: &[T], rows: usize) -> T {
+    if rows == 0 {
+        return T::zero();
+    }
+
+    let largest = values.iter().fold(T::zero(), |largest, value| {
+        let value = magnitude(value.clone());
+        if value > largest { value } else { largest }
+    });
+
+    // The number of rows or columns as a value, added one by one.
+    let size = (0..rows.max(values.len() / rows)).fold(T::zero(), |size, _| size + T::one());
+
+    T::epsilon() * size * largest
+}
+
+/// Returns the row from `from` having the value of the column with the
+/// largest magnitude, or `None` when all these values are within the 
+/// `tolerance` of zero.
+///
+/// Choosing the largest pivot keeps the rounding errors of floating-point
+/// values low, the other ones being divided by it.
+fn find_pivot<T>(values: &[T], rows: usize, column: usize, from: usize, tolerance: &T) -> Option<usize>
+where
+    T: Zero + Num + PartialOrd
+{
+    let mut best: Option<(usize, T)> = None;
+
+    for row in from..rows {
+        let value = magnitude(values[column * rows + row].clone());
+
+        if value > *tolerance && best.as_ref().is_none_or(|(_, largest)| value > *largest) {
+            best = Some((row, value));
+        }
+    }
+
+    best.map(|(row, _)| row)
+}
+
+/// Reduces a column of the values by the pivot column `factors` during the 
+/// Gauss-Jordan elimination: its value of the pivot row is divided by the 
+/// pivot, then subtracted from the other rows as many times as their factor.
+pub(crate) fn reduce_column<T>(column: &mut [T], factors: &[T], pivot_row: usize) 
+where 
+    T: Zero + Num + Div<Output = T>
+{
+    let value = column[pivot_row].clone() / factors[pivot_row].clone();
+
+    if value != T::zero() {
+        for (row, factor) in factors.iter().enumerate() {
+            if row != pivot_row && *factor != T::zero() {
+                column[row] -= value.clone() * factor.clone();
+            }
+        }
+    }
+
+    column[pivot_row] = value;
+}
+
+/// Eliminates the values under the pivot row of a column of the values by the 
+/// pivot column `factors` during the Gaussian elimination.
+pub(crate) fn eliminate_column<T>(column: &mut [T], factors: &[T], pivot_row: usize) 
+where 
+    T: Zero + Num + Div<Output = T>
+{
+    let ratio = column[pivot_row].clone() / factors[pivot_row].clone();
+
+    if ratio == T::zero() {
+        return;
+    }
+
+    for row in pivot_row + 1..factors.len() {
+        column[row] -= ratio.clone() * factors[row].clone();
+    }
+}
+
+/// Reduces the values, stored column by column with `rows` values per column, 
+/// to their reduced row echelon form.
+/// 
+/// The values within the `tolerance` of zero are not pivots, and are replaced 
+/// by zero in the columns without pivot, to be read as such from the reduced 
+/// values.
+///
+/// For each pivot, `update` is given the values of the columns after the 
+/// pivot one, the pivot column and the pivot row, to update each of these 
+/// columns with [`reduce_column`], one after the other or in parallel.
+pub(crate) fn reduce_by<T>(
+    values: &mut [T], 
+    rows: usize, 
+    tolerance: &T, 
+    update: impl Fn(&mut [T], &[T], usize),
+) 
+where 
+    T: Zero + One + Num + PartialOrd
+{
+    if rows == 0 {
+        return;
+    }
+
+    let columns = values.len() / rows;
+    let mut pivot_row = 0;
+
+    for column in 0..columns {
+        if pivot_row == rows {
+            break;
+        }
+
+        // Column without any pivot candidate, nothing to reduce but the 
+        // rounding errors left under the previous pivots.
+        let Some(row) = find_pivot(values, rows, column, pivot_row, tolerance) else {
+            for value in &mut values[column * rows + pivot_row..(column + 1) * rows] {
+                *value = T::zero();
+            }
+
+            continue;
+        };
+
+        swap_rows(values, rows, row, pivot_row);
+
+        let (pivot_column, next_columns) = values.split_at_mut((column + 1) * rows);
+        let factors = &mut pivot_column[column * rows..];
+        update(next_columns, factors, pivot_row);
+
+        // The pivot column becomes the one of the identity.
+        for (row, value) in factors.iter_mut().enumerate() {
+            *value = if row == pivot_row { T::one() } else { T::zero() };
+        }
+
+        pivot_row += 1;
+    }
+}
+
+/// Reduces the values, stored column by column with `rows` values per column, 
+/// to their reduced row echelon form, one column after the other.
+pub(super) fn reduce<T>(values: &mut [T], rows: usize, tolerance: &T) 
+where 
+    T: Field + PartialOrd
+{
+    reduce_by(values, rows, tolerance, |columns, factors, pivot_row| {
+        for column in columns.chunks_mut(factors.len()) {
+            reduce_column(column, factors, pivot_row);
+        }
+    });
+}
+
+/// Returns the determinant of the `size` x `size` values stored column by 
+/// column, eliminating them in place.
+/// 
+/// For each pivot, `update` is given the values of the columns after the 
+/// pivot one, the pivot column and the pivot row, to update each of these 
+/// columns with [`eliminate_column`], one after the other or in parallel.
+pub(crate) fn eliminate_by<T>(values: &mut [T], size: usize, update: impl Fn(&mut [T], &[T], usize)) -> T 
+where 
+    T: Zero + One + Num + PartialOrd
+{
+    let mut negative = false;
+
+    for column in 0..size {
+        // A column without pivot means the matrix is singular.
+        let Some(row) = find_pivot(values, size, column, column, &T::zero()) else {
+            return T::zero();
+        };
+
+        if row != column {
+            swap_rows(values, size, row, column);
+            negative = !negative;
+        }
+
+        let (pivot_column, next_columns) = values.split_at_mut((column + 1) * size);
+        update(next_columns, &pivot_column[column * size..], column);
+    }
+
+    let mut determinant = T::one();
+    for n in 0..size {
+        determinant *= values[n * size + n].clone();
+    }
+
+    if negative {
+        T::zero() - determinant
+    } else {
+        determinant
+    }
+}
+
+/// Returns the determinant of the `size` x `size` values stored column by 
+/// column, eliminating them in place one column after the other.
+pub(super) fn eliminate<T>(values: &mut [T], size: usize) -> T 
+where 
+    T: Zero + One + Num + Div<Output = T> + PartialOrd
+{
+    eliminate_by(values, size, |columns, factors, pivot_row| {
+        for column in columns.chunks_mut(factors.len()) {
+            eliminate_column(column, factors, pivot_row);
+        }
+    })
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R>
+where
+    T: Field + PartialOrd
+{
+    /// Returns the reduced row echelon form of the matrix, obtained by
+    /// Gauss-Jordan elimination.
+    ///
+    /// Each leading value of a row is one and is the only non-zero value of
+    /// its column.
+    ///
+    /// ## Example
+    /// $$
+    /// \begin{pmatrix}
+    ///     1 & 2 & 3 \\\
+    ///     2 & 4 & 8 \\\
+    /// \end{pmatrix} \to
+    /// \begin{pmatrix}
+    ///     1 & 2 & 0 \\\
+    ///     0 & 0 & 1 \\\
+    /// \end{pmatrix}
+    /// $$
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::<f64, 3, 2>::natural([
+    ///     [1.0, 2.0, 3.0],
+    ///     [2.0, 4.0, 8.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.rref(), Matrix::natural([
+    ///     [1.0, 2.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    /// ]));
+    /// ```
+    pub fn rref(self) -> Self {
+        let mut matrix = self;
+        let tolerance = tolerance(matrix.values(), R);
+        reduce(matrix.values_mut(), R, &tolerance);
+        matrix
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Zero + One + Num + Div<Output = T> + PartialOrd
+{
+    /// Returns the determinant of the square matrix, computed by Gaussian
+    /// elimination.
+    ///
+    /// ## Formula
+    /// $$ \det(A) = (-1)^s \prod_{i=1}^{n} u_{i,i} $$
+    ///
+    /// Where $U$ is the row echelon form of $A$ obtained with $s$ row swaps,
+    /// the determinant of the empty 0x0 matrix being one.
+    ///
+    /// The divisions of the elimination are only exact for fields such as
+    /// the floating-point values. The matrices of signed integers have
+    /// [`Matrix::const_determinant`] instead, computed exactly and usable in
+    /// `const` items and blocks, the operators of `T` not being callable at
+    /// compile time here.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::{ Matrix, Matrix3 };
+    ///
+    /// let matrix = Matrix3::<f64>::natural([
+    ///     [0.0, 2.0, 1.0],
+    ///     [1.0, 1.0, 0.0],
+    ///     [2.0, 0.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.determinant(), -10.0);
+    /// assert_eq!(Matrix::<f64, 0, 0>::natural([]).determinant(), 1.0);
+    /// ```
+    pub fn determinant(self) -> T {
+        let mut matrix = self;
+        eliminate(matrix.values_mut(), N)
+    }
+}
+
+/// Set of the solutions of a linear system of `N` unknowns.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<T, const N: usize> {
+    /// The system has no solution, its equations contradicting each other.
+    None,
+    /// The system has exactly one solution.
+    Unique(Vector<T, N>),
+    /// The system has infinitely many solutions, being the `particular` one 
+    /// plus any combination of the `directions`, one per free variable.
+    ///
+    /// ## Formula
+    /// $$ x = p + \sum_{i} t_{i} d_{i}, \quad t_{i} \in \mathbb{R} $$
+    Infinite {
+        /// Solution whose free variables are all zero.
+        particular: Vector<T, N>,
+        /// Solutions of the homogeneous system, the one of the `i`-th free 
+        /// variable being one for this variable and zero for the other free 
+        /// ones.
+        directions: Vec<Vector<T, N>>,
+    },
+}
+
+/// Structure of the solutions of a linear system $Ax = b$ of `N` unknowns, 
+/// read from the reduced row echelon form of its augmented matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearSystem<T, const N: usize> {
+    /// Indices of the columns of $A$ having a pivot, being the unknowns fixed 
+    /// by the free ones, in increasing order.
+    pub pivot_columns: Vec<usize>,
+    /// Indices of the columns of $A$ without pivot, being the unknowns which 
+    /// can take any value, in increasing order.
+    pub free_variables: Vec<usize>,
+    /// Set of the solutions of the system.
+    pub solution: Solution<T, N>,
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> 
+where 
+    T: Field + PartialOrd
+{
+    /// Solves the linear system $Ax = b$ of `C` unknowns and `R` equations, 
+    /// returning which unknowns are pivots or free and the set of the 
+    /// solutions, by Gauss-Jordan elimination of the augmented matrix 
+    /// $(A \mid b)$.
+    ///
+    /// The system has no solution when a row of the reduced matrix is zero 
+    /// except for $b$, otherwise it has a unique solution when every unknown 
+    /// is a pivot, and infinitely many ones when an unknown is free.
+    ///
+    /// ## Example
+    /// $$
+    /// \begin{cases}
+    ///     x + 2y + z = 4 \\\
+    ///     2x + 4y + 3z = 9 \\\
+    /// \end{cases}
+    /// \iff
+    /// \begin{cases}
+    ///     x = 3 - 2y \\\
+    ///     z = 1 \\\
+    /// \end{cases}
+    /// $$
+    /// ```
+    /// use linbra::{
+    ///     matrix::{ Matrix, Solution },
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let a = Matrix::<f64, 3, 2>::natural([
+    ///     [1.0, 2.0, 1.0],
+    ///     [2.0, 4.0, 3.0],
+    /// ]);
+    ///
+    /// let system = a.solve_system([4.0, 9.0].into());
+    ///
+    /// assert_eq!(system.pivot_columns, vec![0, 2]);
+    /// assert_eq!(system.free_variables, vec![1]);
+    /// assert_eq!(system.solution, Solution::Infinite {
+    ///     particular: Vector3::new([3.0, 0.0, 1.0]),
+    ///     directions: vec![Vector3::new([-2.0, 1.0, 0.0])],
+    /// });
+    ///
+    /// let parallel = Matrix::<f64, 3, 2>::natural([
+    ///     [1.0, 2.0, 1.0],
+    ///     [2.0, 4.0, 2.0],
+    /// ]);
+    ///
+    /// assert_eq!(parallel.solve_system([4.0, 9.0].into()).solution, Solution::None);
+    ///
+    /// // Singular matrix whose elimination leaves rounding errors instead of
+    /// // a zero row.
+    /// let singular = Matrix::<f64, 3, 3>::natural([
+    ///     [0.1, 0.2, 0.3],
+    ///     [0.4, 0.5, 0.6],
+    ///     [0.7, 0.8, 0.9],
+    /// ]);
+    ///
+    /// let system = singular.solve_system([1.0, 2.0, 3.0].into());
+    ///
+    /// assert_eq!(system.pivot_columns, vec![0, 1]);
+    /// assert_eq!(system.free_variables, vec![2]);
+    /// assert!(matches!(system.solution, Solution::Infinite { .. }));
+    /// ```
+    pub fn solve_system(self, b: Vector<T, R>) -> LinearSystem<T, C> {
+        let mut values = self.augmented(b);
+        let tolerance = tolerance(&values, R);
+        reduce(&mut values, R, &tolerance);
+
+        Self::read_system(&values)
+    }
+
+    /// Returns the values of the augmented matrix $(A \mid b)$, stored 
+    /// column by column.
+    pub(crate) fn augmented(self, b: Vector<T, R>) -> Vec<T> {
+        self.into_iter().flatten().chain(b).collect()
+    }
+
+    /// Returns the structure of the solutions of the linear system, read from 
+    /// the reduced row echelon form of its augmented matrix.
+    pub(crate) fn read_system(values: &[T]) -> LinearSystem<T, C> {
+        let value = |column: usize, row: usize| values[column * R + row].clone();
+
+        // Column of the leading value of each non-zero row, `C` being the 
+        // one of `b`.
+        let leading: Vec<usize> = (0..R)
+            .filter_map(|row| (0..=C).find(|&column| value(column, row) != T::zero()))
+            .collect();
+
+        let pivot_columns: Vec<usize> = leading.iter().copied().filter(|&column| column < C).collect();
+        let free_variables: Vec<usize> = (0..C).filter(|column| !pivot_columns.contains(column)).collect();
+
+        if leading.contains(&C) {
+            return LinearSystem { pivot_columns, free_variables, solution: Solution::None };
+        }
+
+        // The pivot of the `n`-th row is in the `n`-th pivot column.
+        let mut particular = Vector::zeroed();
+        for (row, &column) in pivot_columns.iter().enumerate() {
+            particular[column] = value(C, row);
+        }
+
+        let solution = if free_variables.is_empty() {
+            Solution::Unique(particular)
+        } else {
+            let directions = free_variables.iter().map(|&free| {
+                let mut direction = Vector::zeroed();
+                direction[free] = T::one();
+
+                for (row, &column) in pivot_columns.iter().enumerate() {
+                    direction[column] = T::zero() - value(free, row);
+                }
+
+                direction
+            }).collect();
+
+            Solution::Infinite { particular, directions }
+        };
+
+        LinearSystem { pivot_columns, free_variables, solution }
+    }
+
+    /// Returns a basis of the null space of the matrix, being the vectors 
+    /// $x$ such that $Ax = 0$, with one vector per free variable and none 
+    /// when the matrix has full column rank.
+    ///
+    /// The vectors are the directions of the solutions of the homogeneous 
+    /// system, see [`Matrix::solve_system`], so a nearly singular matrix of 
+    /// floating-point values has the null space of the singular one.
+    ///
+    /// ## Formula
+    /// $$ \operatorname{N}(A) = \left\{ x \mid Ax = 0 \right\}, \quad \dim \operatorname{N}(A) = C - \operatorname{rank}(A) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let a = Matrix::<f64, 3, 2>::natural([
+    ///     [1.0, 2.0, 1.0],
+    ///     [2.0, 4.0, 3.0],
+    /// ]);
+    ///
+    /// assert_eq!(a.null_space(), vec![Vector3::new([-2.0, 1.0, 0.0])]);
+    ///
+    /// // Matrix of rank 2, with rounding errors instead of a zero row once 
+    /// // eliminated.
+    /// let singular = Matrix::<f64, 3, 3>::natural([
+    ///     [0.1, 0.2, 0.3],
+    ///     [0.4, 0.5, 0.6],
+    ///     [0.7, 0.8, 0.9],
+    /// ]);
+    ///
+    /// let null_space = singular.null_space();
+    ///
+    /// assert_eq!(null_space.len(), 1);
+    /// assert!((singular * null_space[0]).length() < 1e-12);
+    /// ```
+    pub fn null_space(self) -> Vec<Vector<T, C>> {
+        match self.solve_system(Vector::zeroed()).solution {
+            Solution::Infinite { directions, .. } => directions,
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns a basis of the column space of the matrix, being the vectors 
+    /// $Ax$ for any $x$, as the columns of the matrix having a pivot in its 
+    /// reduced row echelon form.
+    ///
+    /// The values of a column within the rounding error of the matrix are not 
+    /// pivots, so a nearly singular matrix of floating-point values has the 
+    /// column space of the singular one.
+    ///
+    /// ## Formula
+    /// $$ \operatorname{C}(A) = \left\{ Ax \mid x \in \mathbb{R}^C \right\}, \quad \dim \operatorname{C}(A) = \operatorname{rank}(A) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::{ Vector2, Vector3 },
+    /// };
+    ///
+    /// let a = Matrix::<f64, 3, 2>::natural([
+    ///     [1.0, 2.0, 1.0],
+    ///     [2.0, 4.0, 3.0],
+    /// ]);
+    ///
+    /// assert_eq!(a.column_space(), vec![Vector2::new([1.0, 2.0]), Vector2::new([1.0, 3.0])]);
+    ///
+    /// // Matrix of rank 2, with rounding errors instead of a zero row once 
+    /// // eliminated.
+    /// let singular = Matrix::<f64, 3, 3>::natural([
+    ///     [0.1, 0.2, 0.3],
+    ///     [0.4, 0.5, 0.6],
+    ///     [0.7, 0.8, 0.9],
+    /// ]);
+    ///
+    /// assert_eq!(singular.column_space(), vec![
+    ///     Vector3::new([0.1, 0.4, 0.7]), 
+    ///     Vector3::new([0.2, 0.5, 0.8]),
+    /// ]);
+    /// ```
+    pub fn column_space(self) -> Vec<Vector<T, R>> {
+        let mut reduced = self.clone();
+        let tolerance = tolerance(reduced.values(), R);
+        reduce(reduced.values_mut(), R, &tolerance);
+
+        // The leading value of each non-zero row is in a pivot column.
+        let pivot_columns = (0..R).filter_map(|row| (0..C).find(|&column| reduced[column][row] != T::zero()));
+
+        pivot_columns.map(|column| Vector::new(self[column].clone())).collect()
+    }
+}
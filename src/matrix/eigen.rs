@@ -0,0 +1,107 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Eigen decomposition of the symmetric matrices, such as covariance matrices,
+//! with the Jacobi eigenvalue algorithm.
+
+use crate::Float;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// Maximum number of sweeps over the values off the diagonal, the algorithm
+/// converging quadratically in a few of them.
+const MAX_SWEEPS: usize = 64;
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Returns the eigenvalues of the symmetric matrix from the greatest to
+    /// the least, and the matrix whose columns are their normalized
+    /// eigenvectors, in the same order.
+    ///
+    /// The matrix is diagonalized by successive plane rotations cancelling
+    /// each of its values off the diagonal, the eigenvectors being the
+    /// product of these rotations. Only the symmetric matrices are
+    /// diagonalized this way.
+    ///
+    /// ## Formula
+    /// $$ A = V \Lambda V^T $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let matrix = Matrix2::natural([
+    ///     [2.0, 1.0],
+    ///     [1.0, 2.0],
+    /// ]);
+    ///
+    /// let (values, vectors) = matrix.symmetric_eigen();
+    /// assert!(values.distance(Vector2::new([3.0, 1.0])) < 1e-12);
+    ///
+    /// let greatest = Vector2::new(vectors[0]);
+    /// assert!((matrix * greatest).distance(greatest * 3.0) < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(self) -> (Vector<T, N>, Self) {
+        let (zero, one) = (T::zero(), T::one());
+        let two = one + one;
+
+        let mut a = self.to_cols_array_2d();
+        let mut v: [[T; N]; N] = std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { one } else { zero })
+        });
+
+        for _ in 0..MAX_SWEEPS {
+            let mut converged = true;
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[p][q] == zero {
+                        continue;
+                    }
+                    converged = false;
+
+                    let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                    let root = (theta * theta + one).sqrt();
+                    let t = if theta < zero { -one / (root - theta) } else { one / (root + theta) };
+                    let c = one / (t * t + one).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut() {
+                        let (akp, akq) = (row[p], row[q]);
+                        row[p] = c * akp - s * akq;
+                        row[q] = s * akp + c * akq;
+                    }
+
+                    let (ap, aq) = (a[p], a[q]);
+                    a[p] = std::array::from_fn(|k| c * ap[k] - s * aq[k]);
+                    a[q] = std::array::from_fn(|k| s * ap[k] + c * aq[k]);
+
+                    for row in v.iter_mut() {
+                        let (vkp, vkq) = (row[p], row[q]);
+                        row[p] = c * vkp - s * vkq;
+                        row[q] = s * vkp + c * vkq;
+                    }
+
+                    // Exactly cancelled, against the rounding errors.
+                    a[p][q] = zero;
+                    a[q][p] = zero;
+                }
+            }
+
+            if converged {
+                break;
+            }
+        }
+
+        let mut order: [usize; N] = std::array::from_fn(|n| n);
+        order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(std::cmp::Ordering::Equal));
+
+        (
+            Vector::new(order.map(|n| a[n][n])),
+            Matrix::new(order.map(|n| std::array::from_fn(|row| v[row][n]))),
+        )
+    }
+}
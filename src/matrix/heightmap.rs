@@ -0,0 +1,100 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Slopes and normals of heightmaps, being matrices of heights whose columns
+//! go along the x axis and rows along the z axis, the y axis going up.
+
+use crate::matrix::Matrix;
+use crate::vector::{ Vector2, Vector3 };
+
+/// Returns the difference of the heights around the index along an axis of
+/// the given length, divided by their distance: centred inside and one-sided
+/// on the edges.
+fn difference(index: usize, length: usize, cell_size: f32, height: impl Fn(usize) -> f32) -> f32 {
+    let (before, after) = (index.saturating_sub(1), (index + 1).min(length - 1));
+
+    if before == after {
+        return 0.0;
+    }
+
+    (height(after) - height(before)) / ((after - before) as f32 * cell_size)
+}
+
+impl<const C: usize, const R: usize> Matrix<f32, C, R> {
+    /// Returns the gradient of the heightmap at each of its cells, being the
+    /// slope of the heights along the x and the z axes, with cells of
+    /// `cell_size` wide.
+    ///
+    /// The slopes are computed by central differences, and by forward or
+    /// backward differences on the edges.
+    ///
+    /// ## Formula
+    /// $$ \nabla h_{x,z} = \begin{pmatrix} \frac{h_{x + 1, z} - h_{x - 1, z}}{2 s} \\\ \frac{h_{x, z + 1} - h_{x, z - 1}}{2 s} \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// // Rising by 1 along x and by 2 along z, for each cell.
+    /// let heights = Matrix::<f32, 3, 2>::natural([
+    ///     [0.0, 1.0, 2.0],
+    ///     [2.0, 3.0, 4.0],
+    /// ]);
+    ///
+    /// let gradients = heights.gradients(0.5);
+    ///
+    /// assert_eq!(gradients[1][0], Vector2::new([2.0, 4.0]));
+    /// assert_eq!(gradients[0][1], Vector2::new([2.0, 4.0]));
+    /// ```
+    pub fn gradients(&self, cell_size: f32) -> Matrix<Vector2<f32>, C, R> {
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| {
+                Vector2::new([
+                    difference(column, C, cell_size, |column| self[column][row]),
+                    difference(row, R, cell_size, |row| self[column][row]),
+                ])
+            })
+        }))
+    }
+
+    /// Returns the normalized normal of the surface of the heightmap at each
+    /// of its cells, with cells of `cell_size` wide, such as to light a
+    /// terrain or to slide down its steep slopes.
+    ///
+    /// ## Formula
+    /// $$ n_{x,z} = \frac{1}{\sqrt{(\nabla h_{x})^2 + 1 + (\nabla h_{z})^2}} \begin{pmatrix} -\nabla h_{x} \\\ 1 \\\ -\nabla h_{z} \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let heights = Matrix::<f32, 2, 2>::natural([
+    ///     [0.0, 1.0],
+    ///     [0.0, 1.0],
+    /// ]);
+    ///
+    /// let normal = heights.normals(1.0)[0][0];
+    /// let expected = Vector3::new([-1.0, 1.0, 0.0]).normalize().unwrap();
+    ///
+    /// assert!(normal.distance(expected) < 1e-6);
+    /// ```
+    pub fn normals(&self, cell_size: f32) -> Matrix<Vector3<f32>, C, R> {
+        let gradients = self.gradients(cell_size);
+
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| {
+                let gradient = gradients[column][row];
+                let normal = Vector3::new([-gradient[0], 1.0, -gradient[1]]);
+
+                normal * (1.0 / normal.length())
+            })
+        }))
+    }
+}
@@ -0,0 +1,166 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Inertia tensors of common solid shapes of uniform density, for rigid-body
+//! physics.
+//!
+//! The tensors are expressed around the centre of mass of the shapes, whose
+//! axes are the ones of the coordinate system. The round shapes are oriented
+//! along the y-axis.
+
+use crate::matrix::Matrix3;
+use crate::vector::Vector3;
+
+/// Returns the diagonal matrix of the principal moments of inertia.
+fn principal(x: f32, y: f32, z: f32) -> Matrix3<f32> {
+    Matrix3::new([
+        [x, 0.0, 0.0],
+        [0.0, y, 0.0],
+        [0.0, 0.0, z],
+    ])
+}
+
+impl Matrix3<f32> {
+    /// Returns the inertia tensor of a solid box of `mass` with the given
+    /// width, height and depth.
+    ///
+    /// ## Formula
+    /// $$ I = \frac{m}{12} \begin{pmatrix} h^2 + d^2 & 0 & 0 \\\ 0 & w^2 + d^2 & 0 \\\ 0 & 0 & w^2 + h^2 \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix3,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let inertia = Matrix3::inertia_box(12.0, Vector3::new([1.0, 2.0, 3.0]));
+    ///
+    /// assert_eq!(inertia, Matrix3::natural([
+    ///     [13.0, 0.0, 0.0],
+    ///     [0.0, 10.0, 0.0],
+    ///     [0.0, 0.0, 5.0],
+    /// ]));
+    /// ```
+    pub fn inertia_box(mass: f32, size: Vector3<f32>) -> Self {
+        let [w2, h2, d2] = [size[0] * size[0], size[1] * size[1], size[2] * size[2]];
+        let k = mass / 12.0;
+
+        principal(k * (h2 + d2), k * (w2 + d2), k * (w2 + h2))
+    }
+
+    /// Returns the inertia tensor of a solid sphere of `mass` and `radius`.
+    ///
+    /// ## Formula
+    /// $$ I = \frac{2}{5} m r^2 \mathbb{I}_3 $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    ///
+    /// assert_eq!(Matrix3::inertia_sphere(5.0, 1.0), Matrix3::natural([
+    ///     [2.0, 0.0, 0.0],
+    ///     [0.0, 2.0, 0.0],
+    ///     [0.0, 0.0, 2.0],
+    /// ]));
+    /// ```
+    pub fn inertia_sphere(mass: f32, radius: f32) -> Self {
+        let moment = 0.4 * mass * radius * radius;
+        principal(moment, moment, moment)
+    }
+
+    /// Returns the inertia tensor of a solid cylinder of `mass`, `radius` and
+    /// `height`, along the y-axis.
+    ///
+    /// ## Formula
+    /// $$ I = \begin{pmatrix} \frac{m}{12} (3 r^2 + h^2) & 0 & 0 \\\ 0 & \frac{m}{2} r^2 & 0 \\\ 0 & 0 & \frac{m}{12} (3 r^2 + h^2) \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    ///
+    /// assert_eq!(Matrix3::inertia_cylinder(12.0, 1.0, 2.0), Matrix3::natural([
+    ///     [7.0, 0.0, 0.0],
+    ///     [0.0, 6.0, 0.0],
+    ///     [0.0, 0.0, 7.0],
+    /// ]));
+    /// ```
+    pub fn inertia_cylinder(mass: f32, radius: f32, height: f32) -> Self {
+        let r2 = radius * radius;
+        let side = mass * (3.0 * r2 + height * height) / 12.0;
+
+        principal(side, 0.5 * mass * r2, side)
+    }
+
+    /// Returns the inertia tensor of a solid capsule of `mass` and `radius`,
+    /// along the y-axis, whose cylinder between the two hemispheres has the
+    /// given `height`.
+    ///
+    /// The mass is shared between the cylinder and the hemispheres according
+    /// to their volumes, each hemisphere being moved away from the centre with
+    /// the parallel-axis theorem.
+    ///
+    /// ## Formula
+    /// With $m_c = m \frac{h}{h + \frac{4}{3} r}$ the mass of the cylinder,
+    /// and $m_s = m - m_c$ the mass of both hemispheres:
+    ///
+    /// $$ I_{yy} = \frac{m_c}{2} r^2 + \frac{2}{5} m_s r^2 \\\
+    /// I_{xx} = I_{zz} = m_c \left(\frac{h^2}{12} + \frac{r^2}{4}\right) + m_s \left(\frac{2}{5} r^2 + \frac{h^2}{4} + \frac{3}{8} h r\right) $$
+    ///
+    /// ## Example
+    /// A capsule without cylinder is a sphere:
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    ///
+    /// assert_eq!(Matrix3::inertia_capsule(5.0, 1.0, 0.0), Matrix3::inertia_sphere(5.0, 1.0));
+    /// ```
+    pub fn inertia_capsule(mass: f32, radius: f32, height: f32) -> Self {
+        let r2 = radius * radius;
+        let cylinder = mass * height / (height + 4.0 / 3.0 * radius);
+        let hemispheres = mass - cylinder;
+
+        let axis = 0.5 * cylinder * r2 + 0.4 * hemispheres * r2;
+        let side = cylinder * (height * height / 12.0 + r2 / 4.0)
+            + hemispheres * (0.4 * r2 + height * height / 4.0 + 0.375 * height * radius);
+
+        principal(side, axis, side)
+    }
+
+    /// Returns the inertia tensor around a point at `offset` from the centre
+    /// of mass of a body of `mass`, this matrix being its inertia tensor
+    /// around its centre of mass.
+    ///
+    /// ## Formula
+    /// $$ I' = I + m \left( (d \cdot d) \mathbb{I}_3 - d d^T \right) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix3,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let inertia = Matrix3::inertia_sphere(5.0, 1.0)
+    ///     .parallel_axis(5.0, Vector3::new([0.0, 0.0, 2.0]));
+    ///
+    /// assert_eq!(inertia, Matrix3::natural([
+    ///     [22.0, 0.0, 0.0],
+    ///     [0.0, 22.0, 0.0],
+    ///     [0.0, 0.0, 2.0],
+    /// ]));
+    /// ```
+    pub fn parallel_axis(self, mass: f32, offset: Vector3<f32>) -> Self {
+        let squared_length = offset.dot(offset);
+        let mut output = self;
+
+        for column in 0..3 {
+            for row in 0..3 {
+                let identity = if column == row { squared_length } else { 0.0 };
+                output[column][row] += mass * (identity - offset[column] * offset[row]);
+            }
+        }
+
+        output
+    }
+}
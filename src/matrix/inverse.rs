@@ -0,0 +1,108 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Inverse of the 4x4 matrices, written out value by value since they are the
+//! most used ones for transformations.
+
+use crate::{ Error, Field };
+use crate::matrix::Matrix4;
+
+impl<T: Field + Copy> Matrix4<T> {
+    /// Returns the inverse of the matrix, or [`Error::SingularMatrix`] when
+    /// the matrix is not invertible.
+    ///
+    /// The values are floating-point or rational ones, see [`Field`], since
+    /// the divisions of integers would be truncated.
+    ///
+    /// It is computed with the cofactors of the matrix, expressed from the
+    /// determinants of its 2x2 sub-matrices.
+    ///
+    /// ## Formula
+    /// $$ A^{-1} = \frac{1}{\det(A)} \operatorname{adj}(A) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix4,
+    ///     Error,
+    /// };
+    ///
+    /// let scale = Matrix4::<f32>::natural([
+    ///     [2.0, 0.0, 0.0, 4.0],
+    ///     [0.0, 4.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 0.5, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// assert_eq!(scale.inverse(), Ok(Matrix4::natural([
+    ///     [0.5, 0.0, 0.0, -2.0],
+    ///     [0.0, 0.25, 0.0, 0.0],
+    ///     [0.0, 0.0, 2.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ])));
+    ///
+    /// assert_eq!(Matrix4::<f32>::natural([[1.0; 4]; 4]).inverse(), Err(Error::SingularMatrix));
+    /// ```
+    ///
+    /// The following fails to compile since the values are integers:
+    /// ```compile_fail
+    /// use linbra::matrix::Matrix4;
+    ///
+    /// Matrix4::<i32>::splat(1).inverse();
+    /// ```
+    pub fn inverse(self) -> Result<Self, Error> {
+        let [
+            [a00, a01, a02, a03],
+            [a10, a11, a12, a13],
+            [a20, a21, a22, a23],
+            [a30, a31, a32, a33],
+        ] = self.to_cols_array_2d();
+
+        let b00 = a00 * a11 - a01 * a10;
+        let b01 = a00 * a12 - a02 * a10;
+        let b02 = a00 * a13 - a03 * a10;
+        let b03 = a01 * a12 - a02 * a11;
+        let b04 = a01 * a13 - a03 * a11;
+        let b05 = a02 * a13 - a03 * a12;
+        let b06 = a20 * a31 - a21 * a30;
+        let b07 = a20 * a32 - a22 * a30;
+        let b08 = a20 * a33 - a23 * a30;
+        let b09 = a21 * a32 - a22 * a31;
+        let b10 = a21 * a33 - a23 * a31;
+        let b11 = a22 * a33 - a23 * a32;
+
+        let determinant = b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06;
+
+        if determinant == T::zero() {
+            return Err(Error::SingularMatrix);
+        }
+
+        Ok(Self::new([
+            [
+                (a11 * b11 - a12 * b10 + a13 * b09) / determinant,
+                (a02 * b10 - a01 * b11 - a03 * b09) / determinant,
+                (a31 * b05 - a32 * b04 + a33 * b03) / determinant,
+                (a22 * b04 - a21 * b05 - a23 * b03) / determinant,
+            ],
+            [
+                (a12 * b08 - a10 * b11 - a13 * b07) / determinant,
+                (a00 * b11 - a02 * b08 + a03 * b07) / determinant,
+                (a32 * b02 - a30 * b05 - a33 * b01) / determinant,
+                (a20 * b05 - a22 * b02 + a23 * b01) / determinant,
+            ],
+            [
+                (a10 * b10 - a11 * b08 + a13 * b06) / determinant,
+                (a01 * b08 - a00 * b10 - a03 * b06) / determinant,
+                (a30 * b04 - a31 * b02 + a33 * b00) / determinant,
+                (a21 * b02 - a20 * b04 - a23 * b00) / determinant,
+            ],
+            [
+                (a11 * b07 - a10 * b09 - a12 * b06) / determinant,
+                (a00 * b09 - a01 * b07 + a02 * b06) / determinant,
+                (a31 * b01 - a30 * b03 - a32 * b00) / determinant,
+                (a20 * b03 - a21 * b01 + a22 * b00) / determinant,
+            ],
+        ]))
+    }
+}
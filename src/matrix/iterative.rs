@@ -0,0 +1,171 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Iterative solvers of linear systems $Ax = b$, refining an approximation of
+//! the solution step by step, for the large diagonally dominant systems of
+//! grid-based simulations such as the diffusion of heat or the pressure of
+//! fluids.
+//!
+//! Both solvers converge when the matrix is strictly diagonally dominant, each
+//! value of its diagonal being greater in absolute value than the sum of the
+//! other ones of its row. Gauss-Seidel usually needs about half as many steps
+//! as Jacobi.
+
+use crate::Float;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// When to stop an iterative solver.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StoppingCriteria<T> {
+    /// Length of the change of the solution during a step under which the
+    /// solution has converged.
+    pub tolerance: T,
+    /// Number of steps after which the solver gives up.
+    pub max_iterations: usize,
+}
+
+impl<T> StoppingCriteria<T> {
+    /// Creates new stopping criteria.
+    pub fn new(tolerance: T, max_iterations: usize) -> Self {
+        Self { tolerance, max_iterations }
+    }
+}
+
+/// Approximated solution given by an iterative solver.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IterativeSolution<T, const N: usize> {
+    /// Last approximation of the solution.
+    pub solution: Vector<T, N>,
+    /// Number of steps done.
+    pub iterations: usize,
+    /// Whether the tolerance was reached before the maximum number of steps.
+    pub converged: bool,
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Runs the steps from the initial approximation until the change of the
+    /// approximation gets under the tolerance.
+    fn iterate(
+        initial: Vector<T, N>,
+        criteria: StoppingCriteria<T>,
+        mut step: impl FnMut(&mut Vector<T, N>),
+    ) -> IterativeSolution<T, N> {
+        let mut solution = initial;
+        let squared_tolerance = criteria.tolerance * criteria.tolerance;
+
+        for iteration in 1..=criteria.max_iterations {
+            let previous = solution;
+            step(&mut solution);
+
+            if (solution - previous).length_squared() <= squared_tolerance {
+                return IterativeSolution { solution, iterations: iteration, converged: true };
+            }
+        }
+
+        IterativeSolution { solution, iterations: criteria.max_iterations, converged: false }
+    }
+
+    /// Returns the sum of the products of the values of the row by the ones
+    /// of the approximation, except on the diagonal.
+    fn off_diagonal(&self, row: usize, x: &Vector<T, N>) -> T {
+        let mut sum = T::zero();
+
+        for column in (0..N).filter(|&column| column != row) {
+            sum += self[column][row] * x[column];
+        }
+
+        sum
+    }
+
+    /// Solves the linear system with the Jacobi method, from the `initial`
+    /// approximation, such as the solution of the previous frame of a
+    /// simulation.
+    ///
+    /// Each step computes every unknown from the approximation of the
+    /// previous step only, so that the unknowns could be computed in
+    /// parallel. The values of the diagonal must not be zero.
+    ///
+    /// ## Formula
+    /// $$ x^{(k + 1)}_{i} = \frac{1}{a_{i,i}} \left( b_{i} - \sum_{j \neq i} a_{i,j} x^{(k)}_{j} \right) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::{ Matrix3, StoppingCriteria },
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let a = Matrix3::natural([
+    ///     [4.0, -1.0, 0.0],
+    ///     [-1.0, 4.0, -1.0],
+    ///     [0.0, -1.0, 4.0],
+    /// ]);
+    /// let b = Vector3::new([2.0, 4.0, 10.0]);
+    ///
+    /// let result = a.solve_jacobi(b, Vector3::zeroed(), StoppingCriteria::new(1e-10, 100));
+    ///
+    /// assert!(result.converged);
+    /// assert!(result.solution.distance(Vector3::new([1.0, 2.0, 3.0])) < 1e-9);
+    /// ```
+    pub fn solve_jacobi(
+        &self,
+        b: Vector<T, N>,
+        initial: Vector<T, N>,
+        criteria: StoppingCriteria<T>,
+    ) -> IterativeSolution<T, N> {
+        Self::iterate(initial, criteria, |x| {
+            let previous = *x;
+
+            for row in 0..N {
+                x[row] = (b[row] - self.off_diagonal(row, &previous)) / self[row][row];
+            }
+        })
+    }
+
+    /// Solves the linear system with the Gauss-Seidel method, from the
+    /// `initial` approximation, such as the solution of the previous frame of
+    /// a simulation.
+    ///
+    /// Each step computes the unknowns in order, using the ones already
+    /// updated during the step, which usually converges faster than
+    /// [`Matrix::solve_jacobi`]. The values of the diagonal must not be zero.
+    ///
+    /// ## Formula
+    /// $$ x^{(k + 1)}_{i} = \frac{1}{a_{i,i}} \left( b_{i} - \sum_{j < i} a_{i,j} x^{(k + 1)}_{j} - \sum_{j > i} a_{i,j} x^{(k)}_{j} \right) $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::{ Matrix3, StoppingCriteria },
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let a = Matrix3::natural([
+    ///     [4.0, -1.0, 0.0],
+    ///     [-1.0, 4.0, -1.0],
+    ///     [0.0, -1.0, 4.0],
+    /// ]);
+    /// let b = Vector3::new([2.0, 4.0, 10.0]);
+    /// let criteria = StoppingCriteria::new(1e-10, 100);
+    ///
+    /// let result = a.solve_gauss_seidel(b, Vector3::zeroed(), criteria);
+    ///
+    /// assert!(result.converged);
+    /// assert!(result.solution.distance(Vector3::new([1.0, 2.0, 3.0])) < 1e-9);
+    /// assert!(result.iterations < a.solve_jacobi(b, Vector3::zeroed(), criteria).iterations);
+    /// ```
+    pub fn solve_gauss_seidel(
+        &self,
+        b: Vector<T, N>,
+        initial: Vector<T, N>,
+        criteria: StoppingCriteria<T>,
+    ) -> IterativeSolution<T, N> {
+        Self::iterate(initial, criteria, |x| {
+            for row in 0..N {
+                x[row] = (b[row] - self.off_diagonal(row, x)) / self[row][row];
+            }
+        })
+    }
+}
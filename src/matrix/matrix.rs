@@ -6,7 +6,8 @@
 
 use std::ops;
 
-use crate::Zero;
+use crate::{ Error, One, Zero };
+use crate::vector::Vector;
 
 /// Linear algebra mathematical tool used for transformations for example.
 /// 
@@ -21,7 +22,12 @@ use crate::Zero;
 ///     x_{R,1} & x_{R,2} & \dots & x_{R,C} \\\ 
 /// \end{pmatrix}
 /// $$
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// 
+/// The matrix has the same memory layout as an array `[[T; R]; C]`, meaning 
+/// it is stored column by column.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[repr(transparent)]
 pub struct Matrix<T, const C: usize, const R: usize> {
     data: [[T; R]; C]
 }
@@ -51,17 +57,205 @@ impl<T: Zero, const C: usize, const R: usize> Matrix<T, C, R> {
     /// ]);
     /// ```
     pub fn natural(data: [[T; C]; R]) -> Self {
-        let mut reversed: [[T; R]; C] = [[T::zero(); R]; C];
+        let mut reversed: [[T; R]; C] = std::array::from_fn(|_| std::array::from_fn(|_| T::zero()));
 
-        for column in 0..C {
-            for row in 0..R {
-                reversed[column][row] = data[row][column];
+        for (row, values) in data.into_iter().enumerate() {
+            for (column, value) in values.into_iter().enumerate() {
+                reversed[column][row] = value;
             }
         }
 
         Self { data: reversed }
     }
+}
+
+impl<T: Zero + One, const N: usize> Matrix<T, N, N> {
+    /// Creates the identity matrix, with ones on the diagonal and zeros 
+    /// elsewhere.
+    /// 
+    /// The matrices of primitive values also have the [`Matrix::IDENTITY`] 
+    /// constant, usable in `const` items and blocks. Both cannot be the same 
+    /// item since the functions of [`Zero`] and [`One`] are not callable at 
+    /// compile time.
+    /// 
+    /// ## Formula
+    /// $$
+    /// I_{i,j} = \begin{cases} 1 & \text{if } i = j \\\ 0 & \text{otherwise} \end{cases}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    /// 
+    /// assert_eq!(Matrix3::<i32>::identity(), Matrix3::natural([
+    ///     [1, 0, 0],
+    ///     [0, 1, 0],
+    ///     [0, 0, 1],
+    /// ]));
+    /// ```
+    pub fn identity() -> Self {
+        Self {
+            data: std::array::from_fn(|column| {
+                std::array::from_fn(|row| if column == row { T::one() } else { T::zero() })
+            })
+        }
+    }
+}
+
+macro_rules! impl_const_square {
+    ($type:tt, $zero:literal, $one:literal) => {
+        /// Implements the square matrices of
+        #[doc = concat!("`", stringify!($type), "` values known at compile time.")]
+        impl<const N: usize> Matrix<$type, N, N> {
+            /// Identity matrix, as [`Matrix::identity`] but usable in `const` 
+            /// items and blocks.
+            /// 
+            /// It is a constant of each primitive type rather than the generic 
+            /// function, whose trait functions are not callable at compile 
+            /// time.
+            /// 
+            /// ## Example
+            /// ```
+            /// use linbra::matrix::Matrix2;
+            /// 
+            #[doc = concat!("const IDENTITY: Matrix2<", stringify!($type), "> = Matrix2::<", stringify!($type), ">::IDENTITY;")]
+            /// 
+            /// assert_eq!(IDENTITY, Matrix2::identity());
+            /// ```
+            pub const IDENTITY: Self = {
+                let mut data = [[$zero; N]; N];
+                let mut n = 0;
+
+                while n < N {
+                    data[n][n] = $one;
+                    n += 1;
+                }
+
+                Self { data }
+            };
+        }
+    };
+}
+
+impl_const_square!(i8, 0, 1);
+impl_const_square!(i16, 0, 1);
+impl_const_square!(i32, 0, 1);
+impl_const_square!(i64, 0, 1);
+impl_const_square!(i128, 0, 1);
+impl_const_square!(isize, 0, 1);
+impl_const_square!(u8, 0, 1);
+impl_const_square!(u16, 0, 1);
+impl_const_square!(u32, 0, 1);
+impl_const_square!(u64, 0, 1);
+impl_const_square!(u128, 0, 1);
+impl_const_square!(usize, 0, 1);
+impl_const_square!(f32, 0.0, 1.0);
+impl_const_square!(f64, 0.0, 1.0);
+
+macro_rules! impl_const_determinant {
+    ($type:tt) => {
+        /// Implements the determinant of the square matrices of
+        #[doc = concat!("`", stringify!($type), "` values at compile time.")]
+        impl<const N: usize> Matrix<$type, N, N> {
+            /// Returns the determinant of the square matrix, as 
+            /// [`Matrix::determinant`] but usable in `const` items and blocks,
+            /// such as to check that a hard-coded transform is not 
+            /// degenerated.
+            /// 
+            /// It is computed exactly by the fraction-free elimination of 
+            /// Bareiss, every division being without remainder. An overflow 
+            /// fails the compilation in a `const` context and panics in debug 
+            /// builds otherwise.
+            /// 
+            /// It is not the generic [`Matrix::determinant`], whose trait 
+            /// operators are not callable at compile time and whose Gaussian 
+            /// elimination truncates the divisions of integers. It is only 
+            /// implemented for the signed integers, the eliminations 
+            /// subtracting values.
+            /// 
+            /// ## Formula
+            /// With $a^{(0)}_{0,0} = 1$, for each step $k$ and $i, j > k$:
+            /// $$ a^{(k + 1)}_{i,j} = \frac{a^{(k)}_{k,k} a^{(k)}_{i,j} - a^{(k)}_{i,k} a^{(k)}_{k,j}}{a^{(k - 1)}_{k - 1,k - 1}}, \quad \det(A) = \pm a^{(n - 1)}_{n,n} $$
+            /// 
+            /// ## Example
+            /// ```
+            /// use linbra::matrix::Matrix3;
+            /// 
+            #[doc = concat!("const SHEAR: Matrix3<", stringify!($type), "> = Matrix3::new([")]
+            ///     [1, 0, 0],
+            ///     [2, 1, 0],
+            ///     [0, 0, 1],
+            /// ]);
+            /// const { assert!(SHEAR.const_determinant() != 0) };
+            /// 
+            #[doc = concat!("let matrix = Matrix3::<", stringify!($type), ">::natural([")]
+            ///     [0, 2, 1],
+            ///     [1, 1, 0],
+            ///     [2, 0, 4],
+            /// ]);
+            /// assert_eq!(matrix.const_determinant(), -10);
+            /// ```
+            pub const fn const_determinant(&self) -> $type {
+                if N == 0 {
+                    return 1;
+                }
+
+                // The columns are eliminated as rows, the determinant of the 
+                // transpose being the same.
+                let mut a = self.data;
+                let mut sign = 1;
+                let mut previous = 1;
+                let mut k = 0;
+
+                while k + 1 < N {
+                    if a[k][k] == 0 {
+                        let mut swap = k + 1;
+
+                        while swap < N && a[swap][k] == 0 {
+                            swap += 1;
+                        }
 
+                        if swap == N {
+                            return 0;
+                        }
+
+                        let row = a[swap];
+                        a[swap] = a[k];
+                        a[k] = row;
+                        sign = -sign;
+                    }
+
+                    let mut i = k + 1;
+
+                    while i < N {
+                        let mut j = k + 1;
+
+                        while j < N {
+                            a[i][j] = (a[k][k] * a[i][j] - a[i][k] * a[k][j]) / previous;
+                            j += 1;
+                        }
+
+                        i += 1;
+                    }
+
+                    previous = a[k][k];
+                    k += 1;
+                }
+
+                sign * a[N - 1][N - 1]
+            }
+        }
+    };
+}
+
+impl_const_determinant!(i8);
+impl_const_determinant!(i16);
+impl_const_determinant!(i32);
+impl_const_determinant!(i64);
+impl_const_determinant!(i128);
+impl_const_determinant!(isize);
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
     /// Creates a new matrix. 
     /// 
     /// It is programmatically in the right order but visually in the wrong 
@@ -86,11 +280,145 @@ impl<T: Zero, const C: usize, const R: usize> Matrix<T, C, R> {
     ///     [40, 45, 47]
     /// ]);
     /// ```
-    pub fn new(data: [[T; R]; C]) -> Self {
+    pub const fn new(data: [[T; R]; C]) -> Self {
         Self { data }
     }
+
+    /// Returns the columns of the matrix, in the same order as 
+    /// [`Matrix::new`] takes them.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    /// 
+    /// let matrix = Matrix2::<f32>::natural([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    /// 
+    /// assert_eq!(matrix.to_cols_array_2d(), [[1.0, 3.0], [2.0, 4.0]]);
+    /// ```
+    pub fn to_cols_array_2d(self) -> [[T; R]; C] {
+        self.data
+    }
+
+    /// Returns a raw pointer to the first value of the matrix, the `C * R` 
+    /// values being contiguous and stored column by column.
+    /// 
+    /// Since the matrix has the same memory layout as `[[T; R]; C]`, it can 
+    /// be passed to C functions expecting a column-major array, such as 
+    /// `float[16]` for a 4x4 matrix.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    /// 
+    /// let matrix = Matrix2::<f32>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let pointer = matrix.as_ptr();
+    /// 
+    /// assert_eq!(unsafe { *pointer.add(2) }, 3.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const T {
+        self.values().as_ptr()
+    }
+
+    /// Returns a mutable raw pointer to the first value of the matrix, the 
+    /// `C * R` values being contiguous and stored column by column.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.values_mut().as_mut_ptr()
+    }
+
+    /// Returns all the values of the matrix, column by column.
+    pub(crate) fn values(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns all the values of the matrix, column by column, as mutable.
+    pub(crate) fn values_mut(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+}
+
+impl<T: Clone, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Creates a new matrix with all its values set to `value`.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    /// 
+    /// assert_eq!(Matrix2::splat(1.5), Matrix2::new([[1.5, 1.5], [1.5, 1.5]]));
+    /// ```
+    pub fn splat(value: T) -> Self {
+        Self {
+            data: std::array::from_fn(|_| std::array::from_fn(|_| value.clone()))
+        }
+    }
+}
+
+macro_rules! impl_cols_array {
+    ($size:literal) => {
+        impl<T: Copy> Matrix<T, $size, $size> {
+            /// Returns the values of the matrix column by column, as expected 
+            /// by the graphics APIs such as OpenGL or wgpu.
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::matrix::Matrix", stringify!($size), ";")]
+            /// 
+            #[doc = concat!("let mut matrix = Matrix", stringify!($size), "::<f32>::new([[0.0; ", stringify!($size), "]; ", stringify!($size), "]);")]
+            /// matrix[1][0] = 5.0;
+            /// 
+            #[doc = concat!("assert_eq!(matrix.to_cols_array()[", stringify!($size), "], 5.0);")]
+            /// ```
+            pub fn to_cols_array(self) -> [T; $size * $size] {
+                std::array::from_fn(|n| self.data[n / $size][n % $size])
+            }
+
+            /// Creates a new matrix from its values column by column, such as 
+            /// the matrices of glTF files.
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::matrix::Matrix", stringify!($size), ";")]
+            /// 
+            #[doc = concat!("let values: [i32; ", stringify!($size * $size), "] = std::array::from_fn(|n| n as i32);")]
+            #[doc = concat!("let matrix = Matrix", stringify!($size), "::from_cols_array(&values);")]
+            /// 
+            #[doc = concat!("assert_eq!(matrix[1][0], ", stringify!($size), ");")]
+            /// assert_eq!(matrix.to_cols_array(), values);
+            /// ```
+            pub fn from_cols_array(values: &[T; $size * $size]) -> Self {
+                Self::new(std::array::from_fn(|column| {
+                    std::array::from_fn(|row| values[column * $size + row])
+                }))
+            }
+
+            /// Creates a new matrix from its values row by row, as they are 
+            /// written mathematically.
+            /// 
+            /// ## Example
+            /// ```
+            #[doc = concat!("use linbra::matrix::Matrix", stringify!($size), ";")]
+            /// 
+            #[doc = concat!("let values: [i32; ", stringify!($size * $size), "] = std::array::from_fn(|n| n as i32);")]
+            #[doc = concat!("let matrix = Matrix", stringify!($size), "::from_rows_array(&values);")]
+            /// 
+            #[doc = concat!("assert_eq!(matrix[0][1], ", stringify!($size), ");")]
+            /// assert_eq!(matrix[1][0], 1);
+            /// ```
+            pub fn from_rows_array(values: &[T; $size * $size]) -> Self {
+                Self::new(std::array::from_fn(|column| {
+                    std::array::from_fn(|row| values[row * $size + column])
+                }))
+            }
+        }
+    };
 }
 
+impl_cols_array!(2);
+impl_cols_array!(3);
+impl_cols_array!(4);
+
 /// Returns the column at index `n` in the matrix.
 /// 
 /// ## Example
@@ -136,3 +464,288 @@ impl<T, const C: usize, const R: usize> ops::IndexMut<usize> for Matrix<T, C, R>
         &mut self.data[row]
     }
 }
+
+/// Implements iteration on the columns of the matrix.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let matrix = Matrix2::new([[1, 2], [3, 4]]);
+/// let columns: Vec<[i32; 2]> = matrix.into_iter().collect();
+/// 
+/// assert_eq!(columns, vec![[1, 2], [3, 4]]);
+/// ```
+impl<T, const C: usize, const R: usize> IntoIterator for Matrix<T, C, R> {
+    type Item = [T; R];
+    type IntoIter = std::array::IntoIter<[T; R], C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+/// Implements iteration on references to the columns of the matrix.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let matrix = Matrix2::new([[1, 2], [3, 4]]);
+/// let mut sums = Vec::new();
+/// 
+/// for column in &matrix {
+///     sums.push(column.iter().sum::<i32>());
+/// }
+/// 
+/// assert_eq!(sums, vec![3, 7]);
+/// ```
+impl<'a, T, const C: usize, const R: usize> IntoIterator for &'a Matrix<T, C, R> {
+    type Item = &'a [T; R];
+    type IntoIter = std::slice::Iter<'a, [T; R]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Implements iteration on mutable references to the columns of the matrix.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let mut matrix = Matrix2::new([[1, 2], [3, 4]]);
+/// 
+/// for column in &mut matrix {
+///     column.reverse();
+/// }
+/// 
+/// assert_eq!(matrix, Matrix2::new([[2, 1], [4, 3]]));
+/// ```
+impl<'a, T, const C: usize, const R: usize> IntoIterator for &'a mut Matrix<T, C, R> {
+    type Item = &'a mut [T; R];
+    type IntoIter = std::slice::IterMut<'a, [T; R]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+/// Creates a single-column matrix from a vector, vectors being columns in the 
+/// products with matrices.
+/// 
+/// ## Example
+/// The outer product of two vectors is the product of a column by a row:
+/// ```
+/// use linbra::{
+///     matrix::Matrix,
+///     vector::Vector,
+/// };
+/// 
+/// let column = Matrix::from(Vector::new([1, 2]));
+/// let row = Vector::new([3, 4, 5]).to_row_matrix();
+/// 
+/// assert_eq!(column * row, Matrix::natural([
+///     [3, 4, 5],
+///     [6, 8, 10],
+/// ]));
+/// ```
+impl<T, const N: usize> From<Vector<T, N>> for Matrix<T, 1, N> {
+    fn from(value: Vector<T, N>) -> Self {
+        Self::new([value.into()])
+    }
+}
+
+/// Returns the vector of the values of a single-column matrix.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     matrix::Matrix,
+///     vector::Vector3,
+/// };
+/// 
+/// let vector: Vector3<i32> = Matrix::new([[1, 2, 3]]).into();
+/// assert_eq!(vector, Vector3::new([1, 2, 3]));
+/// ```
+impl<T, const N: usize> From<Matrix<T, 1, N>> for Vector<T, N> {
+    fn from(value: Matrix<T, 1, N>) -> Self {
+        let [column] = value.data;
+        Vector::new(column)
+    }
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Creates a new matrix from its columns, such as the axes of a basis.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     vector::Vector2,
+    /// };
+    /// 
+    /// let x_axis = Vector2::new([0, 1]);
+    /// let y_axis = Vector2::new([-1, 0]);
+    /// 
+    /// assert_eq!(Matrix2::from_columns([x_axis, y_axis]), Matrix2::natural([
+    ///     [0, -1],
+    ///     [1, 0],
+    /// ]));
+    /// ```
+    pub fn from_columns(columns: [Vector<T, R>; C]) -> Self {
+        Self::new(columns.map(Into::into))
+    }
+
+    /// Creates a new matrix from its rows.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    /// 
+    /// let rows = [Vector3::new([1, 2, 3]), Vector3::new([4, 5, 6])];
+    /// 
+    /// assert_eq!(Matrix::from_rows(rows), Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]));
+    /// ```
+    pub fn from_rows(rows: [Vector<T, C>; R]) -> Self {
+        let mut rows = rows.map(IntoIterator::into_iter);
+
+        Self::new(std::array::from_fn(|_| {
+            std::array::from_fn(|row| rows[row].next().expect("row of length C"))
+        }))
+    }
+
+    /// Creates a new matrix from its rows given as nested vectors, such as 
+    /// the arrays of arrays of JSON documents.
+    /// 
+    /// Returns [`Error::DimensionMismatch`] when there are not `R` rows of `C` 
+    /// values, the found dimensions being the ones of the first row of 
+    /// another length.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     Error,
+    /// };
+    /// 
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// assert_eq!(Matrix::from_nested_vec(rows), Ok(Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ])));
+    /// 
+    /// let ragged = vec![vec![1, 2, 3], vec![4, 5]];
+    /// assert_eq!(Matrix::<i32, 3, 2>::from_nested_vec(ragged), Err(Error::DimensionMismatch {
+    ///     expected: (3, 2),
+    ///     found: (2, 2),
+    /// }));
+    /// ```
+    pub fn from_nested_vec(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let columns = match rows.iter().find(|row| row.len() != C) {
+            Some(row) => row.len(),
+            None => C,
+        };
+
+        if rows.len() != R || columns != C {
+            return Err(Error::DimensionMismatch {
+                expected: (C, R),
+                found: (columns, rows.len()),
+            });
+        }
+
+        let mut rows: Vec<_> = rows.into_iter().map(IntoIterator::into_iter).collect();
+
+        Ok(Self::new(std::array::from_fn(|_| {
+            std::array::from_fn(|row| rows[row].next().expect("row of length C"))
+        })))
+    }
+}
+
+impl<T: Clone, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the rows of the matrix as nested vectors, such as the arrays 
+    /// of arrays of JSON documents.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    /// 
+    /// let matrix = Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    /// 
+    /// assert_eq!(matrix.to_nested_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        (0..R)
+            .map(|row| self.data.iter().map(|column| column[row].clone()).collect())
+            .collect()
+    }
+}
+
+/// Creates a new matrix from a reference to its rows, such as a 
+/// two-dimensional array of C. 
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix;
+/// 
+/// static ROWS: [[i32; 3]; 2] = [
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// ];
+/// 
+/// assert_eq!(Matrix::from(&ROWS), Matrix::natural(ROWS));
+/// ```
+impl<T: Clone, const C: usize, const R: usize> From<&[[T; C]; R]> for Matrix<T, C, R> {
+    fn from(value: &[[T; C]; R]) -> Self {
+        Self::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| value[row][column].clone())
+        }))
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Returns the single-row matrix of the values of the vector, being its 
+    /// transpose.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    /// 
+    /// let row = Vector3::new([1, 2, 3]).to_row_matrix();
+    /// assert_eq!(row, Matrix::natural([[1, 2, 3]]));
+    /// ```
+    pub fn to_row_matrix(self) -> Matrix<T, N, 1> {
+        Matrix::new(<[T; N]>::from(self).map(|value| [value]))
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, 1> {
+    /// Returns the vector of the values of the single-row matrix.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    /// 
+    /// let row = Matrix::natural([[1, 2, 3]]);
+    /// assert_eq!(row.to_row_vector(), Vector3::new([1, 2, 3]));
+    /// ```
+    pub fn to_row_vector(self) -> Vector<T, N> {
+        Vector::new(self.data.map(|[value]| value))
+    }
+}
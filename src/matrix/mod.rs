@@ -2,11 +2,34 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-//! Matrix types and functions to perform calculations on matrices.
+//! Matrix types and functions to perform calculations on matrices, with 
+//! dimensions known at compile time or at runtime.
 
+mod batch;
+mod convolution;
+mod dynamic;
+mod echelon;
+mod eigen;
+mod heightmap;
+mod inertia;
+mod inverse;
+mod iterative;
 mod operations;
+mod reductions;
+mod reshape;
+mod uv;
+mod view;
+#[allow(clippy::module_inception)]
 mod matrix;
+pub use convolution::Edges;
+pub use echelon::{ LinearSystem, Solution };
+pub use iterative::{ IterativeSolution, StoppingCriteria };
+pub use dynamic::*;
 pub use matrix::*;
+pub(crate) use operations::combine4;
+#[cfg(feature = "rayon")]
+pub(crate) use echelon::{ eliminate_by, eliminate_column, reduce_by, reduce_column, tolerance };
+pub use view::*;
 
 /// Matrix with a fixed-length of 2x2.
 pub type Matrix2<T> = Matrix<T, 2, 2>;
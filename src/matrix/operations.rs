@@ -3,3 +3,146 @@
 // Copyright (c) 2023 Antonin Hérault
 
 //! Implementations for operators only related to matrices together.
+//! 
+//! The following operations are implemented:
+//! - matrices product (matrix1 * matrix2)
+
+use std::ops::Mul;
+
+use crate::{ Num, Zero };
+use crate::matrix::Matrix;
+
+/// Number of multiplications from which a product of matrices is computed 
+/// block by block, being about the one of two 64x64 matrices.
+const BLOCKED_THRESHOLD: usize = 64 * 64 * 64;
+
+/// Number of rows and columns of the blocks of a blocked product.
+const BLOCK_SIZE: usize = 32;
+
+/// Implementation for matrices product.
+/// 
+/// For small matrices, the values are computed one by one without a zeroed 
+/// matrix as intermediate, letting the compiler unroll the loops, and the 
+/// products by a matrix of 4 columns such as [`Matrix4`](crate::matrix::Matrix4) 
+/// add whole columns together. For large matrices, the product is computed 
+/// block by block so that the values in use stay in the cache.
+/// 
+/// ## Formula
+/// $$
+/// (A \times B)_{i,j} = \sum_{k=1}^{c} a_{i,k} \times b_{k,j}
+/// $$
+/// 
+/// ## Example
+/// $$
+/// \begin{pmatrix} 
+///     1 & 2 \\\ 
+///     3 & 4 \\\ 
+/// \end{pmatrix}
+/// \times
+/// \begin{pmatrix} 
+///     5 & 6 & 7 \\\ 
+///     8 & 9 & 10 \\\ 
+/// \end{pmatrix} =
+/// \begin{pmatrix} 
+///     21 & 24 & 27 \\\ 
+///     47 & 54 & 61 \\\ 
+/// \end{pmatrix}
+/// $$
+/// 
+/// ```
+/// use linbra::matrix::Matrix;
+/// 
+/// let a = Matrix::<i32, 2, 2>::natural([
+///     [1, 2],
+///     [3, 4],
+/// ]);
+/// let b = Matrix::<i32, 3, 2>::natural([
+///     [5, 6, 7],
+///     [8, 9, 10],
+/// ]);
+/// 
+/// assert_eq!(a * b, Matrix::natural([
+///     [21, 24, 27],
+///     [47, 54, 61],
+/// ]));
+/// ```
+impl<T: Zero + Num, const C: usize, const R: usize, const K: usize> Mul<Matrix<T, K, C>> for Matrix<T, C, R> {
+    type Output = Matrix<T, K, R>;
+
+    fn mul(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        if C == 4 {
+            return Matrix::new(std::array::from_fn(|column| combine4(&self, &rhs[column])));
+        }
+
+        if C * R * K >= BLOCKED_THRESHOLD {
+            return blocked_product(&self, &rhs);
+        }
+
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| {
+                let mut sum = T::zero();
+
+                for k in 0..C {
+                    sum += self[k][row].clone() * rhs[column][k].clone();
+                }
+
+                sum
+            })
+        }))
+    }
+}
+
+/// Returns the combination of the 4 columns of the matrix weighted by the 4 
+/// values, being a column of a product by this matrix, as the hot path of the 
+/// 4x4 transformations.
+/// 
+/// Starting from the first product instead of a zero, the whole columns are 
+/// added together, which the compiler turns into the same instructions as 
+/// the SIMD ones for `f32` values. The caller checks that `C` is 4.
+pub(crate) fn combine4<T: Num, const C: usize, const R: usize>(
+    matrix: &Matrix<T, C, R>, 
+    weights: &[T; C],
+) -> [T; R] {
+    let mut column: [T; R] = std::array::from_fn(|row| matrix[0][row].clone() * weights[0].clone());
+
+    for k in 1..4 {
+        for (row, value) in column.iter_mut().enumerate() {
+            *value += matrix[k][row].clone() * weights[k].clone();
+        }
+    }
+
+    column
+}
+
+/// Returns the product of the two matrices, computed by blocks of 
+/// `BLOCK_SIZE` rows of `lhs` and `BLOCK_SIZE` rows of `rhs`.
+/// 
+/// Since the matrices are stored column by column, each block of `lhs` is 
+/// read contiguously and reused for all the columns of `rhs` while it is in 
+/// the cache.
+fn blocked_product<T: Zero + Num, const C: usize, const R: usize, const K: usize>(
+    lhs: &Matrix<T, C, R>,
+    rhs: &Matrix<T, K, C>,
+) -> Matrix<T, K, R> {
+    let mut output = Matrix::new(std::array::from_fn(|_| std::array::from_fn(|_| T::zero())));
+
+    for k_start in (0..C).step_by(BLOCK_SIZE) {
+        let k_end = (k_start + BLOCK_SIZE).min(C);
+
+        for row_start in (0..R).step_by(BLOCK_SIZE) {
+            let row_end = (row_start + BLOCK_SIZE).min(R);
+
+            for column in 0..K {
+                for k in k_start..k_end {
+                    let value = rhs[column][k].clone();
+
+                    for row in row_start..row_end {
+                        output[column][row] += lhs[k][row].clone() * value.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,236 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Reductions of the rows and the columns of matrices to vectors, such as
+//! their sums or their greatest values, and positions of the greatest and the
+//! least entries, for matrices used as grids of values.
+
+use crate::{ Num, Zero };
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// Returns the value kept when comparing all the values with `keep`, being
+/// the first one when several are equal.
+///
+/// Panics when there is no value.
+fn extremum<'a, T: Clone + 'a>(
+    mut values: impl Iterator<Item = &'a T>,
+    keep: impl Fn(&T, &T) -> bool,
+) -> T {
+    let first = values.next().expect("no value to reduce");
+
+    values
+        .fold(first, |kept, value| if keep(value, kept) { value } else { kept })
+        .clone()
+}
+
+/// Returns the position as `(row, column)` and the value of the entry kept 
+/// when comparing all the entries with `keep`, being the first one column by 
+/// column when several are equal.
+///
+/// Panics when there is no entry.
+fn extremum_entry<T: Clone, const C: usize, const R: usize>(
+    matrix: &Matrix<T, C, R>,
+    keep: impl Fn(&T, &T) -> bool,
+) -> ((usize, usize), T) {
+    let mut kept: Option<((usize, usize), &T)> = None;
+
+    for (column, values) in matrix.into_iter().enumerate() {
+        for (row, value) in values.iter().enumerate() {
+            match kept {
+                Some((_, kept_value)) if !keep(value, kept_value) => (),
+                _ => kept = Some(((row, column), value)),
+            }
+        }
+    }
+
+    let (position, value) = kept.expect("no entry to reduce");
+    (position, value.clone())
+}
+
+/// Implements the sums of the rows and the columns for types implementing the
+/// [`Zero`] and [`Num`] traits.
+impl<T: Zero + Num, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the sums of the values of each column.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.column_sums(), Vector3::new([5, 7, 9]));
+    /// ```
+    pub fn column_sums(&self) -> Vector<T, C> {
+        Vector::new(std::array::from_fn(|column| {
+            let mut sum = T::zero();
+
+            for value in &self[column] {
+                sum += value.clone();
+            }
+
+            sum
+        }))
+    }
+
+    /// Returns the sums of the values of each row.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.row_sums(), Vector2::new([6, 15]));
+    /// ```
+    pub fn row_sums(&self) -> Vector<T, R> {
+        Vector::new(std::array::from_fn(|row| {
+            let mut sum = T::zero();
+
+            for column in self {
+                sum += column[row].clone();
+            }
+
+            sum
+        }))
+    }
+}
+
+/// Implements the greatest and the least values of the rows, the columns and 
+/// the whole matrix, for types implementing the [`PartialOrd`] trait.
+///
+/// These functions panic when the rows or the columns are empty.
+impl<T: PartialOrd + Clone, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the greatest value of each column.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.column_max(), Vector3::new([4, 8, 6]));
+    /// ```
+    pub fn column_max(&self) -> Vector<T, C> {
+        Vector::new(std::array::from_fn(|column| extremum(self[column].iter(), |a, b| a > b)))
+    }
+
+    /// Returns the least value of each column.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector3,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.column_min(), Vector3::new([1, 5, 3]));
+    /// ```
+    pub fn column_min(&self) -> Vector<T, C> {
+        Vector::new(std::array::from_fn(|column| extremum(self[column].iter(), |a, b| a < b)))
+    }
+
+    /// Returns the greatest value of each row.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.row_max(), Vector2::new([8, 6]));
+    /// ```
+    pub fn row_max(&self) -> Vector<T, R> {
+        Vector::new(std::array::from_fn(|row| {
+            extremum(self.into_iter().map(|column| &column[row]), |a, b| a > b)
+        }))
+    }
+
+    /// Returns the least value of each row.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.row_min(), Vector2::new([1, 4]));
+    /// ```
+    pub fn row_min(&self) -> Vector<T, R> {
+        Vector::new(std::array::from_fn(|row| {
+            extremum(self.into_iter().map(|column| &column[row]), |a, b| a < b)
+        }))
+    }
+
+    /// Returns the position as `(row, column)` and the value of the greatest 
+    /// entry, such as the best cell of an influence map.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let influence = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 5, 9],
+    /// ]);
+    ///
+    /// assert_eq!(influence.max_entry(), ((1, 2), 9));
+    /// ```
+    pub fn max_entry(&self) -> ((usize, usize), T) {
+        extremum_entry(self, |a, b| a > b)
+    }
+
+    /// Returns the position as `(row, column)` and the value of the least 
+    /// entry.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let costs = Matrix::natural([
+    ///     [1, 8, 3],
+    ///     [4, 0, 9],
+    /// ]);
+    ///
+    /// assert_eq!(costs.min_entry(), ((1, 1), 0));
+    /// ```
+    pub fn min_entry(&self) -> ((usize, usize), T) {
+        extremum_entry(self, |a, b| a < b)
+    }
+}
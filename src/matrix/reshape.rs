@@ -0,0 +1,87 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Changes of the dimensions of matrices, keeping their values in the order of
+//! their columns or in the same positions.
+
+use crate::matrix::Matrix;
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns a matrix of `C2` columns and `R2` rows with the same values,
+    /// read and written column by column.
+    ///
+    /// The numbers of values must be the same, which is checked at compile
+    /// time.
+    ///
+    /// ## Example
+    /// A flat buffer of samples becomes a grid:
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let samples = Matrix::<i32, 6, 1>::natural([[1, 2, 3, 4, 5, 6]]);
+    /// let grid = samples.reshape::<3, 2>();
+    ///
+    /// assert_eq!(grid, Matrix::natural([
+    ///     [1, 3, 5],
+    ///     [2, 4, 6],
+    /// ]));
+    /// ```
+    ///
+    /// The following fails to compile since the numbers of values differ:
+    /// ```compile_fail
+    /// use linbra::matrix::Matrix2;
+    ///
+    /// let matrix = Matrix2::new([[1, 2], [3, 4]]);
+    /// matrix.reshape::<3, 1>();
+    /// ```
+    pub fn reshape<const C2: usize, const R2: usize>(self) -> Matrix<T, C2, R2> {
+        const { assert!(C * R == C2 * R2, "reshape to a different number of values") };
+
+        let mut values = self.into_iter().flatten();
+
+        Matrix::new(std::array::from_fn(|_| {
+            std::array::from_fn(|_| values.next().expect("same number of values"))
+        }))
+    }
+}
+
+impl<T: Clone, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns a matrix of `C2` columns and `R2` rows keeping the values at
+    /// the same positions, the other ones being truncated, and the new ones
+    /// being set to `fill`.
+    ///
+    /// ## Example
+    /// A 3x3 rotation is embedded into a 4x4 transform:
+    /// ```
+    /// use linbra::matrix::{ Matrix3, Matrix4 };
+    ///
+    /// let rotation = Matrix3::natural([
+    ///     [0, -1, 0],
+    ///     [1, 0, 0],
+    ///     [0, 0, 1],
+    /// ]);
+    ///
+    /// let mut transform: Matrix4<i32> = rotation.resize(0);
+    /// transform[3][3] = 1;
+    ///
+    /// assert_eq!(transform, Matrix4::natural([
+    ///     [0, -1, 0, 0],
+    ///     [1, 0, 0, 0],
+    ///     [0, 0, 1, 0],
+    ///     [0, 0, 0, 1],
+    /// ]));
+    /// assert_eq!(transform.resize(0), rotation);
+    /// ```
+    pub fn resize<const C2: usize, const R2: usize>(self, fill: T) -> Matrix<T, C2, R2> {
+        let mut output = Matrix::splat(fill);
+
+        for (column, values) in self.into_iter().take(C2).enumerate() {
+            for (row, value) in values.into_iter().take(R2).enumerate() {
+                output[column][row] = value;
+            }
+        }
+
+        output
+    }
+}
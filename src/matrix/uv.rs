@@ -0,0 +1,130 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Affine transformations of texture coordinates in the $[0, 1]^2$ space, as
+//! 3x3 matrices of homogeneous coordinates, such as to tile a texture or to
+//! animate the frames of a sprite atlas.
+//!
+//! The transformations are combined by their products, the rightmost one
+//! being applied first.
+//!
+//! ## Example
+//! The third frame of an atlas of 4 columns and 2 rows, frames going from left
+//! to right:
+//! ```
+//! use linbra::{
+//!     matrix::Matrix3,
+//!     vector::Vector2,
+//! };
+//!
+//! let frame = Matrix3::uv_offset(Vector2::new([0.5, 0.0]))
+//!     * Matrix3::uv_tiling(Vector2::new([0.25, 0.5]));
+//!
+//! assert_eq!(frame.transform_uv(Vector2::new([0.0, 0.0])), Vector2::new([0.5, 0.0]));
+//! assert_eq!(frame.transform_uv(Vector2::new([1.0, 1.0])), Vector2::new([0.75, 0.5]));
+//! ```
+
+use crate::Float;
+use crate::matrix::Matrix3;
+use crate::vector::Vector2;
+
+impl<T: Float> Matrix3<T> {
+    /// Returns the scaling of the texture coordinates by the numbers of
+    /// repetitions of the texture along each axis.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} t_{u} & 0 & 0 \\\ 0 & t_{v} & 0 \\\ 0 & 0 & 1 \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix3,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let tiling = Matrix3::uv_tiling(Vector2::new([4.0, 2.0]));
+    ///
+    /// assert_eq!(tiling.transform_uv(Vector2::new([0.5, 0.5])), Vector2::new([2.0, 1.0]));
+    /// ```
+    pub fn uv_tiling(tiles: Vector2<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+
+        Matrix3::natural([
+            [tiles[0], zero, zero],
+            [zero, tiles[1], zero],
+            [zero, zero, one],
+        ])
+    }
+
+    /// Returns the translation of the texture coordinates by the offset.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} 1 & 0 & o_{u} \\\ 0 & 1 & o_{v} \\\ 0 & 0 & 1 \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix3,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let scrolling = Matrix3::uv_offset(Vector2::new([0.25, 0.0]));
+    ///
+    /// assert_eq!(scrolling.transform_uv(Vector2::new([0.5, 0.5])), Vector2::new([0.75, 0.5]));
+    /// ```
+    pub fn uv_offset(offset: Vector2<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+
+        Matrix3::natural([
+            [one, zero, offset[0]],
+            [zero, one, offset[1]],
+            [zero, zero, one],
+        ])
+    }
+
+    /// Returns the rotation of the texture coordinates by the angle in
+    /// radians around the pivot, being usually the centre $(0.5, 0.5)$ of
+    /// the texture.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} \cos\theta & -\sin\theta & p_{u} - p_{u} \cos\theta + p_{v} \sin\theta \\\ \sin\theta & \cos\theta & p_{v} - p_{u} \sin\theta - p_{v} \cos\theta \\\ 0 & 0 & 1 \end{pmatrix} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix3,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let quarter = Matrix3::uv_rotation(std::f64::consts::FRAC_PI_2, Vector2::new([0.5, 0.5]));
+    /// let uv = quarter.transform_uv(Vector2::new([1.0, 0.5]));
+    ///
+    /// assert!(uv.distance(Vector2::new([0.5, 1.0])) < 1e-12);
+    /// ```
+    pub fn uv_rotation(angle: T, pivot: Vector2<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (u, v) = (pivot[0], pivot[1]);
+
+        Matrix3::natural([
+            [cos, -sin, u - u * cos + v * sin],
+            [sin, cos, v - u * sin - v * cos],
+            [zero, zero, one],
+        ])
+    }
+
+    /// Returns the texture coordinates transformed by the matrix, as
+    /// homogeneous coordinates with $w = 1$.
+    ///
+    /// ## Formula
+    /// $$ \begin{pmatrix} u' \\\ v' \end{pmatrix} = \begin{pmatrix} m_{1,1} u + m_{1,2} v + m_{1,3} \\\ m_{2,1} u + m_{2,2} v + m_{2,3} \end{pmatrix} $$
+    pub fn transform_uv(&self, uv: Vector2<T>) -> Vector2<T> {
+        let [x, y, w] = self.to_cols_array_2d();
+
+        Vector2::new([
+            x[0] * uv[0] + y[0] * uv[1] + w[0],
+            x[1] * uv[0] + y[1] * uv[1] + w[1],
+        ])
+    }
+}
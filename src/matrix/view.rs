@@ -0,0 +1,354 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Borrowed rectangular regions of matrices, to work on blocks of a matrix
+//! without copying them.
+//!
+//! A view keeps the column by column layout of its matrix: each column of the
+//! view is contiguous, and two consecutive columns are separated by a stride
+//! being the number of rows of the matrix.
+//!
+//! ## Example
+//! ```
+//! use linbra::matrix::Matrix4;
+//!
+//! let mut matrix = Matrix4::<i32>::natural([
+//!     [1, 2, 0, 0],
+//!     [3, 4, 0, 0],
+//!     [0, 0, 1, 0],
+//!     [0, 0, 0, 1],
+//! ]);
+//!
+//! // Copies the top-left block to the bottom-right one, without allocating.
+//! let top_left = matrix;
+//! matrix.view_mut(2, 2, 2, 2).copy_from(top_left.view(0, 0, 2, 2));
+//!
+//! assert_eq!(matrix, Matrix4::natural([
+//!     [1, 2, 0, 0],
+//!     [3, 4, 0, 0],
+//!     [0, 0, 1, 2],
+//!     [0, 0, 3, 4],
+//! ]));
+//! ```
+
+use std::ops;
+
+use crate::{ Num, Zero };
+use crate::matrix::{DMatrix, Matrix};
+use crate::vector::DVector;
+
+/// Immutable borrow of a rectangular region of a matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    /// Values from the first one of the region to the end of the matrix.
+    values: &'a [T],
+    columns: usize,
+    rows: usize,
+    /// Distance between the first values of two consecutive columns.
+    stride: usize,
+}
+
+// Implemented by hand, the derives requiring `T` to be `Copy` as well.
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+/// Mutable borrow of a rectangular region of a matrix.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    /// Values from the first one of the region to the end of the matrix.
+    values: &'a mut [T],
+    columns: usize,
+    rows: usize,
+    /// Distance between the first values of two consecutive columns.
+    stride: usize,
+}
+
+/// Returns the offset of the first value of the region, checking the region
+/// fits in a matrix of the given dimensions.
+fn region_start(
+    (column, row, columns, rows): (usize, usize, usize, usize),
+    matrix_columns: usize,
+    matrix_rows: usize,
+) -> usize {
+    assert!(
+        column + columns <= matrix_columns && row + rows <= matrix_rows,
+        "region outside of the matrix"
+    );
+
+    column * matrix_rows + row
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Creates a view of the region of `columns` x `rows` values starting at
+    /// `column` and `row`, in values stored column by column.
+    fn new(values: &'a [T], region: (usize, usize, usize, usize), matrix_columns: usize, stride: usize) -> Self {
+        let start = region_start(region, matrix_columns, stride);
+        let (_, _, columns, rows) = region;
+
+        Self { values: &values[start..], columns, rows, stride }
+    }
+
+    /// Returns the number of columns of the view.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// Creates a mutable view of the region of `columns` x `rows` values
+    /// starting at `column` and `row`, in values stored column by column.
+    fn new(values: &'a mut [T], region: (usize, usize, usize, usize), matrix_columns: usize, stride: usize) -> Self {
+        let start = region_start(region, matrix_columns, stride);
+        let (_, _, columns, rows) = region;
+
+        Self { values: &mut values[start..], columns, rows, stride }
+    }
+
+    /// Returns the number of columns of the view.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns an immutable view of the same region.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            values: self.values,
+            columns: self.columns,
+            rows: self.rows,
+            stride: self.stride,
+        }
+    }
+}
+
+impl<T: Clone> MatrixViewMut<'_, T> {
+    /// Overwrites the values of the region by the ones of another view of the
+    /// same dimensions.
+    pub fn copy_from(&mut self, other: MatrixView<'_, T>) {
+        assert_dimensions(self.as_view(), other);
+
+        for column in 0..self.columns {
+            self[column].clone_from_slice(&other[column]);
+        }
+    }
+}
+
+/// Panics when the two views have different dimensions.
+fn assert_dimensions<T>(a: MatrixView<'_, T>, b: MatrixView<'_, T>) {
+    assert!(
+        a.columns == b.columns && a.rows == b.rows,
+        "views of different dimensions"
+    );
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns a view of the region of `columns` x `rows` values starting at
+    /// `column` and `row`.
+    ///
+    /// Panics when the region is outside of the matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix3;
+    ///
+    /// let matrix = Matrix3::<i32>::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// ]);
+    ///
+    /// let view = matrix.view(1, 1, 2, 2);
+    /// assert_eq!(view[0], [5, 8]);
+    /// assert_eq!(view[1], [6, 9]);
+    /// ```
+    pub fn view(&self, column: usize, row: usize, columns: usize, rows: usize) -> MatrixView<'_, T> {
+        MatrixView::new(self.values(), (column, row, columns, rows), C, R)
+    }
+
+    /// Returns a mutable view of the region of `columns` x `rows` values
+    /// starting at `column` and `row`.
+    ///
+    /// Panics when the region is outside of the matrix.
+    pub fn view_mut(&mut self, column: usize, row: usize, columns: usize, rows: usize) -> MatrixViewMut<'_, T> {
+        MatrixViewMut::new(self.values_mut(), (column, row, columns, rows), C, R)
+    }
+}
+
+impl<T> DMatrix<T> {
+    /// Returns a view of the region of `columns` x `rows` values starting at
+    /// `column` and `row`, as [`Matrix::view`].
+    pub fn view(&self, column: usize, row: usize, columns: usize, rows: usize) -> MatrixView<'_, T> {
+        MatrixView::new(self.values(), (column, row, columns, rows), self.columns(), self.rows())
+    }
+
+    /// Returns a mutable view of the region of `columns` x `rows` values
+    /// starting at `column` and `row`, as [`Matrix::view_mut`].
+    pub fn view_mut(&mut self, column: usize, row: usize, columns: usize, rows: usize) -> MatrixViewMut<'_, T> {
+        let (matrix_columns, matrix_rows) = (self.columns(), self.rows());
+        MatrixViewMut::new(self.values_mut(), (column, row, columns, rows), matrix_columns, matrix_rows)
+    }
+}
+
+/// Returns the column at index `n` in the view.
+impl<T> ops::Index<usize> for MatrixView<'_, T> {
+    type Output = [T];
+
+    fn index(&self, column: usize) -> &Self::Output {
+        assert!(column < self.columns, "column outside of the view");
+        &self.values[column * self.stride..column * self.stride + self.rows]
+    }
+}
+
+/// Returns the column at index `n` in the view.
+impl<T> ops::Index<usize> for MatrixViewMut<'_, T> {
+    type Output = [T];
+
+    fn index(&self, column: usize) -> &Self::Output {
+        assert!(column < self.columns, "column outside of the view");
+        &self.values[column * self.stride..column * self.stride + self.rows]
+    }
+}
+
+/// Returns the column at index `n` in the view, as mutable.
+impl<T> ops::IndexMut<usize> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, column: usize) -> &mut Self::Output {
+        assert!(column < self.columns, "column outside of the view");
+        &mut self.values[column * self.stride..column * self.stride + self.rows]
+    }
+}
+
+/// Copies the values of the view into a new dynamic matrix.
+impl<T: Clone> From<MatrixView<'_, T>> for DMatrix<T> {
+    fn from(value: MatrixView<'_, T>) -> Self {
+        let mut data = Vec::with_capacity(value.columns * value.rows);
+
+        for column in 0..value.columns {
+            data.extend_from_slice(&value[column]);
+        }
+
+        DMatrix::new(value.columns, value.rows, data)
+    }
+}
+
+/// Implementation for the product of two views, as for [`Matrix`].
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::{ DMatrix, Matrix3 };
+///
+/// let matrix = Matrix3::<i32>::natural([
+///     [1, 2, 0],
+///     [3, 4, 0],
+///     [0, 0, 1],
+/// ]);
+///
+/// let block = matrix.view(0, 0, 2, 2);
+/// assert_eq!(block * block, DMatrix::natural(vec![
+///     vec![7, 10],
+///     vec![15, 22],
+/// ]));
+/// ```
+impl<T: Zero + Num> ops::Mul<MatrixView<'_, T>> for MatrixView<'_, T> {
+    type Output = DMatrix<T>;
+
+    fn mul(self, rhs: MatrixView<'_, T>) -> Self::Output {
+        assert_eq!(self.columns, rhs.rows, "views of incompatible dimensions");
+
+        let mut output = DMatrix::zeroed(rhs.columns, self.rows);
+
+        for column in 0..rhs.columns {
+            for k in 0..self.columns {
+                let value = rhs[column][k].clone();
+
+                for row in 0..self.rows {
+                    output[column][row] += self[k][row].clone() * value.clone();
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// Implementation for the product of a view and a vector, as for [`Matrix`].
+impl<T: Zero + Num> ops::Mul<DVector<T>> for MatrixView<'_, T> {
+    type Output = DVector<T>;
+
+    fn mul(self, rhs: DVector<T>) -> Self::Output {
+        assert_eq!(self.columns, rhs.len(), "view and vector of incompatible dimensions");
+
+        let mut output = DVector::zeroed(self.rows);
+
+        for (column, value) in rhs.into_iter().enumerate() {
+            for row in 0..self.rows {
+                output[row] += self[column][row].clone() * value.clone();
+            }
+        }
+
+        output
+    }
+}
+
+macro_rules! impl_view_assign {
+    ($trait:ident, $function:ident, $operator:tt, $description:literal) => {
+        #[doc = concat!("Implementation for ", $description, " of a view to the region of a mutable view.")]
+        impl<T: Num> ops::$trait<MatrixView<'_, T>> for MatrixViewMut<'_, T> {
+            fn $function(&mut self, rhs: MatrixView<'_, T>) {
+                assert_dimensions(self.as_view(), rhs);
+
+                for column in 0..self.columns {
+                    for (value, other) in self[column].iter_mut().zip(&rhs[column]) {
+                        *value $operator other.clone();
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_view_assign!(AddAssign, add_assign, +=, "addition");
+impl_view_assign!(SubAssign, sub_assign, -=, "subtraction");
+
+/// Implementation for scalar product of the region of a mutable view.
+///
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+///
+/// let mut matrix = Matrix2::<i32>::natural([
+///     [1, 2],
+///     [3, 4],
+/// ]);
+///
+/// let mut column = matrix.view_mut(1, 0, 1, 2);
+/// column *= 10;
+/// 
+/// assert_eq!(matrix, Matrix2::natural([
+///     [1, 20],
+///     [3, 40],
+/// ]));
+/// ```
+impl<T: Num + ops::MulAssign<U>, U: Num> ops::MulAssign<U> for MatrixViewMut<'_, T> {
+    fn mul_assign(&mut self, rhs: U) {
+        for column in 0..self.columns {
+            for value in self[column].iter_mut() {
+                *value *= rhs.clone();
+            }
+        }
+    }
+}
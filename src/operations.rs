@@ -6,11 +6,12 @@
 //! the matrix-vector products.
 //! 
 //! The following operations are implemented:
-//! - matrix-vector product (matrix * vector)
+//! - matrix-vector product (vector * matrix or matrix * vector)
+//! - dot products between vectors and matrices, with the [`Dot`] trait
 
 use std::ops::Mul;
 
-use crate::{vector::Vector, Zero, Num, matrix::Matrix};
+use crate::{vector::Vector, Zero, Num, matrix::{ combine4, Matrix }};
 
 /// Implementation for matrix-vector product.
 /// 
@@ -90,14 +91,160 @@ impl<const M: usize, const N: usize, T: Zero + Num> Mul<Matrix<T, N, M>> for Vec
     type Output = Vector<T, M>;
 
     fn mul(self, rhs: Matrix<T, N, M>) -> Self::Output {
-        let mut vector = Self::Output::zeroed();
+        rhs * self
+    }
+}
 
-        for n in 0..N {
-            for m in 0..M {
-                vector[m] += self[n] * rhs[n][m];
-            }
+/// Implementation for matrix-vector product, written in the mathematical 
+/// order. 
+/// 
+/// The values are computed one by one without a zeroed vector as 
+/// intermediate, letting the compiler unroll the loops for small matrices, 
+/// and the products by the matrices of 4 columns such as 
+/// [`Matrix4`](crate::matrix::Matrix4) add whole columns together.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix4;
+/// use linbra::vector::Vector4;
+/// 
+/// let translation = Matrix4::<f32>::natural([
+///     [1.0, 0.0, 0.0, 5.0],
+///     [0.0, 1.0, 0.0, 6.0],
+///     [0.0, 0.0, 1.0, 7.0],
+///     [0.0, 0.0, 0.0, 1.0],
+/// ]);
+/// 
+/// let point = Vector4::new([1.0, 2.0, 3.0, 1.0]);
+/// assert_eq!(translation * point, Vector4::new([6.0, 8.0, 10.0, 1.0]));
+/// ```
+impl<const C: usize, const R: usize, T: Zero + Num> Mul<Vector<T, C>> for Matrix<T, C, R> {
+    type Output = Vector<T, R>;
+
+    // The additions are the ones of the sum of the products.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Vector<T, C>) -> Self::Output {
+        if C == 4 {
+            return Vector::new(combine4(&self, &rhs.into()));
         }
 
-        vector
+        Vector::new(std::array::from_fn(|row| {
+            let mut sum = T::zero();
+
+            for column in 0..C {
+                sum += self[column][row].clone() * rhs[column].clone();
+            }
+
+            sum
+        }))
+    }
+}
+
+/// Implements the dot product between vectors and matrices, as the `dot` 
+/// function of numpy, so that algorithms can be written once for all these 
+/// combinations:
+/// - vector · vector is the scalar product of the vectors,
+/// - vector · matrix is the product of the vector as a row by the matrix,
+/// - matrix · vector is the product of the matrix by the vector as a column,
+/// - matrix · matrix is the product of the matrices.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     matrix::Matrix,
+///     vector::{ Vector2, Vector3 },
+///     Dot,
+/// };
+/// 
+/// fn weigh<A: Dot<B>, B>(inputs: A, weights: B) -> A::Output {
+///     inputs.dot(weights)
+/// }
+/// 
+/// let inputs = Vector2::new([1, 2]);
+/// let weights = Matrix::natural([
+///     [1, 0, 3],
+///     [0, 1, 4],
+/// ]);
+/// 
+/// assert_eq!(weigh(inputs, Vector2::new([3, 4])), 11);
+/// assert_eq!(weigh(inputs, weights), Vector3::new([1, 2, 11]));
+/// ```
+pub trait Dot<Rhs = Self> {
+    /// Type of the result of the dot product.
+    type Output;
+
+    /// Returns the dot product of the two values.
+    fn dot(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Implementation for the scalar product of two vectors, see 
+/// [`Vector::dot`].
+impl<T: Zero + Num, const N: usize> Dot for Vector<T, N> {
+    type Output = T;
+
+    fn dot(self, rhs: Self) -> Self::Output {
+        Vector::dot(self, rhs)
+    }
+}
+
+/// Implementation for the product of a vector as a row by a matrix.
+/// 
+/// Unlike the `*` operator between a vector and a matrix, the vector is on 
+/// the left of the matrix. The function is called through the trait, since 
+/// [`Vector::dot`] takes precedence with the method syntax.
+/// 
+/// ## Formula
+/// $$ (v M)_{j} = \sum_{i = 1}^{R} v_{i} \times m_{i,j} $$
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     matrix::Matrix,
+///     vector::{ Vector2, Vector3 },
+///     Dot,
+/// };
+/// 
+/// let matrix = Matrix::natural([
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// ]);
+/// 
+/// assert_eq!(Dot::dot(Vector2::new([1, 10]), matrix), Vector3::new([41, 52, 63]));
+/// ```
+impl<T: Zero + Num, const C: usize, const R: usize> Dot<Matrix<T, C, R>> for Vector<T, R> {
+    type Output = Vector<T, C>;
+
+    fn dot(self, rhs: Matrix<T, C, R>) -> Self::Output {
+        Vector::new(std::array::from_fn(|column| {
+            let mut sum = T::zero();
+
+            for row in 0..R {
+                sum += self[row].clone() * rhs[column][row].clone();
+            }
+
+            sum
+        }))
+    }
+}
+
+/// Implementation for the product of a matrix by a vector as a column, being 
+/// the same as the `*` operator.
+impl<T: Zero + Num, const C: usize, const R: usize> Dot<Vector<T, C>> for Matrix<T, C, R> {
+    type Output = Vector<T, R>;
+
+    fn dot(self, rhs: Vector<T, C>) -> Self::Output {
+        self * rhs
+    }
+}
+
+/// Implementation for the product of two matrices, being the same as the `*` 
+/// operator.
+impl<T: Zero + Num, const C: usize, const R: usize, const K: usize> Dot<Matrix<T, K, C>> 
+    for Matrix<T, C, R> 
+{
+    type Output = Matrix<T, K, R>;
+
+    fn dot(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        self * rhs
     }
 }
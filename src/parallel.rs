@@ -0,0 +1,179 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Parallel calculations on large matrices with `rayon`, available with the
+//! `rayon` feature.
+//!
+//! The operators stay sequential, the parallel functions being worth it only
+//! for large matrices (from about 64x64), where the work of each thread
+//! outweighs the cost of spreading it.
+//!
+//! The products compute the columns of the result in parallel, and the
+//! eliminations update the columns after each pivot in parallel, giving the
+//! same values as the sequential functions.
+
+use std::ops::Div;
+
+use rayon::prelude::*;
+
+use crate::{ Field, Num, One, Zero };
+use crate::matrix::{
+    eliminate_by, eliminate_column, reduce_by, reduce_column, tolerance, LinearSystem, Matrix,
+};
+use crate::vector::Vector;
+
+/// Number of multiplications below which a calculation is done sequentially,
+/// being about the one of the product of two 64x64 matrices.
+const PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+
+/// Updates the columns after a pivot in parallel, with the pivot column
+/// `factors`.
+fn par_update<T: Send + Sync>(
+    columns: &mut [T],
+    factors: &[T],
+    pivot_row: usize,
+    update: impl Fn(&mut [T], &[T], usize) + Sync,
+) {
+    columns
+        .par_chunks_mut(factors.len())
+        .for_each(|column| update(column, factors, pivot_row));
+}
+
+impl<T: Zero + Num + Send + Sync, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the product of the two matrices, computing the columns of the
+    /// result in parallel.
+    ///
+    /// Small matrices are multiplied sequentially, as with the `*` operator.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let a = Box::new(Matrix::<f64, 128, 128>::new([[1.0; 128]; 128]));
+    /// let b = Box::new(Matrix::<f64, 128, 128>::new([[2.0; 128]; 128]));
+    ///
+    /// let product = a.par_mul(*b);
+    /// assert_eq!(product[5][7], 256.0);
+    /// ```
+    pub fn par_mul<const K: usize>(&self, rhs: Matrix<T, K, C>) -> Matrix<T, K, R> {
+        if C * R * K < PARALLEL_THRESHOLD {
+            return self.clone() * rhs;
+        }
+
+        let mut product = Matrix::new(std::array::from_fn(|_| std::array::from_fn(|_| T::zero())));
+
+        product.values_mut().par_chunks_mut(R).enumerate().for_each(|(column, values)| {
+            for (row, value) in values.iter_mut().enumerate() {
+                for k in 0..C {
+                    *value += self[k][row].clone() * rhs[column][k].clone();
+                }
+            }
+        });
+
+        product
+    }
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R>
+where
+    T: Field + PartialOrd + Send + Sync
+{
+    /// Returns the reduced row echelon form of the matrix, as
+    /// [`Matrix::rref`], updating the columns after each pivot in parallel.
+    ///
+    /// Small matrices are reduced sequentially.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let matrix = Box::new(Matrix::<f64, 128, 64>::new(std::array::from_fn(|column| {
+    ///     std::array::from_fn(|row| (column + row * column % 7) as f64)
+    /// })));
+    ///
+    /// assert_eq!(matrix.clone().par_rref(), matrix.rref());
+    /// ```
+    pub fn par_rref(self) -> Self {
+        if C * R * R.min(C) < PARALLEL_THRESHOLD {
+            return self.rref();
+        }
+
+        let mut matrix = self;
+        let tolerance = tolerance(matrix.values(), R);
+        reduce_by(matrix.values_mut(), R, &tolerance, |columns, factors, pivot_row| {
+            par_update(columns, factors, pivot_row, reduce_column);
+        });
+        matrix
+    }
+
+    /// Solves the linear system $Ax = b$, as [`Matrix::solve_system`],
+    /// reducing the augmented matrix in parallel.
+    ///
+    /// Small systems are solved sequentially.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::{ Matrix, Solution },
+    ///     vector::Vector,
+    /// };
+    ///
+    /// // Diagonally dominant system whose solution is only ones.
+    /// let a = Box::new(Matrix::<f64, 96, 96>::new(std::array::from_fn(|column| {
+    ///     std::array::from_fn(|row| if row == column { 4.0 } else { 1.0 / 96.0 })
+    /// })));
+    /// let b = Vector::new([4.0 + 95.0 / 96.0; 96]);
+    ///
+    /// let Solution::Unique(x) = a.par_solve_system(b).solution else {
+    ///     panic!("the system has a unique solution");
+    /// };
+    ///
+    /// assert!(x.distance(Vector::new([1.0; 96])) < 1e-12);
+    /// ```
+    pub fn par_solve_system(self, b: Vector<T, R>) -> LinearSystem<T, C> {
+        if (C + 1) * R * R.min(C + 1) < PARALLEL_THRESHOLD {
+            return self.solve_system(b);
+        }
+
+        let mut values = self.augmented(b);
+        let tolerance = tolerance(&values, R);
+        reduce_by(&mut values, R, &tolerance, |columns, factors, pivot_row| {
+            par_update(columns, factors, pivot_row, reduce_column);
+        });
+
+        Self::read_system(&values)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Zero + One + Num + Div<Output = T> + PartialOrd + Send + Sync
+{
+    /// Returns the determinant of the square matrix, as
+    /// [`Matrix::determinant`], updating the columns after each pivot in
+    /// parallel.
+    ///
+    /// Small matrices are eliminated sequentially.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let scale = Box::new(Matrix::<f64, 100, 100>::new(std::array::from_fn(|column| {
+    ///     std::array::from_fn(|row| if row == column { 2.0 } else { 0.0 })
+    /// })));
+    ///
+    /// assert_eq!(scale.par_determinant(), 2f64.powi(100));
+    /// ```
+    pub fn par_determinant(self) -> T {
+        if N * N * N < PARALLEL_THRESHOLD {
+            return self.determinant();
+        }
+
+        let mut matrix = self;
+        eliminate_by(matrix.values_mut(), N, |columns, factors, pivot_row| {
+            par_update(columns, factors, pivot_row, eliminate_column);
+        })
+    }
+}
@@ -0,0 +1,75 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Implementations of the `bytemuck` traits for vectors and matrices, 
+//! available with the `bytemuck` feature.
+//! 
+//! Vectors and matrices are transparent wrappers around arrays, they are plain 
+//! old data as soon as their values are.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+// SAFETY: `Vector<T, N>` is `#[repr(transparent)]` over `[T; N]`, which is 
+// zeroable when `T` is.
+unsafe impl<T: Zeroable, const N: usize> Zeroable for Vector<T, N> {}
+
+/// Lets vectors be cast from and to bytes.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let vertices = [
+///     Vector3::<f32>::new([0.0, 1.0, 0.0]),
+///     Vector3::<f32>::new([1.0, 0.0, 0.0]),
+/// ];
+/// 
+/// let floats: &[f32] = bytemuck::cast_slice(&vertices);
+/// assert_eq!(floats, &[0.0, 1.0, 0.0, 1.0, 0.0, 0.0]);
+/// 
+/// let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+/// assert_eq!(bytes.len(), 24);
+/// ```
+// SAFETY: `Vector<T, N>` is `#[repr(transparent)]` over `[T; N]`, which has no 
+// padding and is plain old data when `T` is.
+unsafe impl<T: Pod, const N: usize> Pod for Vector<T, N> {}
+
+// SAFETY: `Matrix<T, C, R>` is `#[repr(transparent)]` over `[[T; R]; C]`, 
+// which is zeroable when `T` is.
+unsafe impl<T: Zeroable, const C: usize, const R: usize> Zeroable for Matrix<T, C, R> {}
+
+/// Lets matrices be cast from and to bytes, column by column.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let matrix = Matrix2::<f32>::new([[1.0, 2.0], [3.0, 4.0]]);
+/// 
+/// let floats: &[f32; 4] = bytemuck::cast_ref(&matrix);
+/// assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+/// ```
+// SAFETY: `Matrix<T, C, R>` is `#[repr(transparent)]` over `[[T; R]; C]`, 
+// which has no padding and is plain old data when `T` is.
+unsafe impl<T: Pod, const C: usize, const R: usize> Pod for Matrix<T, C, R> {}
+
+impl<T: Pod, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the bytes of the matrix, column by column, ready to be written 
+    /// to a GPU buffer.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix4;
+    /// 
+    /// let matrix = Matrix4::<f32>::new([[1.0; 4]; 4]);
+    /// 
+    /// assert_eq!(matrix.as_bytes().len(), 64);
+    /// assert_eq!(matrix.as_bytes()[..4], 1.0f32.to_ne_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
@@ -2,15 +2,40 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-//! Traits to get x, y and z values of a 2d-point or a 3d-point, and 
-//! constructors for these points.
+//! Traits to get and set x, y and z values of a 2d-point or a 3d-point,
+//! constructors for these points, a strongly-typed 2d-point wrapper and a 
+//! wrapper of homogeneous coordinates.
 
 use std::ops;
 
-use crate::vector::{Vector2, Vector3};
+use crate::{
+    matrix::Matrix4,
+    sizes::Size2,
+    vector::{Vector2, Vector3, Vector4},
+    Float,
+    Num,
+    One,
+    Zero,
+};
 
-/// Implements functions to retrieve vertical and horizontal positions of a 
-/// point in a 2d plan.
+/// Implements functions to retrieve and update vertical and horizontal 
+/// positions of a point in a 2d plan.
+/// 
+/// The setters are only available to the points that can be indexed mutably.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     points::Point2,
+///     vector::Vector2
+/// };
+/// 
+/// let mut point = Vector2::at(10, 5);
+/// point.set_x(3);
+/// 
+/// assert_eq!(point, Vector2::at(3, 5));
+/// assert_eq!(point.with_y(8), Vector2::at(3, 8));
+/// ```
 pub trait Point2<T: Copy>: ops::Index<usize, Output = T> {
     /// Returns the value on the x-axis of the point.
     fn x(&self) -> T {
@@ -21,6 +46,28 @@ pub trait Point2<T: Copy>: ops::Index<usize, Output = T> {
     fn y(&self) -> T {
         self[1]
     }
+
+    /// Sets the value on the x-axis of the point.
+    fn set_x(&mut self, x: T) where Self: ops::IndexMut<usize> {
+        self[0] = x;
+    }
+
+    /// Sets the value on the y-axis of the point.
+    fn set_y(&mut self, y: T) where Self: ops::IndexMut<usize> {
+        self[1] = y;
+    }
+
+    /// Returns a copy of the point with another value on the x-axis.
+    fn with_x(mut self, x: T) -> Self where Self: Sized + ops::IndexMut<usize> {
+        self.set_x(x);
+        self
+    }
+
+    /// Returns a copy of the point with another value on the y-axis.
+    fn with_y(mut self, y: T) -> Self where Self: Sized + ops::IndexMut<usize> {
+        self.set_y(y);
+        self
+    }
 }
 
 /// Implements the [`Point2`] trait for vectors 2.
@@ -50,15 +97,41 @@ impl<T: Copy> Vector2<T> {
     }
 }
 
-/// Implements a function to retrieve the depth of a point in a 3D plan.
+/// Implements functions to retrieve and update the depth of a point in a 3D 
+/// plan.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     points::{ Point2, Point3 },
+///     vector::Vector3
+/// };
+/// 
+/// let mut point = Vector3::at(10, 5, 2);
+/// point.set_z(4);
+/// 
+/// assert_eq!(point, Vector3::at(10, 5, 4));
+/// assert_eq!(point.with_x(1).with_z(0), Vector3::at(1, 5, 0));
+/// ```
 pub trait Point3<T: Copy>: Point2<T> {
     /// Returns the value on the z-axis of the point.
     fn z(&self) -> T {
         self[2]
     }
+
+    /// Sets the value on the z-axis of the point.
+    fn set_z(&mut self, z: T) where Self: ops::IndexMut<usize> {
+        self[2] = z;
+    }
+
+    /// Returns a copy of the point with another value on the z-axis.
+    fn with_z(mut self, z: T) -> Self where Self: Sized + ops::IndexMut<usize> {
+        self.set_z(z);
+        self
+    }
 }
 
-/// Implements the [`Point3`] trait for 2-vectors.
+/// Implements the [`Point3`] trait for 3-vectors.
 impl<T: Copy> Point3<T> for Vector3<T> {}
 
 /// Implements a constructor for 3d-points.
@@ -82,3 +155,633 @@ impl<T: Copy> Vector3<T> {
         [x, y, z].into()
     }
 }
+
+/// Implements the conversions between cartesian and polar coordinates for 
+/// 2d-points of types implementing the [`Float`] trait.
+impl<T: Float> Vector2<T> {
+    /// Creates a new point on a 2D plan from its distance to the 
+    /// origin and its angle in radians, counter-clockwise from the 
+    /// x-axis.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     y \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     r \cos \theta \\\ 
+    ///     r \sin \theta \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::Point2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// let point = Vector2::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    /// 
+    /// assert!(point.x().abs() < 1e-6);
+    /// assert!((point.y() - 2.0).abs() < 1e-6);
+    /// ```
+    pub fn from_polar(radius: T, angle: T) -> Self {
+        Self::at(radius * angle.cos(), radius * angle.sin())
+    }
+
+    /// Returns the polar coordinates of the point as `(radius, angle)`, 
+    /// the angle being in radians in the range $[-\pi, \pi]$.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// r = \sqrt{x^2 + y^2}, \quad \theta = \operatorname{atan2}(y, x)
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let (radius, angle) = Vector2::at(0.0, 3.0).to_polar();
+    /// 
+    /// assert_eq!(radius, 3.0);
+    /// assert_eq!(angle, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn to_polar(self) -> (T, T) {
+        (self.length(), self.y().atan2(self.x()))
+    }
+}
+
+/// Implements the conversions between cartesian, spherical and cylindrical 
+/// coordinates for 3d-points of types implementing the [`Float`] trait.
+/// 
+/// The polar angle $\theta$ is measured from the z-axis and the 
+/// azimuthal angle $\phi$ is measured counter-clockwise from the x-axis 
+/// in the xy-plan, both in radians.
+impl<T: Float> Vector3<T> {
+    /// Creates a new point in a 3D plan from its distance to the 
+    /// origin, its polar angle and its azimuthal angle.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     y \\\ 
+    ///     z \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     r \sin \theta \cos \phi \\\ 
+    ///     r \sin \theta \sin \phi \\\ 
+    ///     r \cos \theta \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::{ Point2, Point3 },
+    ///     vector::Vector3
+    /// };
+    /// 
+    /// use std::f64::consts::FRAC_PI_2;
+    /// 
+    /// let point = Vector3::<f64>::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2);
+    /// 
+    /// assert!(point.x().abs() < 1e-6);
+    /// assert!((point.y() - 2.0).abs() < 1e-6);
+    /// assert!(point.z().abs() < 1e-6);
+    /// ```
+    pub fn from_spherical(r: T, theta: T, phi: T) -> Self {
+        let (sin_theta, cos_theta) = (theta.sin(), theta.cos());
+        let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+
+        Self::at(
+            r * sin_theta * cos_phi, 
+            r * sin_theta * sin_phi, 
+            r * cos_theta
+        )
+    }
+
+    /// Returns the spherical coordinates of the point as 
+    /// `(r, theta, phi)`.
+    /// 
+    /// The origin has all its coordinates to zero.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// r = \sqrt{x^2 + y^2 + z^2}, \quad 
+    /// \theta = \operatorname{atan2}(\sqrt{x^2 + y^2}, z), \quad 
+    /// \phi = \operatorname{atan2}(y, x)
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let (r, theta, phi) = Vector3::<f64>::at(0.0, 0.0, 4.0).to_spherical();
+    /// 
+    /// assert_eq!(r, 4.0);
+    /// assert_eq!(theta, 0.0);
+    /// assert_eq!(phi, 0.0);
+    /// ```
+    pub fn to_spherical(self) -> (T, T, T) {
+        let r = self.length();
+
+        if r == T::zero() {
+            return (T::zero(), T::zero(), T::zero());
+        }
+
+        let rho = (self.x() * self.x() + self.y() * self.y()).sqrt();
+        (r, rho.atan2(self.z()), self.y().atan2(self.x()))
+    }
+
+    /// Creates a new point in a 3D plan from its distance to the 
+    /// z-axis, its azimuthal angle and its height.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     y \\\ 
+    ///     z \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     \rho \cos \phi \\\ 
+    ///     \rho \sin \phi \\\ 
+    ///     z \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::{ Point2, Point3 },
+    ///     vector::Vector3
+    /// };
+    /// 
+    /// let point = Vector3::<f64>::from_cylindrical(2.0, 0.0, 5.0);
+    /// 
+    /// assert_eq!(point, Vector3::at(2.0, 0.0, 5.0));
+    /// ```
+    pub fn from_cylindrical(rho: T, phi: T, z: T) -> Self {
+        let (sin, cos) = (phi.sin(), phi.cos());
+        Self::at(rho * cos, rho * sin, z)
+    }
+
+    /// Returns the cylindrical coordinates of the point as 
+    /// `(rho, phi, z)`.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \rho = \sqrt{x^2 + y^2}, \quad 
+    /// \phi = \operatorname{atan2}(y, x), \quad 
+    /// z = z
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let (rho, phi, z) = Vector3::<f64>::at(3.0, 4.0, 1.0).to_cylindrical();
+    /// 
+    /// assert_eq!(rho, 5.0);
+    /// assert_eq!(z, 1.0);
+    /// ```
+    pub fn to_cylindrical(self) -> (T, T, T) {
+        let rho = (self.x() * self.x() + self.y() * self.y()).sqrt();
+        (rho, self.y().atan2(self.x()), self.z())
+    }
+}
+
+/// Implements the rotations of 2d-points of types implementing the [`Float`] 
+/// trait.
+impl<T: Float> Vector2<T> {
+    /// Returns the point rotated around the origin by `angle` radians, 
+    /// counter-clockwise.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     x \cos \theta - y \sin \theta \\\ 
+    ///     x \sin \theta + y \cos \theta \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::Point2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// let point = Vector2::at(1.0, 0.0)
+    ///     .rotated(std::f64::consts::FRAC_PI_2);
+    /// 
+    /// assert!(point.x().abs() < 1e-6);
+    /// assert!((point.y() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotated(self, angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        Self::at(
+            self.x() * cos - self.y() * sin, 
+            self.x() * sin + self.y() * cos
+        )
+    }
+
+    /// Returns the point rotated around `pivot` by `angle` radians, 
+    /// counter-clockwise.
+    /// 
+    /// ## Formula
+    /// $$ p' = R_{\theta} (p - c) + c $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::Point2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// let point = Vector2::at(3.0, 1.0)
+    ///     .rotated_around(Vector2::at(2.0, 1.0), std::f64::consts::PI);
+    /// 
+    /// assert!((point.x() - 1.0).abs() < 1e-6);
+    /// assert!((point.y() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotated_around(self, pivot: Self, angle: T) -> Self {
+        (self - pivot).rotated(angle) + pivot
+    }
+}
+
+/// Position of a point on a 2D plan, wrapping the [`Vector2`] of its x and y 
+/// values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     points::{ Point2, Point2D },
+///     vector::Vector2
+/// };
+/// 
+/// let point = Point2D::new(10, 5);
+/// assert_eq!(point.x(), 10);
+/// 
+/// let vector: Vector2<i32> = point.into();
+/// assert_eq!(vector, Vector2::new([10, 5]));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Point2D<T>(Vector2<T>);
+
+impl<T> Point2D<T> {
+    /// Creates a new strongly-typed point on a 2D plan.
+    pub fn new(x: T, y: T) -> Self {
+        Self([x, y].into())
+    }
+}
+
+/// Wraps a vector into a [`Point2D`].
+impl<T> From<Vector2<T>> for Point2D<T> {
+    fn from(value: Vector2<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// Unwraps the vector of a [`Point2D`].
+impl<T> From<Point2D<T>> for Vector2<T> {
+    fn from(value: Point2D<T>) -> Self {
+        value.0
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector.
+impl<T> ops::Index<usize> for Point2D<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector, as mutable.
+impl<T> ops::IndexMut<usize> for Point2D<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Implements the [`Point2`] trait for strongly-typed 2d-points.
+impl<T: Copy> Point2<T> for Point2D<T> {}
+
+/// Implements the conversion between top-left-origin and bottom-left-origin 
+/// coordinates.
+impl<T: Copy + Num> Vector2<T> {
+    /// Returns the point with its y-axis flipped inside a plan of the given 
+    /// height, converting top-left-origin screen coordinates into 
+    /// bottom-left-origin coordinates and vice versa.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     h - y \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let point = Vector2::at(100, 20);
+    /// 
+    /// assert_eq!(point.flip_y(600), Vector2::at(100, 580));
+    /// assert_eq!(point.flip_y(600).flip_y(600), point);
+    /// ```
+    pub fn flip_y(self, height: T) -> Self {
+        Self::at(self.x(), height - self.y())
+    }
+}
+
+/// Implements the conversions between screen coordinates and normalized 
+/// device or centered coordinates, for 2d-points of types implementing the 
+/// [`Float`] trait.
+/// 
+/// Screen coordinates have their origin at the top-left corner and the 
+/// y-axis going down, when normalized device coordinates go from -1 to 
+/// 1 with the y-axis going up.
+impl<T: Float> Vector2<T> {
+    /// Returns the normalized device coordinates of a point given in 
+    /// screen coordinates, for a screen of the given size.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     \frac{2x}{w} - 1 \\\ 
+    ///     1 - \frac{2y}{h} \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let screen = Vector2::<f32>::size(800.0, 600.0);
+    /// let point = Vector2::<f32>::at(400.0, 450.0);
+    /// 
+    /// assert_eq!(point.screen_to_ndc(screen), Vector2::at(0.0, -0.5));
+    /// ```
+    pub fn screen_to_ndc(self, size: Self) -> Self {
+        let (one, two) = (T::one(), T::from_f64(2.0));
+
+        Self::at(
+            two * self.x() / size.w() - one, 
+            one - two * self.y() / size.h()
+        )
+    }
+
+    /// Returns the screen coordinates of a point given in normalized 
+    /// device coordinates, for a screen of the given size.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     \frac{(x + 1) w}{2} \\\ 
+    ///     \frac{(1 - y) h}{2} \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let screen = Vector2::<f32>::size(800.0, 600.0);
+    /// let point = Vector2::<f32>::at(0.0, -0.5);
+    /// 
+    /// assert_eq!(point.ndc_to_screen(screen), Vector2::at(400.0, 450.0));
+    /// ```
+    pub fn ndc_to_screen(self, size: Self) -> Self {
+        let (one, two) = (T::one(), T::from_f64(2.0));
+
+        Self::at(
+            (self.x() + one) * size.w() / two, 
+            (one - self.y()) * size.h() / two
+        )
+    }
+
+    /// Returns the coordinates of a point given in screen coordinates, 
+    /// relatively to the center of the screen with the y-axis going 
+    /// up, for a screen of the given size.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     x - \frac{w}{2} \\\ 
+    ///     \frac{h}{2} - y \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let screen = Vector2::<f64>::size(800.0, 600.0);
+    /// let point = Vector2::<f64>::at(400.0, 0.0);
+    /// 
+    /// assert_eq!(point.screen_to_centered(screen), Vector2::at(0.0, 300.0));
+    /// ```
+    pub fn screen_to_centered(self, size: Self) -> Self {
+        let two = T::from_f64(2.0);
+        Self::at(self.x() - size.w() / two, size.h() / two - self.y())
+    }
+
+    /// Returns the screen coordinates of a point given relatively to 
+    /// the center of the screen with the y-axis going up, for a screen 
+    /// of the given size.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x' \\\ 
+    ///     y' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     x + \frac{w}{2} \\\ 
+    ///     \frac{h}{2} - y \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let screen = Vector2::<f64>::size(800.0, 600.0);
+    /// let point = Vector2::<f64>::at(0.0, 300.0);
+    /// 
+    /// assert_eq!(point.centered_to_screen(screen), Vector2::at(400.0, 0.0));
+    /// ```
+    pub fn centered_to_screen(self, size: Self) -> Self {
+        let two = T::from_f64(2.0);
+        Self::at(self.x() + size.w() / two, size.h() / two - self.y())
+    }
+}
+
+/// Homogeneous coordinates of the 3D space, wrapping a [`Vector4`] whose `w` 
+/// component tells whether it is a point (`w` not zero) or a direction (`w` 
+/// being zero).
+/// 
+/// Points are translated by the transformation matrices, while directions are 
+/// not. Creating them with [`Homogeneous::point`] or 
+/// [`Homogeneous::direction`], and going back to 3D with 
+/// [`Homogeneous::to_vector3`], avoids forgetting the `w` component or the 
+/// perspective division.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     matrix::Matrix4,
+///     points::Homogeneous,
+///     vector::Vector3,
+/// };
+/// 
+/// let translation = Matrix4::natural([
+///     [1, 0, 0, 5],
+///     [0, 1, 0, 0],
+///     [0, 0, 1, 0],
+///     [0, 0, 0, 1],
+/// ]);
+/// 
+/// let point = translation * Homogeneous::point(Vector3::new([1, 2, 3]));
+/// let direction = translation * Homogeneous::direction(Vector3::new([1, 2, 3]));
+/// 
+/// assert_eq!(point.to_vector3(), Vector3::new([6, 2, 3]));
+/// assert_eq!(direction.to_vector3(), Vector3::new([1, 2, 3]));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Homogeneous<T>(Vector4<T>);
+
+impl<T: Zero + One + Num + ops::Div<Output = T> + Copy> Homogeneous<T> {
+    /// Creates the homogeneous coordinates of a point, `w` being one.
+    pub fn point(point: Vector3<T>) -> Self {
+        let [x, y, z]: [T; 3] = point.into();
+        Self(Vector4::new([x, y, z, T::one()]))
+    }
+
+    /// Creates the homogeneous coordinates of a direction, `w` being zero.
+    pub fn direction(direction: Vector3<T>) -> Self {
+        let [x, y, z]: [T; 3] = direction.into();
+        Self(Vector4::new([x, y, z, T::zero()]))
+    }
+
+    /// Returns the `w` component.
+    pub fn w(&self) -> T {
+        self.0[3]
+    }
+
+    /// Whether the coordinates are the ones of a point, `w` not being zero.
+    pub fn is_point(&self) -> bool {
+        self.w() != T::zero()
+    }
+
+    /// Whether the coordinates are the ones of a direction, `w` being zero.
+    pub fn is_direction(&self) -> bool {
+        !self.is_point()
+    }
+
+    /// Returns the coordinates divided by `w` for a point, so that `w` is one, 
+    /// and the same coordinates for a direction.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     y \\\ 
+    ///     z \\\ 
+    ///     w \\\ 
+    /// \end{pmatrix} \sim
+    /// \begin{pmatrix} 
+    ///     x / w \\\ 
+    ///     y / w \\\ 
+    ///     z / w \\\ 
+    ///     1 \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     points::Homogeneous,
+    ///     vector::Vector4,
+    /// };
+    /// 
+    /// let clip = Homogeneous::from(Vector4::new([2.0, 4.0, 1.0, 2.0]));
+    /// assert_eq!(Vector4::from(clip.normalize()), Vector4::new([1.0, 2.0, 0.5, 1.0]));
+    /// ```
+    pub fn normalize(self) -> Self {
+        if self.is_direction() {
+            return self;
+        }
+
+        let w = self.w();
+        Self(Vector4::new([self.0[0] / w, self.0[1] / w, self.0[2] / w, T::one()]))
+    }
+
+    /// Returns the 3D point of the coordinates after the division by `w`, or 
+    /// the 3D direction when `w` is zero.
+    pub fn to_vector3(self) -> Vector3<T> {
+        let [x, y, z, _]: [T; 4] = self.normalize().0.into();
+        Vector3::new([x, y, z])
+    }
+}
+
+/// Wraps a vector into [`Homogeneous`] coordinates, its `w` component telling 
+/// whether it is a point or a direction.
+impl<T> From<Vector4<T>> for Homogeneous<T> {
+    fn from(value: Vector4<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// Unwraps the vector of [`Homogeneous`] coordinates.
+impl<T> From<Homogeneous<T>> for Vector4<T> {
+    fn from(value: Homogeneous<T>) -> Self {
+        value.0
+    }
+}
+
+/// Returns the 3D point or direction of [`Homogeneous`] coordinates, see 
+/// [`Homogeneous::to_vector3`].
+impl<T: Zero + One + Num + ops::Div<Output = T> + Copy> From<Homogeneous<T>> for Vector3<T> {
+    fn from(value: Homogeneous<T>) -> Self {
+        value.to_vector3()
+    }
+}
+
+/// Implementation for the transformation of [`Homogeneous`] coordinates by a 
+/// 4x4 matrix.
+impl<T: Zero + Num> ops::Mul<Homogeneous<T>> for Matrix4<T> {
+    type Output = Homogeneous<T>;
+
+    fn mul(self, rhs: Homogeneous<T>) -> Self::Output {
+        Homogeneous(self * rhs.0)
+    }
+}
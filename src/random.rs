@@ -0,0 +1,159 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Random generation of vectors and matrices, available with the `rand` 
+//! feature.
+//! 
+//! Vectors and matrices are sampled component by component from the 
+//! `StandardUniform` and `Uniform` distributions. Uniform samplers of 
+//! directions and points in the unit circle are also provided for float 
+//! vectors.
+
+use rand::{
+    distr::{uniform::SampleUniform, Distribution, StandardUniform, Uniform},
+    Rng,
+};
+
+use crate::{matrix::Matrix, vector::{Vector, Vector2, Vector3}};
+
+/// Samples each component of the vector from the standard distribution of its 
+/// type.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let vector: Vector3<f32> = rand::random();
+/// assert!(vector.into_iter().all(|value| (0.0..1.0).contains(&value)));
+/// ```
+impl<T, const N: usize> Distribution<Vector<T, N>> for StandardUniform 
+where 
+    StandardUniform: Distribution<T> 
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector<T, N> {
+        Vector::new(std::array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+/// Samples each component of the vector in the range of the uniform 
+/// distribution.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector2;
+/// use rand::distr::{ Distribution, Uniform };
+/// 
+/// let range = Uniform::new(-10, 10).unwrap();
+/// let vector: Vector2<i32> = range.sample(&mut rand::rng());
+/// 
+/// assert!(vector.into_iter().all(|value| (-10..10).contains(&value)));
+/// ```
+impl<T: SampleUniform, const N: usize> Distribution<Vector<T, N>> for Uniform<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector<T, N> {
+        Vector::new(std::array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+/// Samples each value of the matrix from the standard distribution of its 
+/// type.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix3;
+/// 
+/// let matrix: Matrix3<f64> = rand::random();
+/// assert!((0.0..1.0).contains(&matrix[2][1]));
+/// ```
+impl<T, const C: usize, const R: usize> Distribution<Matrix<T, C, R>> for StandardUniform 
+where 
+    StandardUniform: Distribution<T> 
+{
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Matrix<T, C, R> {
+        Matrix::new(std::array::from_fn(|_| std::array::from_fn(|_| self.sample(rng))))
+    }
+}
+
+/// Samples each value of the matrix in the range of the uniform distribution.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// use rand::distr::{ Distribution, Uniform };
+/// 
+/// let range = Uniform::new_inclusive(0, 9).unwrap();
+/// let matrix: Matrix2<u8> = range.sample(&mut rand::rng());
+/// 
+/// assert!(matrix[1][0] <= 9);
+/// ```
+impl<T: SampleUniform, const C: usize, const R: usize> Distribution<Matrix<T, C, R>> for Uniform<T> {
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Matrix<T, C, R> {
+        Matrix::new(std::array::from_fn(|_| std::array::from_fn(|_| self.sample(rng))))
+    }
+}
+
+macro_rules! impl_random_samplers {
+    ($type:tt) => {
+        /// Implements a uniform sampler of points in the unit circle, for 
+        #[doc = concat!("2d-points of `", stringify!($type), "` values.")]
+        impl Vector2<$type> {
+            /// Returns a random point uniformly distributed inside the circle 
+            /// of radius 1 centered on the origin.
+            /// 
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector2;
+            /// 
+            #[doc = concat!("let point = Vector2::<", stringify!($type), ">::random_in_unit_circle(&mut rand::rng());")]
+            /// assert!(point.length() <= 1.0);
+            /// ```
+            pub fn random_in_unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                // Rejects the points of the square outside of the circle.
+                loop {
+                    let point = Self::new([
+                        rng.random_range(-1.0..=1.0), 
+                        rng.random_range(-1.0..=1.0)
+                    ]);
+
+                    if point.length_squared() <= 1.0 {
+                        return point;
+                    }
+                }
+            }
+        }
+
+        /// Implements a uniform sampler of directions, for vectors 3 of 
+        #[doc = concat!("`", stringify!($type), "` values.")]
+        impl Vector3<$type> {
+            /// Returns a random vector of length 1, uniformly distributed on 
+            /// the sphere of radius 1.
+            /// 
+            /// ## Example
+            /// ```
+            /// use linbra::vector::Vector3;
+            /// 
+            #[doc = concat!("let direction = Vector3::<", stringify!($type), ">::random_unit(&mut rand::rng());")]
+            /// assert!((direction.length() - 1.0).abs() < 1e-6);
+            /// ```
+            pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                // Rejects the points of the cube outside of the ball, and the 
+                // ones too close to the origin to be scaled precisely.
+                loop {
+                    let point = Self::new([
+                        rng.random_range(-1.0..=1.0), 
+                        rng.random_range(-1.0..=1.0), 
+                        rng.random_range(-1.0..=1.0)
+                    ]);
+                    let length_squared = point.length_squared();
+
+                    if length_squared <= 1.0 && length_squared > 1e-6 {
+                        return point * (1.0 / length_squared.sqrt());
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_random_samplers!(f32);
+impl_random_samplers!(f64);
@@ -0,0 +1,120 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Rectangle built from a 2d-point and a 2d-size, making the
+//! [`points`](crate::points) and [`sizes`](crate::sizes) work together.
+//!
+//! Since points and sizes are both 2-vectors, adding a size to a point yields
+//! the opposite corner of the rectangle:
+//! ```
+//! use linbra::vector::Vector2;
+//!
+//! let point = Vector2::at(10, 20);
+//! let size = Vector2::size(30, 40);
+//!
+//! assert_eq!(point + size, Vector2::at(40, 60));
+//! ```
+
+use crate::{
+    points::Point2,
+    vector::Vector2,
+    Num, Zero,
+};
+
+/// Axis-aligned rectangle on a 2d plan, defined by its corner with the
+/// smallest coordinates and its size.
+///
+/// $$
+/// \left[ x, x + w \right] \times \left[ y, y + h \right]
+/// $$
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rect<T> {
+    /// Corner of the rectangle with the smallest coordinates.
+    pub point: Vector2<T>,
+    /// Width and height of the rectangle.
+    pub size: Vector2<T>,
+}
+
+impl<T: Zero + Num + PartialOrd + Copy> Rect<T> {
+    /// Creates a new rectangle from its corner with the smallest coordinates
+    /// and its size.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     rects::Rect,
+    ///     vector::Vector2
+    /// };
+    ///
+    /// let rect = Rect::from_point_size(Vector2::at(10, 20), Vector2::size(30, 40));
+    ///
+    /// assert_eq!(rect.opposite_corner(), Vector2::at(40, 60));
+    /// ```
+    pub fn from_point_size(point: Vector2<T>, size: Vector2<T>) -> Self {
+        Self { point, size }
+    }
+
+    /// Creates a new rectangle from two of its opposite corners, in any
+    /// order.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     rects::Rect,
+    ///     vector::Vector2
+    /// };
+    ///
+    /// let rect = Rect::from_corners(Vector2::at(40, 20), Vector2::at(10, 60));
+    ///
+    /// assert_eq!(rect.point, Vector2::at(10, 20));
+    /// assert_eq!(rect.size, Vector2::size(30, 40));
+    /// ```
+    pub fn from_corners(a: Vector2<T>, b: Vector2<T>) -> Self {
+        let (x_min, x_max) = if a.x() < b.x() { (a.x(), b.x()) } else { (b.x(), a.x()) };
+        let (y_min, y_max) = if a.y() < b.y() { (a.y(), b.y()) } else { (b.y(), a.y()) };
+
+        Self::from_point_size(
+            Vector2::at(x_min, y_min),
+            Vector2::size(x_max - x_min, y_max - y_min)
+        )
+    }
+
+    /// Returns the corner of the rectangle with the greatest coordinates.
+    ///
+    /// ## Formula
+    /// $$
+    /// \begin{pmatrix}
+    ///     x \\\
+    ///     y \\\
+    /// \end{pmatrix} +
+    /// \begin{pmatrix}
+    ///     w \\\
+    ///     h \\\
+    /// \end{pmatrix}
+    /// $$
+    pub fn opposite_corner(&self) -> Vector2<T> {
+        self.point + self.size
+    }
+
+    /// Whether the point is inside the rectangle, edges included.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     rects::Rect,
+    ///     vector::Vector2
+    /// };
+    ///
+    /// let rect = Rect::from_point_size(Vector2::at(0, 0), Vector2::size(10, 10));
+    ///
+    /// assert!(rect.contains(Vector2::at(10, 5)));
+    /// assert!(!rect.contains(Vector2::at(11, 5)));
+    /// ```
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        let corner = self.opposite_corner();
+
+        point.x() >= self.point.x() && point.x() <= corner.x()
+            && point.y() >= self.point.y() && point.y() <= corner.y()
+    }
+}
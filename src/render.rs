@@ -0,0 +1,75 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Rendering of matrices as LaTeX or Markdown source, available with the
+//! `render` feature, to paste computed matrices into notes and documentation.
+
+use std::fmt::{ Display, Write };
+
+use crate::matrix::Matrix;
+
+impl<T: Display, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the rows of the matrix, each value being formatted with
+    /// [`Display`].
+    fn formatted_rows(&self) -> [[String; C]; R] {
+        std::array::from_fn(|row| std::array::from_fn(|column| self[column][row].to_string()))
+    }
+
+    /// Returns the LaTeX source of the matrix, as a `pmatrix` environment
+    /// with a row per line.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     matrix.to_latex(),
+    ///     "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6 \\\\\n\\end{pmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::from("\\begin{pmatrix}\n");
+
+        for row in self.formatted_rows() {
+            writeln!(latex, "{} \\\\", row.join(" & ")).expect("writing to a string");
+        }
+
+        latex.push_str("\\end{pmatrix}");
+        latex
+    }
+
+    /// Returns the Markdown source of a table of the matrix, with a row per
+    /// line below an empty header.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::natural([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.to_markdown_table(), "\
+    /// |   |   |   |
+    /// | --- | --- | --- |
+    /// | 1 | 2 | 3 |
+    /// | 4 | 5 | 6 |
+    /// ");
+    /// ```
+    pub fn to_markdown_table(&self) -> String {
+        let mut table = format!("|{}\n|{}\n", "   |".repeat(C), " --- |".repeat(C));
+
+        for row in self.formatted_rows() {
+            writeln!(table, "| {} |", row.join(" | ")).expect("writing to a string");
+        }
+
+        table
+    }
+}
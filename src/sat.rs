@@ -0,0 +1,211 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Overlap tests between convex 2D polygons with the separating axis theorem
+//! (SAT), giving the minimum translation vector to resolve their collision.
+//!
+//! Two convex polygons are apart when the projections of their vertices onto
+//! one of the normals of their edges do not overlap. Otherwise, the normal of
+//! the least overlap tells how to push them apart the least.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     sat::Polygon2,
+//!     vector::Vector2,
+//! };
+//!
+//! let ground = Polygon2::new(vec![
+//!     Vector2::new([0.0, 0.0]),
+//!     Vector2::new([10.0, 0.0]),
+//!     Vector2::new([10.0, 1.0]),
+//!     Vector2::new([0.0, 1.0]),
+//! ]);
+//! let mut player = Polygon2::new(vec![
+//!     Vector2::new([4.0, 0.75]),
+//!     Vector2::new([5.0, 0.75]),
+//!     Vector2::new([5.0, 2.75]),
+//!     Vector2::new([4.0, 2.75]),
+//! ]);
+//!
+//! if let Some(translation) = player.minimum_translation(&ground) {
+//!     player.translate(translation);
+//! }
+//!
+//! assert_eq!(player.vertices[0], Vector2::new([4.0, 1.0]));
+//! ```
+
+use crate::{
+    vector::Vector2,
+    Float,
+};
+
+/// Convex polygon of vertices given in order, either clockwise or
+/// counterclockwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon2<T> {
+    /// Vertices of the polygon, each one being linked to the next one and the
+    /// last one to the first one.
+    pub vertices: Vec<Vector2<T>>,
+}
+
+impl<T: Float> Polygon2<T> {
+    /// Creates a new convex polygon of the vertices.
+    pub fn new(vertices: Vec<Vector2<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Moves all the vertices of the polygon by the translation.
+    pub fn translate(&mut self, translation: Vector2<T>) {
+        for vertex in &mut self.vertices {
+            *vertex = *vertex + translation;
+        }
+    }
+
+    /// Returns the average of the vertices of the polygon.
+    fn centre(&self) -> Vector2<T> {
+        let mut sum = Vector2::zeroed();
+        let mut count = T::zero();
+
+        for vertex in &self.vertices {
+            sum = sum + *vertex;
+            count += T::one();
+        }
+
+        Vector2::new([sum[0] / count, sum[1] / count])
+    }
+
+    /// Returns the normalized normals of the edges of the polygon, skipping
+    /// the degenerated ones.
+    fn axes(&self) -> impl Iterator<Item = Vector2<T>> + '_ {
+        let count = self.vertices.len();
+
+        (0..count).filter_map(move |n| {
+            let edge = self.vertices[(n + 1) % count] - self.vertices[n];
+            Vector2::new([-edge[1], edge[0]]).normalize().ok()
+        })
+    }
+
+    /// Returns the least and the greatest projections of the vertices onto
+    /// the axis.
+    fn project(&self, axis: Vector2<T>) -> (T, T) {
+        let mut vertices = self.vertices.iter();
+        let first = vertices.next().expect("no vertex in the polygon").dot(axis);
+
+        vertices.fold((first, first), |(min, max), vertex| {
+            let projection = vertex.dot(axis);
+
+            (
+                if projection < min { projection } else { min },
+                if projection > max { projection } else { max },
+            )
+        })
+    }
+
+    /// Returns the translation of the least length to move the polygon by so
+    /// that it stops overlapping the other polygon, or `None` when the
+    /// polygons are apart.
+    ///
+    /// The polygons touching give a zero translation.
+    ///
+    /// ## Formula
+    /// For each normalized normal $n$ of the edges of both polygons, the
+    /// overlap of their projections is
+    /// $$ o_{n} = \min(\max_{a} a \cdot n - \min_{b} b \cdot n, \max_{b} b \cdot n - \min_{a} a \cdot n) $$
+    /// and the translation is $o_{n} n$ for the least $o_{n}$, with $n$
+    /// pointing from the other polygon towards this one.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sat::Polygon2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let triangle = Polygon2::new(vec![
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([4.0, 0.0]),
+    ///     Vector2::new([0.0, 4.0]),
+    /// ]);
+    /// let square = Polygon2::new(vec![
+    ///     Vector2::new([-1.0, 1.0]),
+    ///     Vector2::new([0.5, 1.0]),
+    ///     Vector2::new([0.5, 2.0]),
+    ///     Vector2::new([-1.0, 2.0]),
+    /// ]);
+    ///
+    /// assert_eq!(square.minimum_translation(&triangle), Some(Vector2::new([-0.5, 0.0])));
+    ///
+    /// let far = Polygon2::new(vec![
+    ///     Vector2::new([3.0, 3.0]),
+    ///     Vector2::new([4.0, 3.0]),
+    ///     Vector2::new([4.0, 4.0]),
+    /// ]);
+    ///
+    /// assert_eq!(far.minimum_translation(&triangle), None);
+    /// ```
+    pub fn minimum_translation(&self, other: &Self) -> Option<Vector2<T>> {
+        let mut least: Option<(T, Vector2<T>)> = None;
+
+        for axis in self.axes().chain(other.axes()) {
+            let (self_min, self_max) = self.project(axis);
+            let (other_min, other_max) = other.project(axis);
+
+            let forwards = self_max - other_min;
+            let backwards = other_max - self_min;
+            let overlap = if forwards < backwards { forwards } else { backwards };
+
+            if overlap < T::zero() {
+                // Separating axis.
+                return None;
+            }
+
+            if least.is_none_or(|(least, _)| overlap < least) {
+                let axis = if forwards < backwards {
+                    -axis
+                } else if backwards < forwards {
+                    axis
+                } else if (self.centre() - other.centre()).dot(axis) < T::zero() {
+                    // Both ways are as short, such as for polygons of the
+                    // same projections, so the polygon is pushed away from
+                    // the centre of the other one.
+                    -axis
+                } else {
+                    axis
+                };
+
+                least = Some((overlap, axis));
+            }
+        }
+
+        let (overlap, axis) = least?;
+        Some(axis * overlap)
+    }
+
+    /// Returns whether the two polygons overlap or touch.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sat::Polygon2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let a = Polygon2::new(vec![
+    ///     Vector2::new([0.0, 0.0]),
+    ///     Vector2::new([2.0, 0.0]),
+    ///     Vector2::new([1.0, 2.0]),
+    /// ]);
+    /// let b = Polygon2::new(vec![
+    ///     Vector2::new([1.0, 1.0]),
+    ///     Vector2::new([3.0, 1.0]),
+    ///     Vector2::new([2.0, 3.0]),
+    /// ]);
+    ///
+    /// assert!(a.overlaps(&b));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.minimum_translation(other).is_some()
+    }
+}
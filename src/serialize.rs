@@ -0,0 +1,147 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Implementations of the `serde` traits for vectors and matrices, available 
+//! with the `serde` feature.
+//! 
+//! Vectors and matrices are serialized as flat sequences of values. Matrices 
+//! are flattened column by column, as they are stored. When deserializing, the 
+//! length of the sequence must match the dimensions of the vector or matrix.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+/// Serializes the vector as a sequence of its `N` values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let vector = Vector3::new([1, 2, 3]);
+/// assert_eq!(serde_json::to_string(&vector).unwrap(), "[1,2,3]");
+/// ```
+impl<T: Serialize, const N: usize> Serialize for Vector<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut sequence = serializer.serialize_seq(Some(N))?;
+
+        for n in 0..N {
+            sequence.serialize_element(&self[n])?;
+        }
+
+        sequence.end()
+    }
+}
+
+/// Deserializes the vector from a sequence of exactly `N` values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let vector: Vector3<i32> = serde_json::from_str("[1, 2, 3]").unwrap();
+/// assert_eq!(vector, Vector3::new([1, 2, 3]));
+/// 
+/// assert!(serde_json::from_str::<Vector3<i32>>("[1, 2]").is_err());
+/// assert!(serde_json::from_str::<Vector3<i32>>("[1, 2, 3, 4]").is_err());
+/// ```
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut values = deserialize_flat::<D, T>(deserializer, N)?.into_iter();
+        Ok(Vector::new(std::array::from_fn(|_| values.next().unwrap())))
+    }
+}
+
+/// Serializes the matrix as a sequence of its `C * R` values, column by 
+/// column.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let matrix = Matrix2::new([[1, 2], [3, 4]]);
+/// assert_eq!(serde_json::to_string(&matrix).unwrap(), "[1,2,3,4]");
+/// ```
+impl<T: Serialize, const C: usize, const R: usize> Serialize for Matrix<T, C, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut sequence = serializer.serialize_seq(Some(C * R))?;
+
+        for column in 0..C {
+            for row in 0..R {
+                sequence.serialize_element(&self[column][row])?;
+            }
+        }
+
+        sequence.end()
+    }
+}
+
+/// Deserializes the matrix from a sequence of exactly `C * R` values, column 
+/// by column.
+/// 
+/// ## Example
+/// ```
+/// use linbra::matrix::Matrix2;
+/// 
+/// let matrix: Matrix2<i32> = serde_json::from_str("[1, 2, 3, 4]").unwrap();
+/// assert_eq!(matrix, Matrix2::new([[1, 2], [3, 4]]));
+/// 
+/// assert!(serde_json::from_str::<Matrix2<i32>>("[1, 2, 3]").is_err());
+/// ```
+impl<'de, T: Deserialize<'de>, const C: usize, const R: usize> Deserialize<'de> for Matrix<T, C, R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut values = deserialize_flat::<D, T>(deserializer, C * R)?.into_iter();
+        Ok(Matrix::new(std::array::from_fn(|_| {
+            std::array::from_fn(|_| values.next().unwrap())
+        })))
+    }
+}
+
+/// Deserializes a sequence of exactly `length` values.
+fn deserialize_flat<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D, 
+    length: usize
+) -> Result<Vec<T>, D::Error> {
+    deserializer.deserialize_seq(FlatVisitor { length, marker: PhantomData })
+}
+
+/// Visits a sequence of values, checking its length.
+struct FlatVisitor<T> {
+    /// Expected number of values in the sequence.
+    length: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for FlatVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of {} values", self.length)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut sequence: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(self.length);
+
+        while let Some(value) = sequence.next_element()? {
+            // Too many values, the sequence is not read until its end.
+            if values.len() == self.length {
+                return Err(de::Error::invalid_length(self.length + 1, &self));
+            }
+
+            values.push(value);
+        }
+
+        if values.len() != self.length {
+            return Err(de::Error::invalid_length(values.len(), &self));
+        }
+
+        Ok(values)
+    }
+}
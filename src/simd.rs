@@ -0,0 +1,407 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Operations of the `f32` 4D vectors and 4x4 matrices written with the SIMD
+//! instructions of the target, SSE on x86_64 and NEON on aarch64, for the
+//! per-frame workloads such as skinning.
+//!
+//! The generic operators cannot be specialized for `f32` values, so the SIMD
+//! operations are methods of their own, suffixed by `_simd`. On the other
+//! targets, they fall back to plain arrays and give the same results as the
+//! operators.
+//!
+//! The gains are on the products and the inverse of the matrices, the loops of
+//! the vector operators being already vectorized by the compiler. Run
+//! `cargo bench --features simd` to compare them on the target.
+//!
+//! Every function is inlined into its callers, a call from another crate
+//! costing more than the few instructions it runs.
+
+use crate::Error;
+use crate::matrix::Matrix4;
+use crate::vector::Vector4;
+
+#[cfg(target_arch = "x86_64")]
+mod lanes {
+    use std::arch::x86_64::*;
+
+    // SAFETY of the intrinsics: SSE is part of the baseline of x86_64, so it
+    // is available on every processor of this target.
+
+    /// Four `f32` values held in a SSE register.
+    #[derive(Copy, Clone)]
+    pub(super) struct Lanes(__m128);
+
+    impl Lanes {
+        #[inline]
+        pub(super) fn new([x, y, z, w]: [f32; 4]) -> Self {
+            Self(unsafe { _mm_set_ps(w, z, y, x) })
+        }
+
+        #[inline]
+        pub(super) fn splat(value: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(value) })
+        }
+
+        #[inline]
+        pub(super) fn to_array(self) -> [f32; 4] {
+            let mut values = [0.0; 4];
+            // The array has room for the four unaligned values.
+            unsafe { _mm_storeu_ps(values.as_mut_ptr(), self.0) };
+            values
+        }
+
+        #[inline]
+        pub(super) fn add(self, other: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, other.0) })
+        }
+
+        #[inline]
+        pub(super) fn sub(self, other: Self) -> Self {
+            Self(unsafe { _mm_sub_ps(self.0, other.0) })
+        }
+
+        #[inline]
+        pub(super) fn mul(self, other: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, other.0) })
+        }
+
+        /// Returns the lanes `(y, z, x, w)`.
+        #[inline]
+        pub(super) fn yzx(self) -> Self {
+            Self(unsafe { _mm_shuffle_ps::<0b11_00_10_01>(self.0, self.0) })
+        }
+
+        #[inline]
+        pub(super) fn sum(self) -> f32 {
+            unsafe {
+                // (x + z, y + w, ..) then (x + z) + (y + w).
+                let pairs = _mm_add_ps(self.0, _mm_movehl_ps(self.0, self.0));
+                _mm_cvtss_f32(_mm_add_ss(pairs, _mm_shuffle_ps::<0b01>(pairs, pairs)))
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod lanes {
+    use std::arch::aarch64::*;
+
+    // SAFETY of the intrinsics: NEON is part of the baseline of aarch64, so
+    // it is available on every processor of this target.
+
+    /// Four `f32` values held in a NEON register.
+    #[derive(Copy, Clone)]
+    pub(super) struct Lanes(float32x4_t);
+
+    impl Lanes {
+        #[inline]
+        pub(super) fn new(values: [f32; 4]) -> Self {
+            // The array holds the four values to load.
+            Self(unsafe { vld1q_f32(values.as_ptr()) })
+        }
+
+        #[inline]
+        pub(super) fn splat(value: f32) -> Self {
+            Self(unsafe { vdupq_n_f32(value) })
+        }
+
+        #[inline]
+        pub(super) fn to_array(self) -> [f32; 4] {
+            let mut values = [0.0; 4];
+            // The array has room for the four values.
+            unsafe { vst1q_f32(values.as_mut_ptr(), self.0) };
+            values
+        }
+
+        #[inline]
+        pub(super) fn add(self, other: Self) -> Self {
+            Self(unsafe { vaddq_f32(self.0, other.0) })
+        }
+
+        #[inline]
+        pub(super) fn sub(self, other: Self) -> Self {
+            Self(unsafe { vsubq_f32(self.0, other.0) })
+        }
+
+        #[inline]
+        pub(super) fn mul(self, other: Self) -> Self {
+            Self(unsafe { vmulq_f32(self.0, other.0) })
+        }
+
+        /// Returns the lanes `(y, z, x, w)`.
+        #[inline]
+        pub(super) fn yzx(self) -> Self {
+            let [x, y, z, w] = self.to_array();
+            Self::new([y, z, x, w])
+        }
+
+        #[inline]
+        pub(super) fn sum(self) -> f32 {
+            unsafe { vaddvq_f32(self.0) }
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod lanes {
+    /// Four `f32` values, without SIMD instructions on this target.
+    #[derive(Copy, Clone)]
+    pub(super) struct Lanes([f32; 4]);
+
+    impl Lanes {
+        #[inline]
+        pub(super) fn new(values: [f32; 4]) -> Self {
+            Self(values)
+        }
+
+        #[inline]
+        pub(super) fn splat(value: f32) -> Self {
+            Self([value; 4])
+        }
+
+        #[inline]
+        pub(super) fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+
+        #[inline]
+        pub(super) fn add(self, other: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] + other.0[i]))
+        }
+
+        #[inline]
+        pub(super) fn sub(self, other: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] - other.0[i]))
+        }
+
+        #[inline]
+        pub(super) fn mul(self, other: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] * other.0[i]))
+        }
+
+        /// Returns the lanes `(y, z, x, w)`.
+        #[inline]
+        pub(super) fn yzx(self) -> Self {
+            let [x, y, z, w] = self.0;
+            Self([y, z, x, w])
+        }
+
+        #[inline]
+        pub(super) fn sum(self) -> f32 {
+            (self.0[0] + self.0[2]) + (self.0[1] + self.0[3])
+        }
+    }
+}
+
+use lanes::Lanes;
+
+impl Lanes {
+    /// Returns the cross product of the `xyz` lanes, the `w` lane being zero.
+    #[inline]
+    fn cross(self, other: Self) -> Self {
+        self.mul(other.yzx()).sub(self.yzx().mul(other)).yzx()
+    }
+
+    /// Returns the lanes with the `w` one replaced by the value.
+    #[inline]
+    fn with_w(self, value: f32) -> [f32; 4] {
+        let [x, y, z, _] = self.to_array();
+        [x, y, z, value]
+    }
+}
+
+/// Returns the values of the vector as lanes.
+#[inline]
+fn lanes(vector: Vector4<f32>) -> Lanes {
+    Lanes::new([vector[0], vector[1], vector[2], vector[3]])
+}
+
+/// Returns the columns of the matrix as lanes.
+#[inline]
+fn columns(matrix: Matrix4<f32>) -> [Lanes; 4] {
+    matrix.to_cols_array_2d().map(Lanes::new)
+}
+
+/// Returns the combination of the columns weighted by the values.
+#[inline]
+fn combine(columns: &[Lanes; 4], [x, y, z, w]: [f32; 4]) -> Lanes {
+    columns[0].mul(Lanes::splat(x))
+        .add(columns[1].mul(Lanes::splat(y)))
+        .add(columns[2].mul(Lanes::splat(z)))
+        .add(columns[3].mul(Lanes::splat(w)))
+}
+
+impl Vector4<f32> {
+    /// Returns the sum of the vectors, as the `+` operator.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    ///
+    /// let a = Vector4::new([1.0, 2.0, 3.0, 4.0]);
+    /// let b = Vector4::new([4.0, 3.0, 2.0, 1.0]);
+    ///
+    /// assert_eq!(a.add_simd(b), a + b);
+    /// ```
+    #[inline]
+    pub fn add_simd(self, other: Self) -> Self {
+        Vector4::new(lanes(self).add(lanes(other)).to_array())
+    }
+
+    /// Returns the product of the vectors component by component, as the `*`
+    /// operator.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    ///
+    /// let a = Vector4::new([1.0, 2.0, 3.0, 4.0]);
+    /// let b = Vector4::new([4.0, 3.0, 2.0, 1.0]);
+    ///
+    /// assert_eq!(a.mul_simd(b), a * b);
+    /// ```
+    #[inline]
+    pub fn mul_simd(self, other: Self) -> Self {
+        Vector4::new(lanes(self).mul(lanes(other)).to_array())
+    }
+
+    /// Returns the dot product of the vectors, as [`Vector::dot`](crate::vector::Vector::dot).
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    ///
+    /// let a = Vector4::new([1.0, 2.0, 3.0, 4.0]);
+    /// let b = Vector4::new([4.0, 3.0, 2.0, 1.0]);
+    ///
+    /// assert_eq!(a.dot_simd(b), 20.0);
+    /// ```
+    #[inline]
+    pub fn dot_simd(self, other: Self) -> f32 {
+        lanes(self).mul(lanes(other)).sum()
+    }
+}
+
+impl Matrix4<f32> {
+    /// Returns the product of the matrices, as the `*` operator.
+    ///
+    /// Each column of the product is a combination of the columns of this
+    /// matrix, weighted by the values of the column of the other one.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix4;
+    ///
+    /// let a = Matrix4::<f32>::natural([
+    ///     [1.0, 2.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 3.0, 0.0],
+    ///     [2.0, 0.0, 1.0, 4.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// let b = Matrix4::<f32>::natural([
+    ///     [0.0, 1.0, 2.0, 3.0],
+    ///     [1.0, 0.0, 1.0, 0.0],
+    ///     [2.0, 1.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// assert_eq!(a.mul_simd(b), a * b);
+    /// ```
+    #[inline]
+    pub fn mul_simd(self, other: Self) -> Self {
+        let columns = columns(self);
+        Matrix4::new(other.to_cols_array_2d().map(|column| combine(&columns, column).to_array()))
+    }
+
+    /// Returns the vector transformed by the matrix, as the `*` operator.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix4,
+    ///     vector::Vector4,
+    /// };
+    ///
+    /// let translation = Matrix4::<f32>::natural([
+    ///     [1.0, 0.0, 0.0, 5.0],
+    ///     [0.0, 1.0, 0.0, 6.0],
+    ///     [0.0, 0.0, 1.0, 7.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// let point = Vector4::new([1.0, 2.0, 3.0, 1.0]);
+    ///
+    /// assert_eq!(translation.transform_simd(point), Vector4::new([6.0, 8.0, 10.0, 1.0]));
+    /// ```
+    #[inline]
+    pub fn transform_simd(self, vector: Vector4<f32>) -> Vector4<f32> {
+        Vector4::new(combine(&columns(self), [vector[0], vector[1], vector[2], vector[3]]).to_array())
+    }
+
+    /// Returns the inverse of the matrix, or [`Error::SingularMatrix`] when
+    /// the matrix is not invertible, as [`Matrix4::inverse`].
+    ///
+    /// The rows of the inverse are computed from the cross products of the
+    /// `xyz` parts of the columns $a$, $b$, $c$ and $d$ of the matrix.
+    ///
+    /// ## Formula
+    /// $$
+    /// s = a \times b, \quad t = c \times d, \quad
+    /// u = a \, b_{w} - b \, a_{w}, \quad v = c \, d_{w} - d \, c_{w} \\\
+    /// A^{-1} = \frac{1}{s \cdot v + t \cdot u}
+    /// \begin{pmatrix}
+    ///     b \times v + t \, b_{w} & -b \cdot t \\\
+    ///     v \times a - t \, a_{w} & a \cdot t \\\
+    ///     d \times u + s \, d_{w} & -d \cdot s \\\
+    ///     u \times c - s \, c_{w} & c \cdot s \\\
+    /// \end{pmatrix}
+    /// $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix4,
+    ///     Error,
+    /// };
+    ///
+    /// let scale = Matrix4::<f32>::natural([
+    ///     [2.0, 0.0, 0.0, 4.0],
+    ///     [0.0, 4.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 0.5, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// assert_eq!(scale.inverse_simd(), scale.inverse());
+    /// assert_eq!(Matrix4::<f32>::natural([[1.0; 4]; 4]).inverse_simd(), Err(Error::SingularMatrix));
+    /// ```
+    #[inline]
+    pub fn inverse_simd(self) -> Result<Self, Error> {
+        let [a, b, c, d] = columns(self);
+        let [aw, bw, cw, dw] = self.to_cols_array_2d().map(|column| column[3]);
+
+        let s = a.cross(b);
+        let t = c.cross(d);
+        // The `w` lanes cancel out, being the same product twice.
+        let u = a.mul(Lanes::splat(bw)).sub(b.mul(Lanes::splat(aw)));
+        let v = c.mul(Lanes::splat(dw)).sub(d.mul(Lanes::splat(cw)));
+
+        let determinant = s.mul(v).sum() + t.mul(u).sum();
+
+        if determinant == 0.0 {
+            return Err(Error::SingularMatrix);
+        }
+
+        let inverse = Lanes::splat(1.0 / determinant);
+        let (s, t, u, v) = (s.mul(inverse), t.mul(inverse), u.mul(inverse), v.mul(inverse));
+
+        // Dot products of the `xyz` lanes, the `w` lanes of `s` and `t` being
+        // zero.
+        Ok(Matrix4::natural([
+            b.cross(v).add(t.mul(Lanes::splat(bw))).with_w(-b.mul(t).sum()),
+            v.cross(a).sub(t.mul(Lanes::splat(aw))).with_w(a.mul(t).sum()),
+            d.cross(u).add(s.mul(Lanes::splat(dw))).with_w(-d.mul(s).sum()),
+            u.cross(c).sub(s.mul(Lanes::splat(cw))).with_w(c.mul(s).sum()),
+        ]))
+    }
+}
@@ -2,14 +2,19 @@
 // Under the MIT License
 // Copyright (c) 2023 Antonin Hérault
 
-//! Traits to get width, height and depths values of a 2d-objects or 3d-objects, 
-//! and constructors for these sizes.
+//! Traits to get width, height and depths values of a 2d-objects or 3d-objects,
+//! constructors for these sizes, and a strongly-typed 2d-size wrapper.
 
 use std::ops;
-use crate::vector::{Vector2, Vector3};
+use crate::{
+    vector::{Vector2, Vector3},
+    Num,
+};
 
 /// Implements functions to retrieve the width and the height of the size 
-/// vector.
+/// vector, and calculations on 2d-sizes.
+/// 
+/// The scalings are only available to the sizes that can be indexed mutably.
 pub trait Size2<T: Copy>: ops::Index<usize, Output = T> {
     /// Returns the width.
     fn w(&self) -> T {
@@ -20,6 +25,156 @@ pub trait Size2<T: Copy>: ops::Index<usize, Output = T> {
     fn h(&self) -> T {
         self[1]
     }
+
+    /// Returns the area covered by the size.
+    /// 
+    /// ## Formula
+    /// $$ A = w \times h $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sizes::Size2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// assert_eq!(Vector2::size(10, 5).area(), 50);
+    /// ```
+    fn area(&self) -> T where T: Num {
+        self.w() * self.h()
+    }
+
+    /// Returns the aspect ratio of the size, being the width divided by the 
+    /// height.
+    /// 
+    /// ## Formula
+    /// $$ r = \frac{w}{h} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sizes::Size2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// assert_eq!(Vector2::size(1920.0, 1080.0).aspect_ratio(), 16.0 / 9.0);
+    /// ```
+    fn aspect_ratio(&self) -> T where T: ops::Div<Output = T> {
+        self.w() / self.h()
+    }
+
+    /// Returns the biggest size with the same aspect ratio as this size, 
+    /// fitting entirely inside `other`.
+    /// 
+    /// Used to letterbox a content inside a screen for example.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// s = \min \left( \frac{w_{o}}{w}, \frac{h_{o}}{h} \right), \quad 
+    /// \begin{pmatrix} 
+    ///     w' \\\ 
+    ///     h' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     w \times s \\\ 
+    ///     h \times s \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Panics
+    /// With integer values, panics when the height of this size is zero while 
+    /// its width or the height of `other` is zero too, the height being 
+    /// divided by. The floating-point values give NaN dimensions instead.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sizes::Size2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// let content = Vector2::size(400.0, 300.0);
+    /// let screen = Vector2::size(1920.0, 1080.0);
+    /// 
+    /// assert_eq!(content.scaled_to_fit(screen), Vector2::size(1440.0, 1080.0));
+    /// ```
+    fn scaled_to_fit(self, other: Self) -> Self 
+    where 
+        Self: Sized + ops::IndexMut<usize>, 
+        T: Num + ops::Div<Output = T> + PartialOrd
+    {
+        // Compares the aspect ratios without dividing by the sizes.
+        if self.w() * other.h() > other.w() * self.h() {
+            // Limited by the width of `other`.
+            let h = self.h() * other.w() / self.w();
+            resized(self, other.w(), h)
+        } else {
+            // Limited by the height of `other`.
+            let w = self.w() * other.h() / self.h();
+            resized(self, w, other.h())
+        }
+    }
+
+    /// Returns the smallest size with the same aspect ratio as this size, 
+    /// covering entirely `other`.
+    /// 
+    /// Used to crop a content to fill a screen for example.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// s = \max \left( \frac{w_{o}}{w}, \frac{h_{o}}{h} \right), \quad 
+    /// \begin{pmatrix} 
+    ///     w' \\\ 
+    ///     h' \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     w \times s \\\ 
+    ///     h \times s \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Panics
+    /// With integer values, panics when the width of this size is zero, or 
+    /// when its height is zero while its width and the height of `other` are 
+    /// not, the width or the height being divided by. The floating-point 
+    /// values give infinite or NaN dimensions instead.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sizes::Size2,
+    ///     vector::Vector2
+    /// };
+    /// 
+    /// let content = Vector2::size(400.0, 300.0);
+    /// let screen = Vector2::size(1920.0, 1080.0);
+    /// 
+    /// assert_eq!(content.scaled_to_fill(screen), Vector2::size(1920.0, 1440.0));
+    /// ```
+    fn scaled_to_fill(self, other: Self) -> Self 
+    where 
+        Self: Sized + ops::IndexMut<usize>, 
+        T: Num + ops::Div<Output = T> + PartialOrd
+    {
+        // Compares the aspect ratios without dividing by the sizes.
+        if self.w() * other.h() > other.w() * self.h() {
+            // Limited by the height of `other`.
+            let w = self.w() * other.h() / self.h();
+            resized(self, w, other.h())
+        } else {
+            // Limited by the width of `other`.
+            let h = self.h() * other.w() / self.w();
+            resized(self, other.w(), h)
+        }
+    }
+}
+
+/// Returns a copy of the size with another width and height, keeping the other 
+/// values untouched.
+fn resized<T: Copy, S: Size2<T> + ops::IndexMut<usize>>(mut size: S, w: T, h: T) -> S {
+    size[0] = w;
+    size[1] = h;
+    size
 }
 
 /// Implements the [`Size2`] trait for vectors 2.
@@ -49,21 +204,43 @@ impl<T: Copy> Vector2<T> {
     }
 }
 
-/// Implements a function to retrieve the depth of the size vector.
+/// Implements a function to retrieve the depth of the size vector, and 
+/// calculations on 3d-sizes.
 pub trait Size3<T: Copy>: Size2<T> {
     /// Returns the depth.
     fn d(&self) -> T {
-        self[1]
+        self[2]
+    }
+
+    /// Returns the volume covered by the size.
+    /// 
+    /// ## Formula
+    /// $$ V = w \times h \times d $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     sizes::Size3,
+    ///     vector::Vector3
+    /// };
+    /// 
+    /// assert_eq!(Vector3::size(10, 5, 2).volume(), 100);
+    /// ```
+    fn volume(&self) -> T where T: Num {
+        self.area() * self.d()
     }
 }
 
+/// Implements the [`Size3`] trait for vectors 3.
+impl<T: Copy> Size3<T> for Vector3<T> {}
+
 /// Implements a constructor for 3d-sizes.
 /// 
 /// ## Example
 /// ```
 /// use linbra::{
 ///     sizes::{ Size2, Size3 },
-///     vector::Vector2
+///     vector::Vector3
 /// };
 /// 
 /// let size = Vector3::size(10, 5, 2);
@@ -78,3 +255,62 @@ impl<T: Copy> Vector3<T> {
         [w, h, d].into()
     }
 }
+
+/// Width and height of a 2d-object, wrapping the [`Vector2`] of these two 
+/// values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::{
+///     sizes::{ Size2, Size2D },
+///     vector::Vector2
+/// };
+/// 
+/// let size = Size2D::new(10, 5);
+/// assert_eq!(size.area(), 50);
+/// 
+/// let vector: Vector2<i32> = size.into();
+/// assert_eq!(vector, Vector2::new([10, 5]));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Size2D<T>(Vector2<T>);
+
+impl<T> Size2D<T> {
+    /// Creates a new strongly-typed 2d-size.
+    pub fn new(w: T, h: T) -> Self {
+        Self([w, h].into())
+    }
+}
+
+/// Wraps a vector into a [`Size2D`].
+impl<T> From<Vector2<T>> for Size2D<T> {
+    fn from(value: Vector2<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// Unwraps the vector of a [`Size2D`].
+impl<T> From<Size2D<T>> for Vector2<T> {
+    fn from(value: Size2D<T>) -> Self {
+        value.0
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector.
+impl<T> ops::Index<usize> for Size2D<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+/// Returns the value at index `n` in the underlying vector, as mutable.
+impl<T> ops::IndexMut<usize> for Size2D<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Implements the [`Size2`] trait for strongly-typed 2d-sizes.
+impl<T: Copy> Size2<T> for Size2D<T> {}
@@ -0,0 +1,232 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Sparse matrices, storing only their non-zero values, for large systems such
+//! as graph Laplacians or constraint systems.
+//!
+//! A sparse matrix is built from triplets `(row, column, value)`, also known
+//! as the coordinate (COO) format, and stored in the compressed sparse row
+//! (CSR) format:
+//! - the values, row by row,
+//! - the column of each value,
+//! - the offsets of the first value of each row in the values.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     sparse::CsrMatrix,
+//!     vector::DVector,
+//! };
+//!
+//! // Laplacian of the path graph 0 - 1 - 2.
+//! let laplacian = CsrMatrix::from_triplets(3, 3, vec![
+//!     (0, 0, 1), (0, 1, -1),
+//!     (1, 0, -1), (1, 1, 2), (1, 2, -1),
+//!     (2, 1, -1), (2, 2, 1),
+//! ]);
+//!
+//! assert_eq!(laplacian.non_zeros(), 7);
+//! assert_eq!(&laplacian * DVector::new(vec![1, 1, 1]), DVector::zeroed(3));
+//! ```
+
+use std::ops;
+
+use crate::{
+    matrix::{DMatrix, Matrix},
+    vector::DVector,
+    Num, Zero,
+};
+
+/// Sparse matrix in the compressed sparse row format.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CsrMatrix<T> {
+    columns: usize,
+    rows: usize,
+    /// Offsets of the first value of each row, followed by the number of
+    /// values.
+    row_offsets: Vec<usize>,
+    /// Column of each value.
+    column_indices: Vec<usize>,
+    /// Non-zero values, row by row.
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Returns the number of columns of the matrix.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows of the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of values stored in the matrix.
+    pub fn non_zeros(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the value stored at the given row and column, if any.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::sparse::CsrMatrix;
+    ///
+    /// let matrix = CsrMatrix::from_triplets(4, 2, vec![(1, 3, 5.0)]);
+    ///
+    /// assert_eq!(matrix.get(1, 3), Some(&5.0));
+    /// assert_eq!(matrix.get(0, 3), None);
+    /// ```
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+
+        self.column_indices[range.clone()]
+            .binary_search(&column)
+            .ok()
+            .map(|index| &self.values[range.start + index])
+    }
+
+    /// Returns an iterator over the stored values of the row, with their
+    /// columns.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+
+        self.column_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.values[range])
+    }
+}
+
+impl<T: Zero + Num> CsrMatrix<T> {
+    /// Creates a sparse matrix from triplets `(row, column, value)`, in any
+    /// order.
+    ///
+    /// The values of the triplets sharing the same position are summed.
+    /// Panics when a position is outside of the matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::sparse::CsrMatrix;
+    ///
+    /// let matrix = CsrMatrix::from_triplets(2, 2, vec![
+    ///     (1, 0, 3),
+    ///     (0, 1, 2),
+    ///     (1, 0, 4),
+    /// ]);
+    ///
+    /// assert_eq!(matrix.non_zeros(), 2);
+    /// assert_eq!(matrix.get(1, 0), Some(&7));
+    /// ```
+    pub fn from_triplets(columns: usize, rows: usize, triplets: Vec<(usize, usize, T)>) -> Self {
+        let mut triplets = triplets;
+        triplets.sort_by_key(|&(row, column, _)| (row, column));
+
+        let mut row_offsets = vec![0; rows + 1];
+        let mut column_indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+
+        for (row, column, value) in triplets {
+            assert!(row < rows && column < columns, "triplet outside of the matrix");
+
+            if last == Some((row, column)) {
+                if let Some(sum) = values.last_mut() {
+                    *sum += value;
+                }
+                continue;
+            }
+
+            row_offsets[row + 1] += 1;
+            column_indices.push(column);
+            values.push(value);
+            last = Some((row, column));
+        }
+
+        // Turns the numbers of values per row into offsets.
+        for row in 0..rows {
+            row_offsets[row + 1] += row_offsets[row];
+        }
+
+        Self { columns, rows, row_offsets, column_indices, values }
+    }
+
+    /// Returns the dense version of the matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::DMatrix,
+    ///     sparse::CsrMatrix,
+    /// };
+    ///
+    /// let matrix = CsrMatrix::from_triplets(2, 2, vec![(0, 1, 2), (1, 0, 3)]);
+    ///
+    /// assert_eq!(matrix.to_dense(), DMatrix::natural(vec![
+    ///     vec![0, 2],
+    ///     vec![3, 0],
+    /// ]));
+    /// ```
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut dense = DMatrix::zeroed(self.columns, self.rows);
+
+        for row in 0..self.rows {
+            for (column, value) in self.row(row) {
+                dense[column][row] = value.clone();
+            }
+        }
+
+        dense
+    }
+
+    /// Returns the dense version of the matrix, as a fixed-size matrix.
+    ///
+    /// Panics when the dimensions are not the ones of the sparse matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     sparse::CsrMatrix,
+    /// };
+    ///
+    /// let matrix = CsrMatrix::from_triplets(2, 2, vec![(0, 1, 2), (1, 0, 3)]);
+    ///
+    /// assert_eq!(matrix.to_matrix(), Matrix2::natural([
+    ///     [0, 2],
+    ///     [3, 0],
+    /// ]));
+    /// ```
+    pub fn to_matrix<const C: usize, const R: usize>(&self) -> Matrix<T, C, R> {
+        assert!(self.columns == C && self.rows == R, "wrong dimensions for the matrix");
+
+        let dense = self.to_dense();
+        Matrix::new(std::array::from_fn(|column| {
+            std::array::from_fn(|row| dense[column][row].clone())
+        }))
+    }
+}
+
+/// Implementation for the product of a sparse matrix and a dense vector,
+/// only visiting the stored values.
+///
+/// Panics when the length of the vector is not the number of columns.
+impl<T: Zero + Num> ops::Mul<DVector<T>> for &CsrMatrix<T> {
+    type Output = DVector<T>;
+
+    fn mul(self, rhs: DVector<T>) -> Self::Output {
+        assert_eq!(self.columns, rhs.len(), "matrix and vector of incompatible dimensions");
+
+        let mut output = DVector::zeroed(self.rows);
+
+        for row in 0..self.rows {
+            for (column, value) in self.row(row) {
+                output[row] += value.clone() * rhs[column].clone();
+            }
+        }
+
+        output
+    }
+}
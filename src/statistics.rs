@@ -0,0 +1,203 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Descriptive statistics over the components of float vectors and the values
+//! of float matrices.
+//!
+//! The variance is the population one, dividing by the number of values. The 
+//! same goes for the covariance matrix of a set of points.
+
+use crate::{ matrix::Matrix, vector::Vector, Float };
+
+/// Returns the mean of the values, dividing their sum by `count`.
+fn mean<T: Float>(values: &[T], count: usize) -> T {
+    let mut sum = T::zero();
+    for value in values {
+        sum += *value;
+    }
+
+    sum / T::from_usize(count)
+}
+
+/// Returns the population variance of the values around their `mean`.
+fn variance<T: Float>(values: &[T], mean: T) -> T {
+    let mut sum = T::zero();
+    for value in values {
+        sum += (*value - mean) * (*value - mean);
+    }
+
+    sum / T::from_usize(values.len())
+}
+
+/// Implements the statistics over the components of vectors of [`Float`] 
+/// values.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the mean of the components.
+    ///
+    /// ## Formula
+    /// $$ \mu = \frac{1}{n} \sum_{i=1}^{n} a_{i} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    ///
+    /// let vector = Vector4::<f64>::new([2.0, 4.0, 4.0, 6.0]);
+    /// assert_eq!(vector.mean(), 4.0);
+    /// ```
+    pub fn mean(&self) -> T {
+        mean(self.values(), N)
+    }
+
+    /// Returns the population variance of the components.
+    ///
+    /// ## Formula
+    /// $$ \sigma^2 = \frac{1}{n} \sum_{i=1}^{n} (a_{i} - \mu)^2 $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector4;
+    ///
+    /// let vector = Vector4::<f32>::new([2.0, 4.0, 4.0, 6.0]);
+    /// assert_eq!(vector.variance(), 2.0);
+    /// ```
+    pub fn variance(&self) -> T {
+        variance(self.values(), self.mean())
+    }
+
+    /// Returns the population standard deviation of the components.
+    ///
+    /// ## Formula
+    /// $$ \sigma = \sqrt{\sigma^2} $$
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt()
+    }
+
+    /// Returns the centroid of the points, being their mean.
+    ///
+    /// The values are `NaN` when there is no point.
+    ///
+    /// ## Formula
+    /// $$ \bar{p} = \frac{1}{m} \sum_{k=1}^{m} p_{k} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let points = [Vector2::<f64>::new([0.0, 0.0]), Vector2::new([4.0, 2.0])];
+    /// assert_eq!(Vector2::centroid(&points), Vector2::new([2.0, 1.0]));
+    /// ```
+    pub fn centroid(points: &[Self]) -> Self {
+        let mut sum = Self::zeroed();
+
+        for point in points {
+            sum = sum + *point;
+        }
+
+        sum * (T::one() / T::from_usize(points.len()))
+    }
+
+    /// Returns the points translated so that their centroid is the origin.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let points = [Vector2::<f64>::new([0.0, 0.0]), Vector2::new([4.0, 2.0])];
+    /// assert_eq!(
+    ///     Vector2::centered(&points),
+    ///     vec![Vector2::new([-2.0, -1.0]), Vector2::new([2.0, 1.0])],
+    /// );
+    /// ```
+    pub fn centered(points: &[Self]) -> Vec<Self> {
+        let centroid = Self::centroid(points);
+        points.iter().map(|point| *point - centroid).collect()
+    }
+}
+
+/// Implements the statistics over the values of matrices of [`Float`] values.
+impl<T: Float, const C: usize, const R: usize> Matrix<T, C, R> {
+    /// Returns the mean of the values.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    ///
+    /// let matrix = Matrix2::<f64>::new([[1.0, 2.0], [3.0, 6.0]]);
+    /// assert_eq!(matrix.mean(), 3.0);
+    /// ```
+    pub fn mean(&self) -> T {
+        mean(self.values(), C * R)
+    }
+
+    /// Returns the population variance of the values.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::matrix::Matrix2;
+    ///
+    /// let matrix = Matrix2::<f32>::new([[1.0, 2.0], [3.0, 6.0]]);
+    /// assert_eq!(matrix.variance(), 3.5);
+    /// ```
+    pub fn variance(&self) -> T {
+        variance(self.values(), self.mean())
+    }
+
+    /// Returns the population standard deviation of the values.
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt()
+    }
+}
+
+/// Implements the covariance matrix of points of [`Float`] values.
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Returns the covariance matrix of the points, whose value at the row `i` 
+    /// and the column `j` is the covariance between their components `i` and 
+    /// `j`.
+    ///
+    /// Its eigenvectors are the principal axes of the points, as used to fit 
+    /// oriented bounding boxes.
+    ///
+    /// ## Formula
+    /// $$ 
+    /// \Sigma = \frac{1}{m} \sum_{k=1}^{m} (p_{k} - \bar{p})(p_{k} - \bar{p})^T 
+    /// $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     vector::Vector2,
+    /// };
+    ///
+    /// let points = [
+    ///     Vector2::<f64>::new([-1.0, -2.0]),
+    ///     Vector2::new([1.0, 2.0]),
+    /// ];
+    ///
+    /// assert_eq!(Matrix2::covariance(&points), Matrix2::natural([
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0],
+    /// ]));
+    /// ```
+    pub fn covariance(points: &[Vector<T, N>]) -> Self {
+        let mut covariance = Self::new([[T::zero(); N]; N]);
+
+        for point in Vector::<T, N>::centered(points) {
+            for column in 0..N {
+                for row in 0..N {
+                    covariance[column][row] += point[row] * point[column];
+                }
+            }
+        }
+
+        let count = T::from_usize(points.len());
+        for column in 0..N {
+            for row in 0..N {
+                covariance[column][row] = covariance[column][row] / count;
+            }
+        }
+
+        covariance
+    }
+}
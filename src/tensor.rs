@@ -0,0 +1,194 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Rank-3 tensor, being a 3D grid of values such as voxel weights or 3D
+//! lookup tables.
+//!
+//! ## Example
+//! ```
+//! use linbra::{
+//!     matrix::Matrix2,
+//!     tensor::Tensor3,
+//! };
+//!
+//! let mut weights = Tensor3::<f32, 2, 2, 2>::splat(1.0);
+//! weights[(1, 0, 1)] = 3.0;
+//!
+//! let doubled = weights * 2.0;
+//!
+//! assert_eq!(doubled[(1, 0, 1)], 6.0);
+//! assert_eq!(doubled.layer(0), Matrix2::splat(2.0));
+//! ```
+
+use std::ops;
+
+use crate::{ Num, Zero };
+use crate::matrix::Matrix;
+
+/// Rank-3 tensor of `X` layers of `Y` columns of `Z` values.
+///
+/// It has the same memory layout as an array `[[[T; Z]; Y]; X]`, each layer
+/// being stored as a [`Matrix<T, Y, Z>`](Matrix).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Tensor3<T, const X: usize, const Y: usize, const Z: usize> {
+    data: [[[T; Z]; Y]; X]
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize> Tensor3<T, X, Y, Z> {
+    /// Creates a new tensor from its values, layer by layer.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::tensor::Tensor3;
+    ///
+    /// let tensor = Tensor3::new([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+    /// assert_eq!(tensor[(1, 0, 1)], 6);
+    /// ```
+    pub fn new(data: [[[T; Z]; Y]; X]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the values of the tensor, layer by layer.
+    pub fn to_array(self) -> [[[T; Z]; Y]; X] {
+        self.data
+    }
+
+    /// Returns the values of the tensor as a flat slice, layer by layer.
+    pub(crate) fn values(&self) -> &[T] {
+        self.data.as_flattened().as_flattened()
+    }
+
+    /// Returns the values of the tensor as a flat slice, layer by layer, as
+    /// mutable.
+    pub(crate) fn values_mut(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut().as_flattened_mut()
+    }
+}
+
+impl<T: Zero, const X: usize, const Y: usize, const Z: usize> Tensor3<T, X, Y, Z> {
+    /// Creates a new tensor filled with zeros.
+    pub fn zeroed() -> Self {
+        Self::splat(T::zero())
+    }
+}
+
+impl<T: Clone, const X: usize, const Y: usize, const Z: usize> Tensor3<T, X, Y, Z> {
+    /// Creates a new tensor with all its values set to `value`.
+    pub fn splat(value: T) -> Self {
+        Self {
+            data: std::array::from_fn(|_| {
+                std::array::from_fn(|_| std::array::from_fn(|_| value.clone()))
+            })
+        }
+    }
+
+    /// Returns the layer at index `x`, as a matrix of `Y` columns and `Z`
+    /// rows.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     tensor::Tensor3,
+    /// };
+    ///
+    /// let tensor = Tensor3::new([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+    /// assert_eq!(tensor.layer(1), Matrix2::new([[5, 6], [7, 8]]));
+    /// ```
+    pub fn layer(&self, x: usize) -> Matrix<T, Y, Z> {
+        Matrix::new(self.data[x].clone())
+    }
+
+    /// Replaces the layer at index `x` by the values of the matrix.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     matrix::Matrix2,
+    ///     tensor::Tensor3,
+    /// };
+    ///
+    /// let mut tensor = Tensor3::<i32, 2, 2, 2>::zeroed();
+    /// tensor.set_layer(1, Matrix2::splat(9));
+    ///
+    /// assert_eq!(tensor[(1, 1, 0)], 9);
+    /// assert_eq!(tensor[(0, 1, 0)], 0);
+    /// ```
+    pub fn set_layer(&mut self, x: usize, layer: Matrix<T, Y, Z>) {
+        self.data[x] = layer.to_cols_array_2d();
+    }
+}
+
+/// Returns the value at the position `(x, y, z)` in the tensor.
+impl<T, const X: usize, const Y: usize, const Z: usize> ops::Index<(usize, usize, usize)>
+    for Tensor3<T, X, Y, Z>
+{
+    type Output = T;
+
+    fn index(&self, (x, y, z): (usize, usize, usize)) -> &Self::Output {
+        &self.data[x][y][z]
+    }
+}
+
+/// Returns the value at the position `(x, y, z)` in the tensor, as mutable.
+impl<T, const X: usize, const Y: usize, const Z: usize> ops::IndexMut<(usize, usize, usize)>
+    for Tensor3<T, X, Y, Z>
+{
+    fn index_mut(&mut self, (x, y, z): (usize, usize, usize)) -> &mut Self::Output {
+        &mut self.data[x][y][z]
+    }
+}
+
+macro_rules! impl_element_wise {
+    ($trait:ident, $function:ident, $operator:tt, $description:literal) => {
+        #[doc = concat!("Implementation for the element-wise ", $description, " of tensors.")]
+        ///
+        /// ## Example
+        /// ```
+        /// use linbra::tensor::Tensor3;
+        ///
+        /// let a = Tensor3::<i32, 1, 2, 2>::splat(6);
+        /// let b = Tensor3::<i32, 1, 2, 2>::splat(2);
+        ///
+        #[doc = concat!(
+            "assert_eq!(a ", stringify!($operator), " b, Tensor3::splat(6 ",
+            stringify!($operator), " 2));"
+        )]
+        /// ```
+        impl<T: Num, const X: usize, const Y: usize, const Z: usize> ops::$trait<Self>
+            for Tensor3<T, X, Y, Z>
+        {
+            type Output = Self;
+
+            fn $function(self, rhs: Self) -> Self::Output {
+                let mut output = self;
+
+                for (value, other) in output.values_mut().iter_mut().zip(rhs.values()) {
+                    *value = value.clone() $operator other.clone();
+                }
+
+                output
+            }
+        }
+    };
+}
+
+impl_element_wise!(Add, add, +, "addition");
+impl_element_wise!(Sub, sub, -, "subtraction");
+impl_element_wise!(Mul, mul, *, "product");
+
+/// Implementation for the product of a tensor by a scalar.
+impl<T: Num, const X: usize, const Y: usize, const Z: usize> ops::Mul<T> for Tensor3<T, X, Y, Z> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let mut output = self;
+
+        for value in output.values_mut() {
+            *value *= rhs.clone();
+        }
+
+        output
+    }
+}
@@ -0,0 +1,169 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Component-wise comparisons of vectors, returning masks of booleans, and
+//! selection of components from masks, with the predicates on all or any of
+//! the components.
+//!
+//! As in shaders, conditional calculations are written without branching on
+//! each component:
+//! ```
+//! use linbra::vector::Vector3;
+//!
+//! let values = Vector3::new([-2, 5, -1]);
+//! let zero = Vector3::zeroed();
+//!
+//! // Replaces the negative components by zero.
+//! let clamped = Vector3::select(values.cmp_lt(zero), zero, values);
+//! assert_eq!(clamped, Vector3::new([0, 5, 0]));
+//! ```
+
+use crate::vector::Vector;
+
+macro_rules! impl_comparison {
+    ($function:ident, $all:ident, $any:ident, $operator:tt, $description:literal) => {
+        #[doc = concat!("Returns the mask of the components ", $description, " the ones of `other`.")]
+        ///
+        /// ## Example
+        /// ```
+        /// use linbra::vector::Vector3;
+        ///
+        /// let a = Vector3::new([1, 2, 3]);
+        /// let b = Vector3::new([3, 2, 1]);
+        ///
+        #[doc = concat!(
+            "assert_eq!(a.", stringify!($function), "(b), Vector3::new([",
+            "1 ", stringify!($operator), " 3, ",
+            "2 ", stringify!($operator), " 2, ",
+            "3 ", stringify!($operator), " 1]));"
+        )]
+        /// ```
+        pub fn $function(self, other: Self) -> Vector<bool, N> {
+            Vector::new(std::array::from_fn(|n| self[n] $operator other[n]))
+        }
+
+        #[doc = concat!("Whether all the components are ", $description, " the ones of `other`.")]
+        ///
+        /// ## Example
+        /// ```
+        /// use linbra::vector::Vector2;
+        ///
+        #[doc = concat!(
+            "assert_eq!(Vector2::new([1, 2]).", stringify!($all), "(Vector2::new([2, 2])), ",
+            "1 ", stringify!($operator), " 2 && 2 ", stringify!($operator), " 2);"
+        )]
+        /// ```
+        pub fn $all(self, other: Self) -> bool {
+            self.values().iter().zip(other.values()).all(|(a, b)| a $operator b)
+        }
+
+        #[doc = concat!("Whether at least one component is ", $description, " the one of `other`.")]
+        ///
+        /// ## Example
+        /// ```
+        /// use linbra::vector::Vector2;
+        ///
+        #[doc = concat!(
+            "assert_eq!(Vector2::new([1, 2]).", stringify!($any), "(Vector2::new([2, 2])), ",
+            "1 ", stringify!($operator), " 2 || 2 ", stringify!($operator), " 2);"
+        )]
+        /// ```
+        pub fn $any(self, other: Self) -> bool {
+            self.values().iter().zip(other.values()).any(|(a, b)| a $operator b)
+        }
+    };
+}
+
+/// Implements the component-wise comparisons for types implementing the
+/// [`PartialOrd`] trait.
+impl<T: PartialOrd, const N: usize> Vector<T, N> {
+    impl_comparison!(cmp_eq, all_eq, any_eq, ==, "equal to");
+    impl_comparison!(cmp_ne, all_ne, any_ne, !=, "not equal to");
+    impl_comparison!(cmp_lt, all_lt, any_lt, <, "less than");
+    impl_comparison!(cmp_le, all_le, any_le, <=, "less than or equal to");
+    impl_comparison!(cmp_gt, all_gt, any_gt, >, "greater than");
+    impl_comparison!(cmp_ge, all_ge, any_ge, >=, "greater than or equal to");
+
+    /// Whether all the components are between the ones of `min` and `max`,
+    /// both included, as for the containment of a point in an axis-aligned
+    /// box.
+    ///
+    /// ## Formula
+    /// $$ \forall i, \quad m_{i} \le a_{i} \le M_{i} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// let min = Vector2::new([-1.0, -1.0]);
+    /// let max = Vector2::new([1.0, 1.0]);
+    ///
+    /// assert!(Vector2::new([0.5, 1.0]).all_in_range(min, max));
+    /// assert!(!Vector2::new([0.5, 1.5]).all_in_range(min, max));
+    /// ```
+    pub fn all_in_range(self, min: Self, max: Self) -> bool {
+        self.values()
+            .iter()
+            .zip(min.values().iter().zip(max.values()))
+            .all(|(value, (min, max))| min <= value && value <= max)
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Returns a vector made of the components of `a` where the mask is
+    /// `true`, and of the ones of `b` where it is `false`.
+    ///
+    /// ## Formula
+    /// $$ c_{i} = \begin{cases} a_{i} & \text{if } m_{i} \\\ b_{i} & \text{otherwise} \end{cases} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    ///
+    /// let mask = Vector3::new([true, false, true]);
+    /// let a = Vector3::new([1, 2, 3]);
+    /// let b = Vector3::new([10, 20, 30]);
+    ///
+    /// assert_eq!(Vector3::select(mask, a, b), Vector3::new([1, 20, 3]));
+    /// ```
+    pub fn select(mask: Vector<bool, N>, a: Self, b: Self) -> Self {
+        let mut output = b;
+
+        for (n, (value, keep)) in a.into_iter().zip(mask).enumerate() {
+            if keep {
+                output[n] = value;
+            }
+        }
+
+        output
+    }
+}
+
+impl<const N: usize> Vector<bool, N> {
+    /// Whether all the components of the mask are `true`.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// assert!(Vector2::new([1, 2]).cmp_lt(Vector2::new([3, 4])).all());
+    /// assert!(!Vector2::new([true, false]).all());
+    /// ```
+    pub fn all(self) -> bool {
+        self.into_iter().all(|value| value)
+    }
+
+    /// Whether at least one component of the mask is `true`.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    ///
+    /// assert!(Vector2::new([true, false]).any());
+    /// assert!(!Vector2::new([false, false]).any());
+    /// ```
+    pub fn any(self) -> bool {
+        self.into_iter().any(|value| value)
+    }
+}
@@ -0,0 +1,216 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+//! Vector whose length is only known at runtime, its values being stored on
+//! the heap.
+//!
+//! Operations between two dynamic vectors panic when their lengths differ.
+
+use std::{ops, vec::IntoIter};
+
+use crate::{ Error, Num, Zero };
+use crate::vector::Vector;
+
+/// Vector with a length known at runtime, for data whose dimensions come from
+/// files or user inputs.
+///
+/// $$
+/// \begin{pmatrix}
+///     a_{1} \\\
+///     a_{2} \\\
+///     \vdots \\\
+///     a_{n} \\\
+/// \end{pmatrix}
+/// $$
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DVector<T> {
+    /// Values of the vector.
+    data: Vec<T>
+}
+
+impl<T> DVector<T> {
+    /// Creates a new vector from its values.
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::DVector;
+    ///
+    /// let vector = DVector::new(vec![5, 10, 15]);
+    /// assert_eq!(vector.len(), 3);
+    /// ```
+    pub fn new(data: Vec<T>) -> Self {
+        Self { data }
+    }
+
+    /// Returns the number of values in the vector.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the vector has no value.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T: Zero> DVector<T> {
+    /// Creates a new vector of `len` values filled with zeros.
+    pub fn zeroed(len: usize) -> Self {
+        Self::new(vec![T::zero(); len])
+    }
+}
+
+impl<T: Zero + Num> DVector<T> {
+    /// Returns the dot product of the two vectors.
+    ///
+    /// ## Formula
+    /// $$ a \cdot b = a_{1} \times b_{1} + \dots + a_{n} \times b_{n} $$
+    ///
+    /// ## Example
+    /// ```
+    /// use linbra::vector::DVector;
+    ///
+    /// let a = DVector::new(vec![1, 2, 3]);
+    /// let b = DVector::new(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(a.dot(&b), 32);
+    /// ```
+    pub fn dot(&self, rhs: &Self) -> T {
+        assert_eq!(self.len(), rhs.len(), "vectors of different lengths");
+
+        let mut sum = T::zero();
+
+        for (a, b) in self.data.iter().zip(&rhs.data) {
+            sum += a.clone() * b.clone();
+        }
+
+        sum
+    }
+
+    /// Returns the squared length of the vector.
+    ///
+    /// ## Formula
+    /// $$ \lVert a \rVert^2 = a \cdot a $$
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+/// Creates a dynamic vector from the values of a fixed-size vector.
+///
+/// ## Example
+/// ```
+/// use linbra::vector::{ DVector, Vector3 };
+///
+/// let vector: DVector<i32> = Vector3::new([1, 2, 3]).into();
+/// assert_eq!(vector, DVector::new(vec![1, 2, 3]));
+/// ```
+impl<T, const N: usize> From<Vector<T, N>> for DVector<T> {
+    fn from(value: Vector<T, N>) -> Self {
+        Self::new(value.into_iter().collect())
+    }
+}
+
+/// Creates a fixed-size vector from the values of a dynamic vector.
+///
+/// Fails with [`Error::DimensionMismatch`] when the lengths differ.
+///
+/// ## Example
+/// ```
+/// use linbra::vector::{ DVector, Vector3 };
+///
+/// let vector = Vector3::try_from(DVector::new(vec![1, 2, 3]));
+/// assert_eq!(vector, Ok(Vector3::new([1, 2, 3])));
+///
+/// assert!(Vector3::try_from(DVector::new(vec![1, 2])).is_err());
+/// ```
+impl<T, const N: usize> TryFrom<DVector<T>> for Vector<T, N> {
+    type Error = Error;
+
+    fn try_from(value: DVector<T>) -> Result<Self, Self::Error> {
+        Self::try_from(value.data)
+    }
+}
+
+/// Creates a dynamic vector from a list of values.
+impl<T> From<Vec<T>> for DVector<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Returns the value at index `n` in the vector.
+impl<T> ops::Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+/// Returns the value at index `n` in the vector, as mutable.
+impl<T> ops::IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+/// Implementations iteration on the vector by converting its values into an
+/// iterator.
+impl<T> IntoIterator for DVector<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+/// Implementation for scalar product, as for [`Vector`].
+///
+/// ## Example
+/// ```
+/// use linbra::vector::DVector;
+///
+/// let vector = DVector::new(vec![5, 8]);
+/// assert_eq!(vector * 2, DVector::new(vec![10, 16]));
+/// ```
+impl<T: Zero + Num + ops::MulAssign<U>, U: Num> ops::Mul<U> for DVector<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: U) -> Self::Output {
+        let mut vector = self;
+
+        for value in &mut vector.data {
+            *value *= rhs.clone();
+        }
+
+        vector
+    }
+}
+
+macro_rules! impl_component_wise {
+    ($trait:ident, $function:ident, $operator:tt, $description:literal) => {
+        #[doc = concat!("Implementation for vectors ", $description, ", as for [`Vector`].")]
+        impl<T: Zero + Num> ops::$trait<Self> for DVector<T> {
+            type Output = Self;
+
+            fn $function(self, rhs: Self) -> Self::Output {
+                assert_eq!(self.len(), rhs.len(), "vectors of different lengths");
+
+                let mut output = self;
+
+                for (value, other) in output.data.iter_mut().zip(rhs) {
+                    *value $operator other;
+                }
+
+                output
+            }
+        }
+    };
+}
+
+impl_component_wise!(Mul, mul, *=, "vectorial product");
+impl_component_wise!(Add, add, +=, "addition");
+impl_component_wise!(Sub, sub, -=, "subtraction");
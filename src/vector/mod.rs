@@ -3,11 +3,18 @@
 // Copyright (c) 2023 Antonin Hérault
 
 //! Fixed-size vector and easy-types for different usually used vectors with 
-//! into/from implementations on relevant primitives types.
+//! into/from implementations on relevant primitives types, and dynamic vector 
+//! whose length is known at runtime.
 
+mod comparisons;
+mod dynamic;
 mod operations;
+#[allow(clippy::module_inception)]
 mod vector;
 
+pub use dynamic::*;
+// Kept public so that the items of the operations stay re-exported.
+#[allow(unused_imports)]
 pub use operations::*;
 pub use vector::*;
 
@@ -62,3 +69,51 @@ impl<T> From<(T, T, T, T)> for Vector4<T> {
         Self::new([value.0, value.1, value.2, value.3])
     }
 }
+
+/// Returns the values of a vector 2 as a tuple of two values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector2;
+/// 
+/// let tuple: (u8, u8) = Vector2::new([8, 9]).into();
+/// assert_eq!(tuple, (8, 9));
+/// ```
+impl<T> From<Vector2<T>> for (T, T) {
+    fn from(value: Vector2<T>) -> Self {
+        let [x, y] = value.into();
+        (x, y)
+    }
+}
+
+/// Returns the values of a vector 3 as a tuple of three values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let tuple: (u8, u8, u8) = Vector3::new([8, 9, 10]).into();
+/// assert_eq!(tuple, (8, 9, 10));
+/// ```
+impl<T> From<Vector3<T>> for (T, T, T) {
+    fn from(value: Vector3<T>) -> Self {
+        let [x, y, z] = value.into();
+        (x, y, z)
+    }
+}
+
+/// Returns the values of a vector 4 as a tuple of four values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector4;
+/// 
+/// let tuple: (u8, u8, u8, u8) = Vector4::new([8, 9, 10, 11]).into();
+/// assert_eq!(tuple, (8, 9, 10, 11));
+/// ```
+impl<T> From<Vector4<T>> for (T, T, T, T) {
+    fn from(value: Vector4<T>) -> Self {
+        let [x, y, z, w] = value.into();
+        (x, y, z, w)
+    }
+}
@@ -9,11 +9,12 @@
 //! - vectorial product (vector1 * vector2)
 //! - vectors addition (vector1 + vector 2)
 //! - vectors subtraction (vector1 - vector 2)
+//! - vector negation (-vector)
 
-use crate::{ Num, Zero };
+use crate::{ Num, Signed, Zero };
 use crate::vector::Vector;
 
-use std::ops::{ Add, Sub, Mul, MulAssign };
+use std::ops::{ Add, Sub, Mul, MulAssign, Neg };
 
 /// Implementation for scalar product
 /// 
@@ -63,7 +64,7 @@ impl<T: Zero + Num + MulAssign<U>, U: Num, const N: usize> Mul<U> for Vector<T,
         let mut vector = self;
         
         for n in 0..N {
-            vector[n] *= rhs; 
+            vector[n] *= rhs.clone(); 
         }
 
         vector
@@ -131,8 +132,8 @@ impl<T: Zero + Num, const N: usize> Mul<Self> for Vector<T, N> {
     fn mul(self, rhs: Self) -> Self::Output {
         let mut vector = self;
         
-        for n in 0..N {
-            vector[n] *= rhs[n]; 
+        for (n, value) in rhs.into_iter().enumerate() {
+            vector[n] *= value; 
         }
 
         vector
@@ -285,3 +286,40 @@ impl<T: Zero + Num, const N: usize> Sub<Self> for Vector<T, N> {
         output
     }
 }
+
+/// Implementation for vector negation, for types implementing the [`Signed`] 
+/// trait.
+/// 
+/// ## Formula
+/// $$
+/// -\begin{pmatrix} 
+///     a_{1} \\\ 
+///     \vdots \\\ 
+///     a_{n} \\\ 
+/// \end{pmatrix} = 
+/// \begin{pmatrix} 
+///     -a_{1} \\\ 
+///     \vdots \\\ 
+///     -a_{n} \\\ 
+/// \end{pmatrix}
+/// $$
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// assert_eq!(-Vector3::new([5, -8, 0]), Vector3::new([-5, 8, 0]));
+/// ```
+impl<T: Signed, const N: usize> Neg for Vector<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let mut output = self;
+
+        for n in 0..N {
+            output[n] = -output[n].clone();
+        }
+
+        output
+    }
+}
@@ -4,7 +4,7 @@
 
 use std::{ops, array::IntoIter};
 
-use crate::Zero;
+use crate::{ Error, Float, Num, Signed, Zero };
 
 /// Linear algebra mathematical tool.
 /// 
@@ -19,7 +19,11 @@ use crate::Zero;
 ///     a_{n} \\\ 
 /// \end{pmatrix}
 /// $$
+/// 
+/// The vector has the same memory layout as an array `[T; N]`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[repr(transparent)]
 pub struct Vector<T, const N: usize> {
     /// Array of data contained by the vector.
     data: [T; N]
@@ -43,6 +47,94 @@ impl<T, const N: usize> Vector<T, N> {
     pub fn new(data: [T; N]) -> Self {
         Self { data }
     }
+
+    /// Returns a raw pointer to the first value of the vector, the `N` values 
+    /// being contiguous. 
+    /// 
+    /// Since the vector has the same memory layout as `[T; N]`, it can be 
+    /// passed to C functions expecting an array, such as `float[3]`.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let vector = Vector3::<f32>::new([1.0, 2.0, 3.0]);
+    /// let pointer = vector.as_ptr();
+    /// 
+    /// assert_eq!(unsafe { *pointer.add(2) }, 3.0);
+    /// ```
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns a mutable raw pointer to the first value of the vector, the 
+    /// `N` values being contiguous.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    /// Returns a reference to the value at the index, or `None` when the 
+    /// index is out of bounds, unlike the indexing operator which panics.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let vector = Vector3::new([1, 2, 3]);
+    /// 
+    /// assert_eq!(vector.get(2), Some(&3));
+    /// assert_eq!(vector.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+
+    /// Returns a mutable reference to the value at the index, or `None` when 
+    /// the index is out of bounds, unlike the indexing operator which panics.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let mut vector = Vector3::new([1, 2, 3]);
+    /// 
+    /// if let Some(value) = vector.get_mut(1) {
+    ///     *value = 5;
+    /// }
+    /// 
+    /// assert_eq!(vector, Vector3::new([1, 5, 3]));
+    /// assert_eq!(vector.get_mut(3), None);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index)
+    }
+
+    /// Returns all the values of the vector.
+    pub(crate) fn values(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Zero, const N: usize> Vector<T, N> {
+    /// Returns the value at the index, or zero when the index is out of 
+    /// bounds.
+    /// 
+    /// It never panics, so that untrusted indices can be read in threads 
+    /// which must not panic, such as audio or render threads, a vector being 
+    /// considered as followed by an infinity of zeros.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let gains = Vector2::new([0.5, 0.25]);
+    /// 
+    /// assert_eq!(gains.component(1), 0.25);
+    /// assert_eq!(gains.component(7), 0.0);
+    /// ```
+    pub fn component(&self, index: usize) -> T {
+        self.data.get(index).cloned().unwrap_or_else(T::zero)
+    }
 }
 
 /// Creates a vector `N` from an array of `N` values.
@@ -62,8 +154,49 @@ impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
     }
 }
 
+/// Creates a new vector from a list of values.
+/// 
+/// Fails with [`Error::DimensionMismatch`] when the list has not `N` values.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector2;
+/// 
+/// assert_eq!(Vector2::try_from(vec![8, 9]), Ok(Vector2::new([8, 9])));
+/// assert!(Vector2::try_from(vec![8, 9, 10]).is_err());
+/// ```
+impl<T, const N: usize> TryFrom<Vec<T>> for Vector<T, N> {
+    type Error = Error;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        let found = value.len();
+
+        <[T; N]>::try_from(value)
+            .map(Self::new)
+            .map_err(|_| Error::DimensionMismatch { expected: (1, N), found: (1, found) })
+    }
+}
+
+/// Returns the values of the vector as an array.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let array: [u8; 3] = Vector3::new([8, 9, 10]).into();
+/// assert_eq!(array, [8, 9, 10]);
+/// ```
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(value: Vector<T, N>) -> Self {
+        value.data
+    }
+}
+
 /// Returns the value at index `n` in the vector.
 /// 
+/// Panics when the index is out of bounds, [`Vector::get`] and 
+/// [`Vector::component`] being the ones which do not.
+/// 
 /// ## Usage
 /// ```
 /// use linbra::vector::{ Vector, Vector3 };
@@ -83,6 +216,9 @@ impl<T, const N: usize> ops::Index<usize> for Vector<T, N> {
 
 /// Returns the value at index `n` in the vector, as mutable.
 /// 
+/// Panics when the index is out of bounds, [`Vector::get_mut`] being the one 
+/// which does not.
+/// 
 /// ## Usage
 /// ```
 /// use linbra::vector::{ Vector, Vector3 };
@@ -110,6 +246,53 @@ impl<T, const N: usize> IntoIterator for Vector<T, N> {
     }
 }
 
+/// Implements iteration on references to the values of the vector.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let vector = Vector3::new([1, 2, 3]);
+/// let mut sum = 0;
+/// 
+/// for value in &vector {
+///     sum += value;
+/// }
+/// 
+/// assert_eq!(sum, 6);
+/// ```
+impl<'a, T, const N: usize> IntoIterator for &'a Vector<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Implements iteration on mutable references to the values of the vector.
+/// 
+/// ## Example
+/// ```
+/// use linbra::vector::Vector3;
+/// 
+/// let mut vector = Vector3::new([1, 2, 3]);
+/// 
+/// for value in &mut vector {
+///     *value *= 2;
+/// }
+/// 
+/// assert_eq!(vector, Vector3::new([2, 4, 6]));
+/// ```
+impl<'a, T, const N: usize> IntoIterator for &'a mut Vector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 /// Implements a constructor filling the vector with zeros for types 
 /// implementing the [`Zero`] trait.
 /// 
@@ -118,7 +301,473 @@ impl<T: Zero, const N: usize> Vector<T, N> {
     /// Creates a new vector filled with zeros.
     pub fn zeroed() -> Self {
         Self {
-            data: [T::zero(); N]
+            data: std::array::from_fn(|_| T::zero())
+        }
+    }
+}
+
+/// Implements a constructor repeating a value for types implementing the 
+/// [`Clone`] trait.
+impl<T: Clone, const N: usize> Vector<T, N> {
+    /// Creates a new vector with all its components set to `value`.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// assert_eq!(Vector3::splat(7), Vector3::new([7, 7, 7]));
+    /// ```
+    pub fn splat(value: T) -> Self {
+        Self {
+            data: std::array::from_fn(|_| value.clone())
+        }
+    }
+}
+
+/// Implements the functions relying on the sum of products of components, for 
+/// types implementing the [`Zero`] and [`Num`] traits.
+impl<T: Zero + Num, const N: usize> Vector<T, N> {
+    /// Returns the dot product of the two vectors.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     a_{1} \\\ 
+    ///     \vdots \\\ 
+    ///     a_{n} \\\ 
+    /// \end{pmatrix} 
+    /// \cdot
+    /// \begin{pmatrix} 
+    ///     b_{1} \\\ 
+    ///     \vdots \\\ 
+    ///     b_{n} \\\ 
+    /// \end{pmatrix} = 
+    /// a_{1} \times b_{1} + \dots + a_{n} \times b_{n}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let a = Vector3::new([1, 2, 3]);
+    /// let b = Vector3::new([4, 5, 6]);
+    /// 
+    /// assert_eq!(a.dot(b), 32);
+    /// ```
+    pub fn dot(self, rhs: Self) -> T {
+        let mut sum = T::zero();
+
+        for (a, b) in self.into_iter().zip(rhs) {
+            sum += a * b;
+        }
+
+        sum
+    }
+
+    /// Returns the squared length of the vector, avoiding a square root.
+    /// 
+    /// ## Formula
+    /// $$ \lVert a \rVert^2 = a \cdot a $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// assert_eq!(Vector2::new([3, 4]).length_squared(), 25);
+    /// ```
+    pub fn length_squared(self) -> T {
+        self.clone().dot(self)
+    }
+}
+
+/// Implements the functions requiring a square root or a rounding, for types 
+/// implementing the [`Float`] trait.
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the euclidean length of the vector.
+    /// 
+    /// ## Formula
+    /// $$ \lVert a \rVert = \sqrt{a_{1}^2 + \dots + a_{n}^2} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let vector = Vector2::new([3.0, 4.0]);
+    /// assert_eq!(vector.length(), 5.0);
+    /// ```
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the vector with the same direction and a length of one.
+    /// 
+    /// Fails with [`Error::ZeroVector`] when the length is zero, the vector 
+    /// having no direction.
+    /// 
+    /// ## Formula
+    /// $$ \hat{a} = \frac{a}{\lVert a \rVert} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::{
+    ///     vector::Vector2,
+    ///     Error,
+    /// };
+    /// 
+    /// let vector = Vector2::new([0.0, 5.0]);
+    /// 
+    /// assert_eq!(vector.normalize(), Ok(Vector2::new([0.0, 1.0])));
+    /// assert_eq!(Vector2::<f64>::zeroed().normalize(), Err(Error::ZeroVector));
+    /// ```
+    pub fn normalize(self) -> Result<Self, Error> {
+        let length = self.length();
+
+        if length == T::zero() {
+            return Err(Error::ZeroVector);
         }
+
+        Ok(self * (T::one() / length))
+    }
+
+    /// Returns the euclidean distance between the two points.
+    /// 
+    /// ## Formula
+    /// $$ d(a, b) = \lVert b - a \rVert $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let a = Vector2::new([1.0, 1.0]);
+    /// let b = Vector2::new([4.0, 5.0]);
+    /// 
+    /// assert_eq!(a.distance(b), 5.0);
+    /// ```
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    /// Returns the vector with the same direction and a length 
+    /// clamped between `min` and `max`.
+    /// 
+    /// A zero vector has no direction, so it is returned as is.
+    /// 
+    /// ## Formula
+    /// $$ a' = \frac{a}{\lVert a \rVert} \times \operatorname{clamp}(\lVert a \rVert, min, max) $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let vector = Vector2::new([3.0, 4.0]);
+    /// 
+    /// assert_eq!(vector.clamp_length(1.0, 2.5), Vector2::new([1.5, 2.0]));
+    /// assert_eq!(vector.clamp_length(10.0, 20.0), Vector2::new([6.0, 8.0]));
+    /// ```
+    pub fn clamp_length(self, min: T, max: T) -> Self {
+        let length = self.length();
+
+        if length == T::zero() {
+            self
+        } else if length < min {
+            self * (min / length)
+        } else if length > max {
+            self * (max / length)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the vector with the same direction and a length limited 
+    /// to `max`, such as a velocity capped to a maximum speed.
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let velocity = Vector2::new([0.0, 10.0]);
+    /// 
+    /// assert_eq!(velocity.limit(4.0), Vector2::new([0.0, 4.0]));
+    /// assert_eq!(velocity.limit(20.0), velocity);
+    /// ```
+    pub fn limit(self, max: T) -> Self {
+        self.clamp_length(T::zero(), max)
+    }
+
+    /// Returns the vector with each component rounded to the nearest 
+    /// multiple of `step`.
+    /// 
+    /// ## Formula
+    /// $$ a'_{i} = \operatorname{round} \left( \frac{a_{i}}{s} \right) \times s $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let vector = Vector2::new([1.3, 2.6]);
+    /// assert_eq!(vector.snap(0.5), Vector2::new([1.5, 2.5]));
+    /// ```
+    pub fn snap(self, step: T) -> Self {
+        let mut vector = self;
+
+        for n in 0..N {
+            vector[n] = (vector[n] / step).round() * step;
+        }
+
+        vector
+    }
+
+    /// Returns the vector with each component rounded to the nearest 
+    /// multiple of the matching component of `cell_size`.
+    /// 
+    /// ## Formula
+    /// $$ a'_{i} = \operatorname{round} \left( \frac{a_{i}}{c_{i}} \right) \times c_{i} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let vector = Vector2::new([33.0, 17.0]);
+    /// let cell_size = Vector2::new([16.0, 8.0]);
+    /// 
+    /// assert_eq!(vector.snap_to_grid(cell_size), Vector2::new([32.0, 16.0]));
+    /// ```
+    pub fn snap_to_grid(self, cell_size: Self) -> Self {
+        let mut vector = self;
+
+        for n in 0..N {
+            vector[n] = (vector[n] / cell_size[n]).round() * cell_size[n];
+        }
+
+        vector
+    }
+}
+
+/// Implements the distances only relying on the absolute differences between 
+/// components, for types implementing the [`Zero`], [`Num`] and [`PartialOrd`] 
+/// traits.
+/// 
+/// Unlike [`Vector::distance`], these distances are also available on integer 
+/// vectors, signed or not.
+impl<T: Zero + Num + PartialOrd, const N: usize> Vector<T, N> {
+    /// Returns the manhattan distance between the two points, being the sum 
+    /// of the absolute differences between their components.
+    /// 
+    /// ## Formula
+    /// $$ d(a, b) = \sum_{i=1}^{n} |a_{i} - b_{i}| $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let a = Vector2::<u32>::new([1, 7]);
+    /// let b = Vector2::<u32>::new([4, 3]);
+    /// 
+    /// assert_eq!(a.distance_manhattan(b), 7);
+    /// ```
+    pub fn distance_manhattan(self, other: Self) -> T {
+        let mut sum = T::zero();
+
+        for (a, b) in self.into_iter().zip(other) {
+            sum += abs_difference(a, b);
+        }
+
+        sum
+    }
+
+    /// Returns the chebyshev distance between the two points, being the 
+    /// greatest absolute difference between their components.
+    /// 
+    /// ## Formula
+    /// $$ d(a, b) = \max_{i} |a_{i} - b_{i}| $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let a = Vector2::<i32>::new([1, 7]);
+    /// let b = Vector2::<i32>::new([4, 3]);
+    /// 
+    /// assert_eq!(a.distance_chebyshev(b), 4);
+    /// ```
+    pub fn distance_chebyshev(self, other: Self) -> T {
+        let mut max = T::zero();
+
+        for (a, b) in self.into_iter().zip(other) {
+            let difference = abs_difference(a, b);
+
+            if difference > max {
+                max = difference;
+            }
+        }
+
+        max
+    }
+}
+
+/// Implements the absolute value for types implementing the [`Signed`] trait.
+impl<T: Zero + Signed + PartialOrd, const N: usize> Vector<T, N> {
+    /// Returns the vector made of the absolute values of the components.
+    /// 
+    /// ## Formula
+    /// $$ |a|_{i} = |a_{i}| $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// assert_eq!(Vector3::new([-2, 0, 3]).abs(), Vector3::new([2, 0, 3]));
+    /// ```
+    pub fn abs(self) -> Self {
+        let mut output = self;
+
+        for n in 0..N {
+            if output[n] < T::zero() {
+                output[n] = -output[n].clone();
+            }
+        }
+
+        output
+    }
+}
+
+/// Returns the absolute difference between two values, without overflowing 
+/// for unsigned types.
+fn abs_difference<T: Num + PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Implements the perpendicular vector and the 2d cross product, for types 
+/// implementing the [`Num`] trait.
+impl<T: Num> Vector<T, 2> {
+    /// Returns the vector rotated by 90 degrees counterclockwise.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     x \\\ 
+    ///     y \\\ 
+    /// \end{pmatrix}^\perp = 
+    /// \begin{pmatrix} 
+    ///     -y \\\ 
+    ///     x \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// assert_eq!(Vector2::new([3, 1]).perp(), Vector2::new([-1, 3]));
+    /// ```
+    pub fn perp(self) -> Self 
+    where 
+        T: Signed 
+    {
+        let [x, y] = self.data;
+        Self::new([-y, x])
+    }
+
+    /// Returns the 2d cross product of the two vectors, being the dot product 
+    /// of the perpendicular of this vector with the other one.
+    /// 
+    /// It is positive when `other` is counterclockwise from this vector, 
+    /// negative when it is clockwise and zero when they are collinear. Its 
+    /// absolute value is the area of the parallelogram of the two vectors, and 
+    /// the torque of a force `other` applied at the lever arm `self`.
+    /// 
+    /// ## Formula
+    /// $$ a^\perp \cdot b = a_{x} \times b_{y} - a_{y} \times b_{x} $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector2;
+    /// 
+    /// let a = Vector2::new([2, 0]);
+    /// 
+    /// assert_eq!(a.perp_dot(Vector2::new([1, 3])), 6);
+    /// assert_eq!(a.perp_dot(Vector2::new([1, -3])), -6);
+    /// ```
+    pub fn perp_dot(self, other: Self) -> T {
+        let [ax, ay] = self.data;
+        let [bx, by] = other.data;
+
+        ax * by - ay * bx
+    }
+}
+
+/// Implements the cross product and the scalar triple product, for types 
+/// implementing the [`Zero`] and [`Num`] traits.
+impl<T: Zero + Num> Vector<T, 3> {
+    /// Returns the cross product of the two vectors, perpendicular to both of 
+    /// them following the right-hand rule.
+    /// 
+    /// ## Formula
+    /// $$ 
+    /// \begin{pmatrix} 
+    ///     a_{x} \\\ 
+    ///     a_{y} \\\ 
+    ///     a_{z} \\\ 
+    /// \end{pmatrix} \times
+    /// \begin{pmatrix} 
+    ///     b_{x} \\\ 
+    ///     b_{y} \\\ 
+    ///     b_{z} \\\ 
+    /// \end{pmatrix} = 
+    /// \begin{pmatrix} 
+    ///     a_{y} b_{z} - a_{z} b_{y} \\\ 
+    ///     a_{z} b_{x} - a_{x} b_{z} \\\ 
+    ///     a_{x} b_{y} - a_{y} b_{x} \\\ 
+    /// \end{pmatrix}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let x = Vector3::new([1, 0, 0]);
+    /// let y = Vector3::new([0, 1, 0]);
+    /// 
+    /// assert_eq!(x.cross(y), Vector3::new([0, 0, 1]));
+    /// ```
+    pub fn cross(self, other: Self) -> Self {
+        let [ax, ay, az] = self.data;
+        let [bx, by, bz] = other.data;
+
+        Self::new([
+            ay.clone() * bz.clone() - az.clone() * by.clone(),
+            az * bx.clone() - ax.clone() * bz,
+            ax * by - ay * bx,
+        ])
+    }
+
+    /// Returns the scalar triple product of the three vectors.
+    /// 
+    /// Its absolute value is the volume of the parallelepiped built on the 
+    /// three vectors, six times the one of the tetrahedron they build. Its sign 
+    /// tells the orientation: positive when `a`, `b` and `c` follow the 
+    /// right-hand rule, negative when they follow the left-hand rule and zero 
+    /// when they are coplanar.
+    /// 
+    /// ## Formula
+    /// $$ a \cdot (b \times c) $$
+    /// 
+    /// ## Example
+    /// ```
+    /// use linbra::vector::Vector3;
+    /// 
+    /// let a = Vector3::new([2, 0, 0]);
+    /// let b = Vector3::new([0, 3, 0]);
+    /// let c = Vector3::new([1, 1, 4]);
+    /// 
+    /// assert_eq!(Vector3::triple(a, b, c), 24);
+    /// assert_eq!(Vector3::triple(b, a, c), -24);
+    /// ```
+    pub fn triple(a: Self, b: Self, c: Self) -> T {
+        a.dot(b.cross(c))
     }
 }
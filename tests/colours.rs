@@ -24,3 +24,19 @@ fn implementation() {
     let red = colour.r();
     assert_eq!(255, red);
 }
+
+#[test]
+fn normalized_channels() {
+    use linbra::{
+        vector::Vector4,
+        colours::{ RGB, RGBA },
+    };
+
+    let colour: Vector4<u8> = 0xFF00FF80.into();
+    let normalized = colour.to_channels::<f32>();
+
+    assert_eq!(1.0, normalized.r());
+    assert_eq!(0.0, normalized.g());
+    assert_eq!(colour, normalized.to_channels::<u8>());
+    assert_eq!(0x80, normalized.to_channels::<u8>().a());
+}
@@ -33,3 +33,23 @@ fn same_into() {
     
     assert_eq!(10, vec3.r());
 }
+
+#[test]
+fn strongly_typed() {
+    use linbra::{
+        points::{ Point2, Point2D },
+        sizes::{ Size2, Size2D },
+        vector::Vector2,
+    };
+
+    let vec2: Vector2<u32> = [10, 5].into();
+
+    let point: Point2D<u32> = vec2.into();
+    let size: Size2D<u32> = vec2.into();
+    assert_eq!(10, point.x());
+    assert_eq!(10, size.w());
+
+    // Going back to the raw vectors is required to mix them.
+    let corner = Vector2::from(point) + Vector2::from(size);
+    assert_eq!(Vector2::new([20, 10]), corner);
+}
@@ -0,0 +1,29 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I16F16, U8F8};
+use linbra::{
+    matrix::Matrix2,
+    vector::Vector2,
+};
+
+#[test]
+fn operations() {
+    let vector = Vector2::new([I16F16::from_num(3), I16F16::from_num(4)]);
+
+    assert_eq!(vector.dot(vector), I16F16::from_num(25));
+    assert_eq!(vector - vector, Vector2::zeroed());
+    assert_eq!(vector * vector, Vector2::new([I16F16::from_num(9), I16F16::from_num(16)]));
+}
+
+#[test]
+fn unsigned_matrix() {
+    let one = U8F8::from_num(1);
+    let half = U8F8::from_num(0.5);
+    let matrix = Matrix2::natural([[one, half], [U8F8::ZERO, one]]);
+
+    assert_eq!(matrix[1], [half, one]);
+}
@@ -0,0 +1,28 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "half")]
+
+use half::f16;
+use linbra::{
+    matrix::Matrix2,
+    vector::Vector2,
+};
+
+#[test]
+fn matrix_round_trip() {
+    let matrix = Matrix2::<f64>::new([[1.0, 0.25], [-3.0, 8.0]]);
+    let compact = matrix.to_f16();
+
+    assert_eq!(compact[1], [f16::from_f64(-3.0), f16::from_f64(8.0)]);
+    assert_eq!(Matrix2::<f64>::from(compact), matrix);
+}
+
+#[test]
+fn rounding() {
+    let vector = Vector2::<f32>::new([0.1, 65504.0]).to_f16();
+    
+    assert_eq!(vector[0].to_f32(), 0.099975586);
+    assert_eq!(Vector2::<f16>::zeroed()[1], f16::ZERO);
+}
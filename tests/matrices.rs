@@ -0,0 +1,56 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+use linbra::{
+    matrix::Matrix,
+    vector::Vector,
+};
+
+#[test]
+fn blocked_product() {
+    let a = Box::new(Matrix::<i64, 70, 100>::new(std::array::from_fn(|column| {
+        std::array::from_fn(|row| (column * 3 + row * 7) as i64 % 11 - 5)
+    })));
+    let b = Box::new(Matrix::<i64, 50, 70>::new(std::array::from_fn(|column| {
+        std::array::from_fn(|row| (column * 5 + row * 2) as i64 % 13 - 6)
+    })));
+
+    let product = Box::new(*a * *b);
+
+    for column in 0..50 {
+        assert_eq!(Vector::new(product[column]), *a * Vector::new(b[column]));
+    }
+}
+
+#[test]
+fn views_of_dynamic_matrix() {
+    use linbra::matrix::DMatrix;
+
+    let mut matrix = DMatrix::natural(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+    ]);
+    let copy = matrix.clone();
+
+    let mut right = matrix.view_mut(1, 0, 2, 2);
+    right += copy.view(0, 0, 2, 2);
+    right -= copy.view(1, 0, 2, 2);
+
+    assert_eq!(matrix, DMatrix::natural(vec![
+        vec![1, 1, 2],
+        vec![4, 4, 5],
+    ]));
+    assert_eq!(DMatrix::from(matrix.view(1, 1, 2, 1)), DMatrix::natural(vec![vec![4, 5]]));
+}
+
+#[test]
+fn array_layout() {
+    use std::mem::{align_of, size_of};
+    use linbra::{matrix::Matrix4, vector::Vector3};
+
+    assert_eq!(size_of::<Matrix4<f32>>(), size_of::<[[f32; 4]; 4]>());
+    assert_eq!(align_of::<Matrix4<f32>>(), align_of::<[[f32; 4]; 4]>());
+    assert_eq!(size_of::<Vector3<f64>>(), size_of::<[f64; 3]>());
+    assert_eq!(align_of::<Vector3<f64>>(), align_of::<[f64; 3]>());
+}
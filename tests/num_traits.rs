@@ -0,0 +1,41 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "num-traits")]
+
+#[test]
+fn third_party_scalar() {
+    use std::num::Wrapping;
+    use linbra::{
+        matrix::Matrix2,
+        vector::Vector2,
+    };
+
+    let vector = Vector2::new([Wrapping(250u8), Wrapping(1)]);
+    let sum = vector + Vector2::new([Wrapping(10), Wrapping(2)]);
+
+    assert_eq!(Vector2::new([Wrapping(4), Wrapping(3)]), sum);
+    assert_eq!(Wrapping(0), Vector2::<Wrapping<u8>>::zeroed()[0]);
+    assert_eq!(
+        Matrix2::natural([[Wrapping(1u8), Wrapping(0)], [Wrapping(0), Wrapping(1)]]),
+        Matrix2::identity(),
+    );
+}
+
+#[test]
+fn arbitrary_precision() {
+    use num_bigint::BigInt;
+    use linbra::{
+        matrix::Matrix,
+        vector::Vector,
+    };
+
+    let big = BigInt::from(u64::MAX);
+    let vector = Vector::new([big.clone(), BigInt::from(2)]);
+    let matrix = Matrix::<BigInt, 2, 1>::natural([[BigInt::from(1), big.clone()]]);
+
+    assert_eq!(vector.clone().dot(vector.clone()), &big * &big + 4);
+    assert_eq!(vector.clone() * matrix, Vector::new([&big + &big * 2]));
+    assert_eq!((vector.clone() + vector)[0], big * 2);
+}
@@ -0,0 +1,19 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "rayon")]
+
+use linbra::matrix::Matrix;
+
+#[test]
+fn same_as_sequential() {
+    let a = Box::new(Matrix::<i64, 80, 70>::new(std::array::from_fn(|column| {
+        std::array::from_fn(|row| (column * 3 + row * 7) as i64 % 11 - 5)
+    })));
+    let b = Box::new(Matrix::<i64, 90, 80>::new(std::array::from_fn(|column| {
+        std::array::from_fn(|row| (column * 5 + row * 2) as i64 % 13 - 6)
+    })));
+
+    assert_eq!(a.par_mul(*b), *a * *b);
+}
@@ -0,0 +1,57 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "num-rational")]
+
+use linbra::matrix::{Matrix, Matrix3};
+use num_rational::Rational64;
+
+fn ratio(numerator: i64, denominator: i64) -> Rational64 {
+    Rational64::new(numerator, denominator)
+}
+
+#[test]
+fn exact_reduction() {
+    let matrix = Matrix::<Rational64, 3, 2>::natural([
+        [ratio(3, 1), ratio(1, 1), ratio(1, 1)],
+        [ratio(1, 1), ratio(3, 1), ratio(0, 1)],
+    ]);
+
+    assert_eq!(matrix.rref(), Matrix::natural([
+        [ratio(1, 1), ratio(0, 1), ratio(3, 8)],
+        [ratio(0, 1), ratio(1, 1), ratio(-1, 8)],
+    ]));
+}
+
+#[test]
+fn exact_determinant() {
+    let matrix = Matrix3::natural([
+        [ratio(1, 3), ratio(1, 2), ratio(0, 1)],
+        [ratio(0, 1), ratio(1, 1), ratio(2, 7)],
+        [ratio(1, 1), ratio(0, 1), ratio(1, 1)],
+    ]);
+
+    assert_eq!(matrix.determinant(), ratio(10, 21));
+}
+
+#[test]
+fn exact_inverse() {
+    use linbra::matrix::Matrix4;
+
+    let matrix = Matrix4::natural([
+        [ratio(2, 1), ratio(1, 1), ratio(0, 1), ratio(3, 1)],
+        [ratio(1, 2), ratio(4, 1), ratio(1, 1), ratio(0, 1)],
+        [ratio(0, 1), ratio(2, 1), ratio(5, 1), ratio(1, 3)],
+        [ratio(1, 1), ratio(0, 1), ratio(1, 1), ratio(1, 1)],
+    ]);
+    let identity = Matrix4::natural(std::array::from_fn(|row| {
+        std::array::from_fn(|column| ratio((row == column) as i64, 1))
+    }));
+
+    let inverse = matrix.inverse().unwrap();
+
+    assert_eq!(matrix * inverse, identity);
+    assert_eq!(inverse * matrix, identity);
+    assert_eq!(inverse.determinant() * matrix.determinant(), ratio(1, 1));
+}
@@ -0,0 +1,40 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#![cfg(feature = "rkyv")]
+
+use linbra::{
+    matrix::{ArchivedMatrix, Matrix4},
+    vector::{ArchivedVector, Vector3},
+};
+use rkyv::rancor::Error;
+
+#[test]
+fn vector_round_trip() {
+    let vectors = vec![
+        Vector3::<f32>::new([1.0, 2.0, 3.0]),
+        Vector3::<f32>::new([-4.0, 5.0, 0.5]),
+    ];
+
+    let bytes = rkyv::to_bytes::<Error>(&vectors).unwrap();
+    let archived = rkyv::access::<rkyv::vec::ArchivedVec<ArchivedVector<f32, 3>>, Error>(&bytes).unwrap();
+
+    assert_eq!(archived.len(), 2);
+    assert_eq!(rkyv::deserialize::<Vector3<f32>, Error>(&archived[1]).unwrap(), vectors[1]);
+}
+
+#[test]
+fn matrix_round_trip() {
+    let matrix = Matrix4::<i32>::new([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ]);
+
+    let bytes = rkyv::to_bytes::<Error>(&matrix).unwrap();
+    let archived = rkyv::access::<ArchivedMatrix<i32, 4, 4>, Error>(&bytes).unwrap();
+
+    assert_eq!(rkyv::deserialize::<Matrix4<i32>, Error>(archived).unwrap(), matrix);
+}
@@ -0,0 +1,28 @@
+// This file is part of "linbra"
+// Under the MIT License
+// Copyright (c) 2023 Antonin Hérault
+
+#[test]
+fn depth() {
+    use linbra::{
+        sizes::{ Size2, Size3 },
+        vector::Vector3,
+    };
+
+    let size = Vector3::<u32>::size(10, 5, 2);
+    assert_eq!(10, size.w());
+    assert_eq!(5, size.h());
+    assert_eq!(2, size.d());
+}
+
+#[test]
+fn volume() {
+    use linbra::{
+        sizes::{ Size2, Size3 },
+        vector::Vector3,
+    };
+
+    let size: Vector3<f32> = [4.0, 2.5, 2.0].into();
+    assert_eq!(10.0, size.area());
+    assert_eq!(20.0, size.volume());
+}